@@ -2,8 +2,8 @@ use clap::Parser;
 use miden_client::{
     accounts::{AccountId, AccountType, StorageSlot},
     assets::Asset,
-    crypto::FeltRng,
-    Client, ZERO,
+    crypto::{Digest, FeltRng},
+    Client, Word, ZERO,
 };
 
 use crate::{
@@ -140,20 +140,22 @@ pub async fn show_account<R: FeltRng>(
         let faucet_details_map = load_faucet_details_map()?;
         println!("Assets: ");
 
-        let mut table = create_dynamic_table(&["Asset Type", "Faucet", "Amount"]);
+        let mut table = create_dynamic_table(&["Asset Type", "Faucet", "Amount", "Asset Data"]);
         for asset in assets {
-            let (asset_type, faucet, amount) = match asset {
+            let (asset_type, faucet, amount, asset_data) = match asset {
                 Asset::Fungible(fungible_asset) => {
                     let (faucet, amount) =
                         faucet_details_map.format_fungible_asset(&fungible_asset)?;
-                    ("Fungible Asset", faucet, amount)
-                },
-                Asset::NonFungible(non_fungible_asset) => {
-                    // TODO: Display non-fungible assets more clearly.
-                    ("Non Fungible Asset", non_fungible_asset.faucet_id().to_hex(), 1.0.to_string())
+                    ("Fungible Asset", faucet, amount, "-".to_string())
                 },
+                Asset::NonFungible(non_fungible_asset) => (
+                    "Non Fungible Asset",
+                    non_fungible_asset.faucet_id().to_hex(),
+                    1.to_string(),
+                    Digest::from(Word::from(non_fungible_asset)).to_hex(),
+                ),
             };
-            table.add_row(vec![asset_type, &faucet, &amount.to_string()]);
+            table.add_row(vec![asset_type, &faucet, &amount.to_string(), &asset_data]);
         }
 
         println!("{table}\n");
@@ -21,6 +21,11 @@ pub struct ImportCmd {
     /// Paths to the files that contains the account/note data
     #[arg()]
     filenames: Vec<PathBuf>,
+
+    /// Import accounts without storing their secret keys, for clients whose transactions are
+    /// signed by an air-gapped signer
+    #[clap(long, default_value_t = false)]
+    without_keys: bool,
 }
 
 impl ImportCmd {
@@ -34,7 +39,7 @@ impl ImportCmd {
                 let note_id = client.import_note(note_file).await.map_err(|err| err.to_string())?;
                 println!("Succesfully imported note {}", note_id.inner());
             } else {
-                let account_id = import_account(&mut client, filename)
+                let account_id = import_account(&mut client, filename, self.without_keys)
                     .await
                     .map_err(|_| format!("Failed to parse file {}", filename.to_string_lossy()))?;
                 println!("Succesfully imported account {}", account_id);
@@ -54,6 +59,7 @@ impl ImportCmd {
 async fn import_account(
     client: &mut Client<impl FeltRng>,
     filename: &PathBuf,
+    without_keys: bool,
 ) -> Result<AccountId, String> {
     info!(
         "Attempting to import account data from {}...",
@@ -64,7 +70,13 @@ async fn import_account(
         AccountData::read_from_bytes(&account_data_file_contents).map_err(|err| err.to_string())?;
     let account_id = account_data.account.id();
 
-    client.import_account(account_data).await?;
+    if without_keys {
+        // The seed is only needed to execute transactions against new accounts
+        let account_seed = account_data.account_seed.filter(|_| account_data.account.is_new());
+        client.import_account_without_auth(&account_data.account, account_seed).await?;
+    } else {
+        client.import_account(account_data).await?;
+    }
 
     Ok(account_id)
 }
@@ -17,6 +17,10 @@ pub struct InitCmd {
     #[clap(long)]
     rpc: Option<String>,
 
+    /// API key sent to the node as a bearer token on every RPC request
+    #[clap(long)]
+    rpc_api_key: Option<String>,
+
     /// Store file path
     #[clap(long)]
     store_path: Option<String>,
@@ -47,6 +51,8 @@ impl InitCmd {
             cli_config.rpc.endpoint = endpoint;
         }
 
+        cli_config.rpc.api_key = self.rpc_api_key.clone();
+
         if let Some(path) = &self.store_path {
             cli_config.store.database_filepath = path.to_string();
         }
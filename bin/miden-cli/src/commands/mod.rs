@@ -5,6 +5,10 @@ pub mod init;
 pub mod new_account;
 pub mod new_transactions;
 pub mod notes;
+pub mod payments;
+pub mod policy;
+pub mod sign;
+pub mod swaps;
 pub mod sync;
 pub mod tags;
 pub mod transactions;
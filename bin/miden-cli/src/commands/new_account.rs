@@ -31,26 +31,33 @@ pub struct NewFaucetCmd {
 
 impl NewFaucetCmd {
     pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
-        if self.non_fungible {
-            todo!("Non-fungible faucets are not supported yet");
-        }
+        let client_template = if self.non_fungible {
+            if self.token_symbol.is_some() || self.decimals.is_some() || self.max_supply.is_some() {
+                return Err(
+                    "`token-symbol`, `decimals` and `max-supply` flags can't be used for a non-fungible faucet"
+                        .to_string(),
+                );
+            }
 
-        if self.token_symbol.is_none() || self.decimals.is_none() || self.max_supply.is_none() {
-            return Err(
-                "`token-symbol`, `decimals` and `max-supply` flags must be provided for a fungible faucet"
-                    .to_string(),
-            );
-        }
+            AccountTemplate::NonFungibleFaucet { storage_mode: self.storage_mode }
+        } else {
+            if self.token_symbol.is_none() || self.decimals.is_none() || self.max_supply.is_none() {
+                return Err(
+                    "`token-symbol`, `decimals` and `max-supply` flags must be provided for a fungible faucet"
+                        .to_string(),
+                );
+            }
 
-        let decimals = self.decimals.expect("decimals must be provided");
-        let token_symbol = self.token_symbol.clone().expect("token symbol must be provided");
+            let decimals = self.decimals.expect("decimals must be provided");
+            let token_symbol = self.token_symbol.clone().expect("token symbol must be provided");
 
-        let client_template = AccountTemplate::FungibleFaucet {
-            token_symbol: TokenSymbol::new(token_symbol.as_str())
-                .map_err(|err| format!("error: token symbol is invalid: {}", err))?,
-            decimals,
-            max_supply: self.max_supply.expect("max supply must be provided"),
-            storage_mode: self.storage_mode,
+            AccountTemplate::FungibleFaucet {
+                token_symbol: TokenSymbol::new(token_symbol.as_str())
+                    .map_err(|err| format!("error: token symbol is invalid: {}", err))?,
+                decimals,
+                max_supply: self.max_supply.expect("max supply must be provided"),
+                storage_mode: self.storage_mode,
+            }
         };
 
         let (new_account, _account_seed) = client.new_account(client_template).await?;
@@ -1,20 +1,24 @@
-use std::io;
+use std::{fs, io, path::PathBuf, str::FromStr, sync::Arc};
 
-use clap::{Parser, ValueEnum};
+use clap::{Args, Parser, ValueEnum};
 use miden_client::{
     accounts::AccountId,
-    assets::{FungibleAsset, NonFungibleDeltaAction},
+    assets::{Asset, FungibleAsset},
     crypto::{Digest, FeltRng},
     notes::{build_swap_tag, get_input_note_with_id_prefix, NoteType as MidenNoteType},
     transactions::{
-        PaymentTransactionData, SwapTransactionData, TransactionRequest, TransactionResult,
+        AssetChange, LocalTransactionProver, PaymentTransactionData, StorageSlotChange,
+        SwapTransactionData, TransactionProver, TransactionRequest, TransactionResult,
     },
     Client,
 };
+use miden_tx_prover::RemoteTransactionProver;
 use tracing::info;
 
 use crate::{
+    config::CliConfig,
     create_dynamic_table,
+    faucet_details_map::FaucetDetailsMap,
     utils::{
         get_input_acc_id_by_prefix_or_default, load_faucet_details_map, parse_account_id,
         SHARED_TOKEN_DOCUMENTATION,
@@ -36,6 +40,70 @@ impl From<&NoteType> for MidenNoteType {
     }
 }
 
+/// Prover used to prove a transaction, overriding the one set in the config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProverArg {
+    /// Prove the transaction locally.
+    Local,
+    /// Prove the transaction with the proving service at `remote_prover_endpoint`.
+    Remote,
+    /// Prove the transaction with the proving service at the URL.
+    Url(String),
+}
+
+impl FromStr for ProverArg {
+    type Err = String;
+
+    fn from_str(prover: &str) -> Result<Self, Self::Err> {
+        match prover {
+            "local" => Ok(ProverArg::Local),
+            "remote" => Ok(ProverArg::Remote),
+            url if url.contains("://") => Ok(ProverArg::Url(url.to_string())),
+            _ => Err(format!("Invalid prover {prover}, expected `local`, `remote` or a URL")),
+        }
+    }
+}
+
+impl ProverArg {
+    /// Returns the prover the argument refers to.
+    pub(crate) fn tx_prover(
+        &self,
+        cli_config: &CliConfig,
+    ) -> Result<Arc<dyn TransactionProver>, String> {
+        match self {
+            ProverArg::Local => Ok(Arc::new(LocalTransactionProver::new(Default::default()))),
+            ProverArg::Remote => {
+                let endpoint = cli_config
+                    .remote_prover_endpoint
+                    .as_ref()
+                    .ok_or("No `remote_prover_endpoint` is set in the config")?;
+                Ok(Arc::new(RemoteTransactionProver::new(&endpoint.to_string())))
+            },
+            ProverArg::Url(url) => Ok(Arc::new(RemoteTransactionProver::new(url))),
+        }
+    }
+}
+
+/// Prover argument of the commands that execute and submit a transaction.
+#[derive(Debug, Clone, Args)]
+pub struct ProverArgs {
+    /// Prover used for this transaction: `local`, `remote` (the `remote_prover_endpoint` of the
+    /// config) or the URL of a proving service. Defaults to the prover set in the config
+    #[clap(long, value_name = "local|remote|URL")]
+    prover: Option<ProverArg>,
+}
+
+impl ProverArgs {
+    /// Returns the prover selected by the argument, or `None` if the client's prover should be
+    /// used.
+    pub(crate) fn tx_prover(
+        &self,
+        cli_config: &CliConfig,
+    ) -> Result<Option<Arc<dyn TransactionProver>>, String> {
+        self.prover.as_ref().map(|prover| prover.tx_prover(cli_config)).transpose()
+    }
+}
+
 #[derive(Debug, Parser, Clone)]
 /// Mint tokens from a fungible faucet to a wallet.
 pub struct MintCmd {
@@ -52,11 +120,18 @@ pub struct MintCmd {
     /// Flag to submit the executed transaction without asking for confirmation
     #[clap(long, default_value_t = false)]
     force: bool,
+    #[clap(flatten)]
+    prover: ProverArgs,
 }
 
 impl MintCmd {
-    pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
         let force = self.force;
+        let tx_prover = self.prover.tx_prover(cli_config)?;
         let faucet_details_map = load_faucet_details_map()?;
 
         let fungible_asset = faucet_details_map.parse_fungible_asset(&self.asset)?;
@@ -71,8 +146,14 @@ impl MintCmd {
         )
         .map_err(|err| err.to_string())?;
 
-        execute_transaction(&mut client, fungible_asset.faucet_id(), transaction_request, force)
-            .await
+        execute_transaction(
+            &mut client,
+            fungible_asset.faucet_id(),
+            transaction_request,
+            force,
+            tx_prover,
+        )
+        .await
     }
 }
 
@@ -96,6 +177,8 @@ pub struct SendCmd {
     /// Flag to submit the executed transaction without asking for confirmation
     #[clap(long, default_value_t = false)]
     force: bool,
+    #[clap(flatten)]
+    prover: ProverArgs,
     /// Set the recall height for the transaction. If the note was not consumed by this height, the
     /// sender may consume it back.
     ///
@@ -105,8 +188,13 @@ pub struct SendCmd {
 }
 
 impl SendCmd {
-    pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
         let force = self.force;
+        let tx_prover = self.prover.tx_prover(cli_config)?;
 
         let faucet_details_map = load_faucet_details_map()?;
 
@@ -131,10 +219,108 @@ impl SendCmd {
         )
         .map_err(|err| err.to_string())?;
 
-        execute_transaction(&mut client, sender_account_id, transaction_request, force).await
+        execute_transaction(&mut client, sender_account_id, transaction_request, force, tx_prover)
+            .await
     }
 }
 
+#[derive(Debug, Parser, Clone)]
+/// Create a transaction that sends a pay-to-id note to each recipient listed in a CSV file.
+///
+/// Each line of the file must have the form `<TARGET_ACCOUNT_ID>,<AMOUNT>,<TOKEN>`, where
+/// `<TOKEN>` is either a faucet ID (with `<AMOUNT>` in base units) or a token symbol tracked in the
+/// token symbol map file (with `<AMOUNT>` as a decimal number). An optional header line starting
+/// with `target`, empty lines and lines starting with `#` are ignored.
+pub struct SendBatchCmd {
+    /// Sender account ID or its hex prefix. If none is provided, the default account's ID is used
+    /// instead
+    #[clap(short = 's', long = "sender")]
+    sender_account_id: Option<String>,
+
+    /// Path to the CSV file with the payments
+    #[clap(short, long)]
+    file: PathBuf,
+
+    #[clap(short, long, value_enum)]
+    note_type: NoteType,
+    /// Flag to submit the executed transaction without asking for confirmation
+    #[clap(long, default_value_t = false)]
+    force: bool,
+    #[clap(flatten)]
+    prover: ProverArgs,
+}
+
+impl SendBatchCmd {
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
+        let force = self.force;
+        let tx_prover = self.prover.tx_prover(cli_config)?;
+
+        let faucet_details_map = load_faucet_details_map()?;
+
+        // try to use either the provided argument or the default account
+        let sender_account_id =
+            get_input_acc_id_by_prefix_or_default(&client, self.sender_account_id.clone()).await?;
+
+        let contents = fs::read_to_string(&self.file).map_err(|err| {
+            format!("Failed to read payments file {}: {err}", self.file.display())
+        })?;
+
+        let mut payments = Vec::new();
+        for (line_index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty()
+                || line.starts_with('#')
+                || (line_index == 0 && line.to_lowercase().starts_with("target"))
+            {
+                continue;
+            }
+
+            let payment = parse_payment_line(&client, &faucet_details_map, sender_account_id, line)
+                .await
+                .map_err(|err| format!("Invalid payment on line {}: {err}", line_index + 1))?;
+            payments.push(payment);
+        }
+
+        println!("Sending {} payments from account {}", payments.len(), sender_account_id);
+
+        let transaction_request =
+            TransactionRequest::pay_to_many(payments, (&self.note_type).into(), client.rng())
+                .map_err(|err| err.to_string())?;
+
+        execute_transaction(&mut client, sender_account_id, transaction_request, force, tx_prover)
+            .await
+    }
+}
+
+/// Parses a `<TARGET_ACCOUNT_ID>,<AMOUNT>,<TOKEN>` line of a payments file.
+async fn parse_payment_line(
+    client: &Client<impl FeltRng>,
+    faucet_details_map: &FaucetDetailsMap,
+    sender_account_id: AccountId,
+    line: &str,
+) -> Result<PaymentTransactionData, String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let [target, amount, token] = fields.as_slice() else {
+        return Err(format!(
+            "expected `<TARGET_ACCOUNT_ID>,<AMOUNT>,<TOKEN>` but found {} fields",
+            fields.len()
+        ));
+    };
+
+    let target_account_id = parse_account_id(client, target).await?;
+    let fungible_asset = faucet_details_map.parse_fungible_asset(&format!("{amount}::{token}"))?;
+
+    Ok(PaymentTransactionData::new(
+        vec![fungible_asset.into()],
+        sender_account_id,
+        target_account_id,
+    ))
+}
+
 #[derive(Debug, Parser, Clone)]
 /// Create a swap transaction.
 pub struct SwapCmd {
@@ -156,11 +342,18 @@ pub struct SwapCmd {
     /// Flag to submit the executed transaction without asking for confirmation
     #[clap(long, default_value_t = false)]
     force: bool,
+    #[clap(flatten)]
+    prover: ProverArgs,
 }
 
 impl SwapCmd {
-    pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
         let force = self.force;
+        let tx_prover = self.prover.tx_prover(cli_config)?;
 
         let faucet_details_map = load_faucet_details_map()?;
 
@@ -186,7 +379,8 @@ impl SwapCmd {
         )
         .map_err(|err| err.to_string())?;
 
-        execute_transaction(&mut client, sender_account_id, transaction_request, force).await?;
+        execute_transaction(&mut client, sender_account_id, transaction_request, force, tx_prover)
+            .await?;
 
         let payback_note_tag: u32 = build_swap_tag(
             (&self.note_type).into(),
@@ -199,6 +393,7 @@ impl SwapCmd {
             "To receive updates about the payback Swap Note run `miden tags add {}`",
             payback_note_tag
         );
+        println!("Run `miden swaps` to follow the status of the order.");
 
         Ok(())
     }
@@ -218,11 +413,18 @@ pub struct ConsumeNotesCmd {
     /// Flag to submit the executed transaction without asking for confirmation
     #[clap(short, long, default_value_t = false)]
     force: bool,
+    #[clap(flatten)]
+    prover: ProverArgs,
 }
 
 impl ConsumeNotesCmd {
-    pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
         let force = self.force;
+        let tx_prover = self.prover.tx_prover(cli_config)?;
 
         let mut list_of_notes = Vec::new();
         for note_id in &self.list_of_notes {
@@ -248,23 +450,35 @@ impl ConsumeNotesCmd {
 
         let transaction_request = TransactionRequest::consume_notes(list_of_notes);
 
-        execute_transaction(&mut client, account_id, transaction_request, force).await
+        execute_transaction(&mut client, account_id, transaction_request, force, tx_prover).await
     }
 }
 
 // EXECUTE TRANSACTION
 // ================================================================================================
 
-async fn execute_transaction(
+pub(crate) async fn execute_transaction(
     client: &mut Client<impl FeltRng>,
     account_id: AccountId,
     transaction_request: TransactionRequest,
     force: bool,
+    tx_prover: Option<Arc<dyn TransactionProver>>,
 ) -> Result<(), String> {
     println!("Executing transaction...");
     let transaction_execution_result =
         client.new_transaction(account_id, transaction_request).await?;
 
+    confirm_and_submit_transaction(client, transaction_execution_result, force, tx_prover).await
+}
+
+/// Shows the effects of an executed transaction and, once confirmed, proves and submits it with
+/// `tx_prover`, or with the client's prover if it's not provided.
+pub(crate) async fn confirm_and_submit_transaction(
+    client: &mut Client<impl FeltRng>,
+    transaction_execution_result: TransactionResult,
+    force: bool,
+    tx_prover: Option<Arc<dyn TransactionProver>>,
+) -> Result<(), String> {
     // Show delta and ask for confirmation
     print_transaction_details(&transaction_execution_result)?;
     if !force {
@@ -287,7 +501,14 @@ async fn execute_transaction(
         .map(|note| note.id())
         .collect::<Vec<_>>();
 
-    client.submit_transaction(transaction_execution_result).await?;
+    match tx_prover {
+        Some(tx_prover) => {
+            client
+                .submit_transaction_with_prover(transaction_execution_result, tx_prover)
+                .await?
+        },
+        None => client.submit_transaction(transaction_execution_result).await?,
+    }
 
     println!("Succesfully created transaction.");
     println!("Transaction ID: {}", transaction_id);
@@ -302,99 +523,173 @@ async fn execute_transaction(
     Ok(())
 }
 
-fn print_transaction_details(transaction_result: &TransactionResult) -> Result<(), String> {
+pub(crate) fn print_transaction_details(
+    transaction_result: &TransactionResult,
+) -> Result<(), String> {
+    let summary = transaction_result.summary();
+    let faucet_details_map = load_faucet_details_map()?;
+
     println!("The transaction will have the following effects:\n");
 
     // INPUT NOTES
-    let input_note_ids = transaction_result
-        .executed_transaction()
-        .input_notes()
-        .iter()
-        .map(|note| note.id())
-        .collect::<Vec<_>>();
-    if input_note_ids.is_empty() {
+    if summary.consumed_notes().is_empty() {
         println!("No notes will be consumed.");
     } else {
         println!("The following notes will be consumed:");
-        for input_note_id in input_note_ids {
-            println!("\t- {}", input_note_id.to_hex());
+        let mut table = create_dynamic_table(&["Note ID", "Sender", "Assets"]);
+        for note in summary.consumed_notes() {
+            table.add_row(vec![
+                note.id.to_hex(),
+                note.sender.to_hex(),
+                format_assets(&faucet_details_map, &note.assets)?,
+            ]);
         }
+        println!("{table}");
     }
     println!();
 
     // OUTPUT NOTES
-    let output_note_count = transaction_result.executed_transaction().output_notes().iter().count();
-    if output_note_count == 0 {
+    if summary.created_notes().is_empty() {
         println!("No notes will be created as a result of this transaction.");
     } else {
-        println!("{output_note_count} notes will be created as a result of this transaction.");
+        println!("The following notes will be created:");
+        let mut table = create_dynamic_table(&["Note ID", "Type", "Tag", "Recipient", "Assets"]);
+        for note in summary.created_notes() {
+            let assets = match &note.assets {
+                Some(assets) => format_assets(&faucet_details_map, assets)?,
+                None => "-".to_string(),
+            };
+            table.add_row(vec![
+                note.id.to_hex(),
+                format!("{:?}", note.note_type),
+                note.tag.to_string(),
+                note.recipient.map(|recipient| recipient.to_hex()).unwrap_or("-".to_string()),
+                assets,
+            ]);
+        }
+        println!("{table}");
     }
     println!();
 
     // ACCOUNT CHANGES
-    println!(
-        "The account with ID {} will be modified as follows:",
-        transaction_result.executed_transaction().account_id()
-    );
-
-    let account_delta = transaction_result.account_delta();
+    println!("The account with ID {} will be modified as follows:", summary.account_id());
 
-    let has_storage_changes = !account_delta.storage().is_empty();
-    if has_storage_changes {
+    if summary.storage_changes().is_empty() {
+        println!("Account Storage will not be changed.");
+    } else {
         let mut table = create_dynamic_table(&["Storage Slot", "Effect"]);
 
-        for (updated_item_slot, new_value) in account_delta.storage().values() {
-            let value_digest: Digest = new_value.into();
-            table.add_row(vec![
-                updated_item_slot.to_string(),
-                format!("Updated ({})", value_digest.to_hex()),
-            ]);
+        for change in summary.storage_changes() {
+            let effect = match change {
+                StorageSlotChange::Value { value, .. } => {
+                    let value_digest: Digest = (*value).into();
+                    format!("Updated ({})", value_digest.to_hex())
+                },
+                StorageSlotChange::Map { updated_keys, .. } => {
+                    format!("Updated {updated_keys} map entries")
+                },
+            };
+            table.add_row(vec![change.index().to_string(), effect]);
         }
 
         println!("Storage changes:");
         println!("{table}");
-    } else {
-        println!("Account Storage will not be changed.");
     }
 
-    if !account_delta.vault().is_empty() {
-        let faucet_details_map = load_faucet_details_map()?;
+    if summary.asset_changes().is_empty() {
+        println!("Account Vault will not be changed.");
+    } else {
         let mut table = create_dynamic_table(&["Asset Type", "Faucet ID", "Amount"]);
 
-        for (faucet_id, amount) in account_delta.vault().fungible().iter() {
-            let asset = FungibleAsset::new(*faucet_id, amount.unsigned_abs())
-                .map_err(|err| err.to_string())?;
-            let (faucet_fmt, amount_fmt) = faucet_details_map.format_fungible_asset(&asset)?;
-
-            if amount.is_positive() {
-                table.add_row(vec!["Fungible Asset", &faucet_fmt, &format!("+{}", amount_fmt)]);
-            } else {
-                table.add_row(vec!["Fungible Asset", &faucet_fmt, &format!("-{}", amount_fmt)]);
-            }
-        }
-
-        for (asset, action) in account_delta.vault().non_fungible().iter() {
-            match action {
-                NonFungibleDeltaAction::Add => {
-                    table.add_row(vec!["Non Fungible Asset", &asset.faucet_id().to_hex(), "1"]);
+        for change in summary.asset_changes() {
+            match change {
+                AssetChange::Fungible { faucet_id, amount } => {
+                    let asset = FungibleAsset::new(*faucet_id, amount.unsigned_abs())
+                        .map_err(|err| err.to_string())?;
+                    let (faucet_fmt, amount_fmt) =
+                        faucet_details_map.format_fungible_asset(&asset)?;
+
+                    if amount.is_positive() {
+                        table.add_row(vec![
+                            "Fungible Asset",
+                            &faucet_fmt,
+                            &format!("+{}", amount_fmt),
+                        ]);
+                    } else {
+                        table.add_row(vec![
+                            "Fungible Asset",
+                            &faucet_fmt,
+                            &format!("-{}", amount_fmt),
+                        ]);
+                    }
                 },
-                NonFungibleDeltaAction::Remove => {
-                    table.add_row(vec!["Non Fungible Asset", &asset.faucet_id().to_hex(), "-1"]);
+                AssetChange::NonFungible { asset, added } => {
+                    let amount = if *added { "1" } else { "-1" };
+                    table.add_row(vec!["Non Fungible Asset", &asset.faucet_id().to_hex(), amount]);
                 },
             }
         }
 
         println!("Vault changes:");
         println!("{table}");
-    } else {
-        println!("Account Vault will not be changed.");
     }
 
-    if let Some(new_nonce) = account_delta.nonce() {
-        println!("New nonce: {new_nonce}.")
+    if let Some(new_nonce) = summary.final_nonce() {
+        println!("New nonce: {new_nonce} (was {}).", summary.initial_nonce())
     } else {
         println!("No nonce changes.")
     }
 
+    println!(
+        "The transaction was executed against block {} and expires after block {}.",
+        summary.block_num(),
+        summary.expiration_block_num()
+    );
+
+    let stats = transaction_result.stats();
+    let mut table = create_dynamic_table(&["Phase", "Cycles"]);
+    table.add_row(vec!["Prologue".to_string(), stats.prologue_cycles().to_string()]);
+    table.add_row(vec![
+        "Notes processing".to_string(),
+        stats.notes_processing_cycles().to_string(),
+    ]);
+    for (note_id, cycles) in stats.note_execution_cycles() {
+        table.add_row(vec![format!("  Note {}", note_id.to_hex()), cycles.to_string()]);
+    }
+    table.add_row(vec!["Transaction script".to_string(), stats.tx_script_cycles().to_string()]);
+    table.add_row(vec!["Epilogue".to_string(), stats.epilogue_cycles().to_string()]);
+    table.add_row(vec!["Total".to_string(), stats.total_cycles().to_string()]);
+
+    println!("Execution cost:");
+    println!("{table}");
+    println!("Estimated trace length to prove: {} rows.", stats.trace_length());
+
     Ok(())
 }
+
+/// Formats a list of assets as a comma separated list of amounts and faucets.
+pub(crate) fn format_assets(
+    faucet_details_map: &FaucetDetailsMap,
+    assets: &[Asset],
+) -> Result<String, String> {
+    if assets.is_empty() {
+        return Ok("-".to_string());
+    }
+
+    let mut formatted_assets = Vec::with_capacity(assets.len());
+    for asset in assets {
+        let formatted_asset = match asset {
+            Asset::Fungible(fungible_asset) => {
+                let (faucet_fmt, amount_fmt) =
+                    faucet_details_map.format_fungible_asset(fungible_asset)?;
+                format!("{amount_fmt} {faucet_fmt}")
+            },
+            Asset::NonFungible(non_fungible_asset) => {
+                format!("1 {}", non_fungible_asset.faucet_id().to_hex())
+            },
+        };
+        formatted_assets.push(formatted_asset);
+    }
+
+    Ok(formatted_assets.join(", "))
+}
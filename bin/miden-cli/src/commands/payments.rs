@@ -0,0 +1,206 @@
+use miden_client::{
+    crypto::FeltRng,
+    transactions::{PaymentExecution, PaymentOutcome, PaymentSchedule, PaymentTransactionData},
+    Client,
+};
+
+use super::new_transactions::{format_assets, NoteType};
+use crate::{
+    create_dynamic_table,
+    utils::{
+        get_input_acc_id_by_prefix_or_default, load_faucet_details_map, parse_account_id,
+        SHARED_TOKEN_DOCUMENTATION,
+    },
+    Parser,
+};
+
+#[derive(Debug, Parser, Clone)]
+#[clap(
+    about = "View, schedule and cancel recurring payments. Due payments are made by `miden sync \
+    --watch` and `miden payments --run`. Defaults to `list` command."
+)]
+pub struct PaymentsCmd {
+    /// List the recurring payments stored in the client
+    #[clap(short, long, group = "action")]
+    list: bool,
+
+    /// Schedule a new payment from the sender to the target account
+    #[clap(short, long, group = "action")]
+    schedule: bool,
+
+    /// Show the attempts made for a payment, given its ID
+    #[clap(long, group = "action", value_name = "ID")]
+    history: Option<u32>,
+
+    /// Cancel a payment, given its ID
+    #[clap(short, long, group = "action", value_name = "ID")]
+    cancel: Option<u32>,
+
+    /// Make the payments that are due at the current sync height
+    #[clap(short, long, group = "action")]
+    run: bool,
+
+    /// Account that makes the payment. If not provided, the default account is used
+    #[clap(long, value_name = "ID")]
+    sender: Option<String>,
+
+    /// Account that receives the payment
+    #[clap(short, long, value_name = "ID")]
+    target: Option<String>,
+
+    /// Asset sent by each payment
+    #[clap(
+        short,
+        long,
+        value_name = "AMOUNT::TOKEN",
+        help = format!("Asset sent by each payment.\n{SHARED_TOKEN_DOCUMENTATION}")
+    )]
+    asset: Option<String>,
+
+    /// Type of the notes created by the payment
+    #[clap(short, long, value_enum, default_value = "private")]
+    note_type: NoteType,
+
+    /// Block at which the first payment is due. Defaults to the current sync height
+    #[clap(long, value_name = "BLOCK")]
+    start_block: Option<u32>,
+
+    /// Number of blocks between payments. If not provided, a single payment is made
+    #[clap(short, long, value_name = "BLOCKS")]
+    every: Option<u32>,
+}
+
+impl PaymentsCmd {
+    pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
+        match self {
+            PaymentsCmd { schedule: true, .. } => self.schedule_payment(client).await,
+            PaymentsCmd { history: Some(payment_id), .. } => {
+                show_history(client, *payment_id).await
+            },
+            PaymentsCmd { cancel: Some(payment_id), .. } => {
+                client.cancel_recurring_payment(*payment_id).await?;
+                println!("Recurring payment {payment_id} cancelled");
+                Ok(())
+            },
+            PaymentsCmd { run: true, .. } => run_due_payments(client).await,
+            _ => list_payments(client).await,
+        }
+    }
+
+    async fn schedule_payment(&self, client: Client<impl FeltRng>) -> Result<(), String> {
+        let faucet_details_map = load_faucet_details_map()?;
+
+        let sender_account_id =
+            get_input_acc_id_by_prefix_or_default(&client, self.sender.clone()).await?;
+        let target = self.target.as_ref().ok_or("A target account is required")?;
+        let target_account_id = parse_account_id(&client, target).await?;
+        let asset = self.asset.as_ref().ok_or("An asset is required")?;
+        let fungible_asset = faucet_details_map.parse_fungible_asset(asset)?;
+
+        let start_block = match self.start_block {
+            Some(start_block) => start_block,
+            None => client.get_sync_height().await?,
+        };
+        let schedule = match self.every {
+            Some(interval) => PaymentSchedule::every(interval, start_block),
+            None => PaymentSchedule::once_at(start_block),
+        };
+
+        let payment_data = PaymentTransactionData::new(
+            vec![fungible_asset.into()],
+            sender_account_id,
+            target_account_id,
+        );
+        let payment = client
+            .schedule_payment(payment_data, (&self.note_type).into(), schedule)
+            .await?;
+
+        println!("Recurring payment {} scheduled, starting at block {start_block}", payment.id());
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+async fn list_payments(client: Client<impl FeltRng>) -> Result<(), String> {
+    let faucet_details_map = load_faucet_details_map()?;
+
+    let mut table = create_dynamic_table(&[
+        "ID",
+        "Sender",
+        "Target",
+        "Assets",
+        "Note Type",
+        "Every (Blocks)",
+        "Next Block",
+        "Attempts",
+    ]);
+    for payment in client.get_recurring_payments().await? {
+        table.add_row(vec![
+            payment.id().to_string(),
+            payment.payment_data().account_id().to_hex(),
+            payment.payment_data().target_account_id().to_hex(),
+            format_assets(&faucet_details_map, payment.payment_data().assets())?,
+            format!("{:?}", payment.note_type()),
+            payment
+                .schedule()
+                .interval()
+                .map_or("-".to_string(), |interval| interval.to_string()),
+            payment.next_block().map_or("completed".to_string(), |block| block.to_string()),
+            payment.history().len().to_string(),
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+async fn show_history(client: Client<impl FeltRng>, payment_id: u32) -> Result<(), String> {
+    let payment = client
+        .get_recurring_payments()
+        .await?
+        .into_iter()
+        .find(|payment| payment.id() == payment_id)
+        .ok_or(format!("No recurring payment with ID {payment_id} is stored"))?;
+
+    let mut table = create_dynamic_table(&["Block", "Result"]);
+    for execution in payment.history() {
+        table.add_row(vec![execution.block_num.to_string(), format_outcome(&execution.outcome)]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+async fn run_due_payments(mut client: Client<impl FeltRng>) -> Result<(), String> {
+    let executions = client.run_due_payments().await?;
+    if executions.is_empty() {
+        println!("No payments are due");
+        return Ok(());
+    }
+
+    print_payment_executions(&executions);
+    Ok(())
+}
+
+/// Prints the attempts made to run due payments, along with the ID of their payment.
+pub(crate) fn print_payment_executions(executions: &[(u32, PaymentExecution)]) {
+    let mut table = create_dynamic_table(&["Payment ID", "Block", "Result"]);
+    for (payment_id, execution) in executions {
+        table.add_row(vec![
+            payment_id.to_string(),
+            execution.block_num.to_string(),
+            format_outcome(&execution.outcome),
+        ]);
+    }
+
+    println!("{table}");
+}
+
+fn format_outcome(outcome: &PaymentOutcome) -> String {
+    match outcome {
+        PaymentOutcome::Submitted(transaction_id) => format!("Submitted in {transaction_id}"),
+        PaymentOutcome::Failed(err) => format!("Failed: {err}"),
+    }
+}
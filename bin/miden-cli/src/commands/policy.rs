@@ -0,0 +1,194 @@
+use miden_client::{
+    accounts::AccountId,
+    assets::FungibleAsset,
+    crypto::FeltRng,
+    transactions::{SpendingLimit, SpendingPolicy},
+    Client,
+};
+
+use super::new_transactions::NoteType;
+use crate::{
+    create_dynamic_table,
+    faucet_details_map::FaucetDetailsMap,
+    utils::{
+        get_input_acc_id_by_prefix_or_default, load_faucet_details_map, parse_account_id,
+        SHARED_TOKEN_DOCUMENTATION,
+    },
+    Parser,
+};
+
+#[derive(Default, Debug, Parser, Clone)]
+#[clap(
+    about = "View and manage the spending policy of an account, which the client checks before \
+    executing its transactions. Defaults to showing the policy."
+)]
+pub struct PolicyCmd {
+    /// Account whose policy is managed. If not provided, the default account is used
+    #[clap(short, long, value_name = "ID")]
+    account: Option<String>,
+
+    /// Maximum amount of an asset a single transaction can send. Can be repeated
+    #[clap(
+        long,
+        value_name = "AMOUNT::TOKEN",
+        help = format!("Maximum amount of an asset a single transaction can send. Can be repeated.\n{SHARED_TOKEN_DOCUMENTATION}")
+    )]
+    tx_limit: Vec<String>,
+
+    /// Maximum amount of an asset the transactions of a day can send. Can be repeated
+    #[clap(
+        long,
+        value_name = "AMOUNT::TOKEN",
+        help = format!("Maximum amount of an asset the transactions of a day can send. Can be repeated.\n{SHARED_TOKEN_DOCUMENTATION}")
+    )]
+    daily_limit: Vec<String>,
+
+    /// Account notes can be sent to. Once a recipient is allowed, notes can only be sent to
+    /// allowed recipients. Can be repeated
+    #[clap(long, value_name = "ID")]
+    allow_recipient: Vec<String>,
+
+    /// Type of the notes the account can't create. Can be repeated
+    #[clap(long, value_enum)]
+    block_note_type: Vec<NoteType>,
+
+    /// Remove the policy of the account
+    #[clap(
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["tx_limit", "daily_limit", "allow_recipient", "block_note_type"]
+    )]
+    remove: bool,
+}
+
+impl PolicyCmd {
+    pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
+        let account_id =
+            get_input_acc_id_by_prefix_or_default(&client, self.account.clone()).await?;
+        let faucet_details_map = load_faucet_details_map()?;
+
+        if self.remove {
+            client.remove_spending_policy(account_id).await?;
+            println!("Spending policy of account {} removed", account_id.to_hex());
+            return Ok(());
+        }
+
+        if self.updates_policy() {
+            let policy = client.get_spending_policy(account_id).await?.unwrap_or_default();
+            let policy = self.update_policy(&client, &faucet_details_map, policy).await?;
+            client.set_spending_policy(account_id, policy).await?;
+        }
+
+        show_policy(&client, &faucet_details_map, account_id).await
+    }
+
+    /// Returns whether the command changes the policy instead of only showing it.
+    fn updates_policy(&self) -> bool {
+        !self.tx_limit.is_empty()
+            || !self.daily_limit.is_empty()
+            || !self.allow_recipient.is_empty()
+            || !self.block_note_type.is_empty()
+    }
+
+    /// Adds the restrictions of the command to the policy, replacing the limits of the faucets
+    /// it sets.
+    async fn update_policy(
+        &self,
+        client: &Client<impl FeltRng>,
+        faucet_details_map: &FaucetDetailsMap,
+        mut policy: SpendingPolicy,
+    ) -> Result<SpendingPolicy, String> {
+        for tx_limit in &self.tx_limit {
+            let asset = faucet_details_map.parse_fungible_asset(tx_limit)?;
+            let limit = SpendingLimit {
+                per_transaction: Some(asset.amount()),
+                ..current_limit(&policy, &asset)
+            };
+            policy = policy.with_limit(asset.faucet_id(), limit);
+        }
+
+        for daily_limit in &self.daily_limit {
+            let asset = faucet_details_map.parse_fungible_asset(daily_limit)?;
+            let limit = SpendingLimit {
+                daily: Some(asset.amount()),
+                ..current_limit(&policy, &asset)
+            };
+            policy = policy.with_limit(asset.faucet_id(), limit);
+        }
+
+        for recipient in &self.allow_recipient {
+            policy = policy.with_allowed_recipient(parse_account_id(client, recipient).await?);
+        }
+
+        for note_type in &self.block_note_type {
+            policy = policy.with_blocked_note_type(note_type.into());
+        }
+
+        Ok(policy)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn current_limit(policy: &SpendingPolicy, asset: &FungibleAsset) -> SpendingLimit {
+    policy.limits().get(&asset.faucet_id()).copied().unwrap_or_default()
+}
+
+async fn show_policy(
+    client: &Client<impl FeltRng>,
+    faucet_details_map: &FaucetDetailsMap,
+    account_id: AccountId,
+) -> Result<(), String> {
+    let Some(policy) = client.get_spending_policy(account_id).await? else {
+        println!("Account {} has no spending policy", account_id.to_hex());
+        return Ok(());
+    };
+    let daily_spending = client.get_daily_spending(account_id).await?;
+
+    let format_amount = |faucet_id, amount: Option<u64>| -> Result<String, String> {
+        let Some(amount) = amount else {
+            return Ok("-".to_string());
+        };
+        let asset = FungibleAsset::new(faucet_id, amount).map_err(|err| err.to_string())?;
+        let (_, amount) = faucet_details_map.format_fungible_asset(&asset)?;
+        Ok(amount)
+    };
+
+    let mut table = create_dynamic_table(&["Faucet", "Per Transaction", "Daily", "Spent Today"]);
+    for (faucet_id, limit) in policy.limits() {
+        table.add_row(vec![
+            faucet_details_map.get_token_symbol_or_default(faucet_id),
+            format_amount(*faucet_id, limit.per_transaction)?,
+            format_amount(*faucet_id, limit.daily)?,
+            format_amount(*faucet_id, Some(daily_spending.get(faucet_id).copied().unwrap_or(0)))?,
+        ]);
+    }
+    println!("Spending limits:\n{table}");
+
+    let allowed_recipients = if policy.allowed_recipients().is_empty() {
+        "any".to_string()
+    } else {
+        policy
+            .allowed_recipients()
+            .iter()
+            .map(|account_id| account_id.to_hex())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    println!("Allowed recipients: {allowed_recipients}");
+
+    let blocked_note_types = if policy.blocked_note_types().is_empty() {
+        "none".to_string()
+    } else {
+        policy
+            .blocked_note_types()
+            .iter()
+            .map(|note_type| format!("{note_type:?}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    println!("Blocked note types: {blocked_note_types}");
+
+    Ok(())
+}
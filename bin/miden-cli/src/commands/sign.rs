@@ -0,0 +1,114 @@
+use std::{fs, io, path::PathBuf};
+
+use miden_client::{
+    auth::{AuthSecretKey, SigningRequest, SIGNATURE_FILE_EXTENSION},
+    crypto::FeltRng,
+    store::StoreError,
+    utils::{Deserializable, Serializable},
+    Client, ClientError, Word,
+};
+
+use crate::{create_dynamic_table, Parser};
+
+#[derive(Debug, Parser, Clone)]
+#[clap(about = "Sign a signing request exported by a client that uses an air-gapped signer")]
+pub struct SignCmd {
+    /// Path to the signing request file
+    #[clap(short, long)]
+    request: PathBuf,
+
+    /// Path of the signature file. Defaults to the request path with a `.signature` extension
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// Flag to sign the request without asking for confirmation
+    #[clap(short, long, default_value_t = false)]
+    force: bool,
+}
+
+impl SignCmd {
+    pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
+        let contents = fs::read(&self.request).map_err(|err| {
+            format!("Failed to read signing request {}: {err}", self.request.display())
+        })?;
+        let request = SigningRequest::read_from_bytes(&contents).map_err(|err| {
+            format!("Failed to parse signing request {}: {err}", self.request.display())
+        })?;
+
+        let secret_key = find_secret_key(&client, request.pub_key()).await?;
+
+        print_signing_request(&request);
+        if !self.force {
+            println!("\nSign the request? (Y/N)");
+            let mut proceed_str: String = String::new();
+            io::stdin().read_line(&mut proceed_str).expect("Should read line");
+
+            if proceed_str.trim().to_lowercase() != "y" {
+                println!("Signing was cancelled.");
+                return Ok(());
+            }
+        }
+
+        let response = request
+            .sign(&secret_key, &mut rand::thread_rng())
+            .map_err(|err| format!("Failed to sign the request: {err}"))?;
+
+        let output = self
+            .output
+            .clone()
+            .unwrap_or_else(|| self.request.with_extension(SIGNATURE_FILE_EXTENSION));
+        fs::write(&output, response.to_bytes())
+            .map_err(|err| format!("Failed to write signature file {}: {err}", output.display()))?;
+
+        println!("Signature written to {}.", output.display());
+        Ok(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the secret key of the tracked account whose public key is `pub_key`. Accounts tracked
+/// without their secret keys are skipped.
+async fn find_secret_key(
+    client: &Client<impl FeltRng>,
+    pub_key: Word,
+) -> Result<AuthSecretKey, String> {
+    for (account, _seed) in client.get_account_headers().await? {
+        let secret_key = match client.get_account_auth(account.id()).await {
+            Ok(secret_key) => secret_key,
+            Err(ClientError::StoreError(StoreError::AccountDataNotFound(_))) => continue,
+            Err(err) => return Err(err.to_string()),
+        };
+        let AuthSecretKey::RpoFalcon512(key) = &secret_key;
+        if Word::from(key.public_key()) == pub_key {
+            return Ok(secret_key);
+        }
+    }
+
+    Err("None of the tracked accounts holds the key requested by the signing request".to_string())
+}
+
+fn print_signing_request(request: &SigningRequest) {
+    println!("Signing request: {}", request.id());
+    println!(
+        "The changes below are reported by the client that exported the request and are not \
+        covered by the signature. Only sign requests exported by a client you trust."
+    );
+
+    let account_delta = request.account_delta();
+    let mut table = create_dynamic_table(&["Asset", "Change"]);
+    for (faucet_id, amount) in account_delta.vault().fungible().iter() {
+        table.add_row(vec![faucet_id.to_hex(), amount.to_string()]);
+    }
+    for (asset, action) in account_delta.vault().non_fungible().iter() {
+        table.add_row(vec![asset.faucet_id().to_hex(), format!("{action:?}")]);
+    }
+
+    if table.row_count() == 0 {
+        println!("The transaction does not change the account's vault.");
+    } else {
+        println!("The transaction changes the account's vault as follows:");
+        println!("{table}");
+    }
+}
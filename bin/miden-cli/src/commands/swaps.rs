@@ -0,0 +1,79 @@
+use miden_client::{crypto::FeltRng, Client};
+
+use super::new_transactions::{confirm_and_submit_transaction, format_assets};
+use crate::{
+    create_dynamic_table, get_output_note_with_id_prefix, utils::load_faucet_details_map, Parser,
+};
+
+#[derive(Default, Debug, Parser, Clone)]
+#[clap(
+    about = "View and cancel the SWAP orders created by this client. Defaults to `list` command."
+)]
+pub struct SwapsCmd {
+    /// List the SWAP notes created by this client and the status of their payback notes
+    #[clap(short, long, group = "action")]
+    list: bool,
+
+    /// Reclaim the offered asset of an open SWAP note, given its ID or a prefix of it. Fails for
+    /// notes using the standard SWAP script, which can't be reclaimed by their creator
+    #[clap(short, long, group = "action", value_name = "note_id")]
+    cancel: Option<String>,
+
+    /// Flag to submit the cancellation without asking for confirmation
+    #[clap(short, long, default_value_t = false)]
+    force: bool,
+}
+
+impl SwapsCmd {
+    pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
+        match &self.cancel {
+            Some(note_id) => cancel_swap(client, note_id, self.force).await,
+            None => list_swaps(client).await,
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+async fn list_swaps(client: Client<impl FeltRng>) -> Result<(), String> {
+    let faucet_details_map = load_faucet_details_map()?;
+
+    let mut table = create_dynamic_table(&[
+        "SWAP Note ID",
+        "Creator",
+        "Offered",
+        "Requested",
+        "Payback Note ID",
+        "Status",
+    ]);
+    for swap_order in client.get_swap_orders().await? {
+        table.add_row(vec![
+            swap_order.id().to_hex(),
+            swap_order.creator().to_hex(),
+            format_assets(&faucet_details_map, &[swap_order.offered_asset()])?,
+            format_assets(&faucet_details_map, &[swap_order.requested_asset()])?,
+            swap_order.payback_note_id().to_hex(),
+            swap_order.status().to_string(),
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
+async fn cancel_swap(
+    mut client: Client<impl FeltRng>,
+    note_id_prefix: &str,
+    force: bool,
+) -> Result<(), String> {
+    let note_id = get_output_note_with_id_prefix(&client, note_id_prefix)
+        .await
+        .map_err(|err| err.to_string())?
+        .id();
+
+    println!("Executing transaction...");
+    let transaction_execution_result = client.cancel_swap(note_id).await?;
+
+    confirm_and_submit_transaction(&mut client, transaction_execution_result, force, None).await
+}
@@ -1,20 +1,74 @@
+use std::time::Duration;
+
 use clap::Parser;
 use miden_client::{crypto::FeltRng, Client};
 
+use super::payments::print_payment_executions;
+use crate::resubmit_queued_transactions;
+
 #[derive(Debug, Parser, Clone)]
 #[clap(about = "Sync this client with the latest state of the Miden network.")]
-pub struct SyncCmd {}
+pub struct SyncCmd {
+    /// Keep syncing until the process is stopped, making the recurring payments that are due
+    /// after each sync
+    #[clap(short, long, default_value_t = false)]
+    watch: bool,
+
+    /// Number of seconds to wait between syncs in watch mode
+    #[clap(long, value_name = "SECONDS", default_value_t = 10, requires = "watch")]
+    interval: u64,
+}
 
 impl SyncCmd {
     pub async fn execute(&self, mut client: Client<impl FeltRng>) -> Result<(), String> {
-        let new_details = client.sync_state().await?;
-
-        println!("State synced to block {}", new_details.block_num);
-        println!("New public notes: {}", new_details.received_notes.len());
-        println!("Tracked notes updated: {}", new_details.committed_notes.len());
-        println!("Tracked notes consumed: {}", new_details.consumed_notes.len());
-        println!("Tracked accounts updated: {}", new_details.updated_accounts.len());
-        println!("Commited transactions: {}", new_details.committed_transactions.len());
-        Ok(())
+        if !self.watch {
+            return sync(&mut client).await;
+        }
+
+        // Errors are reported without stopping, so the next sync can recover from them
+        loop {
+            if let Err(err) = sync_and_run_due_payments(&mut client).await {
+                println!("Sync failed: {err}");
+            }
+
+            tokio::time::sleep(Duration::from_secs(self.interval)).await;
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+async fn sync(client: &mut Client<impl FeltRng>) -> Result<(), String> {
+    let new_details = client.sync_state().await?;
+
+    println!("State synced to block {}", new_details.block_num);
+    println!("New public notes: {}", new_details.received_notes.len());
+    println!("Tracked notes updated: {}", new_details.committed_notes.len());
+    println!("Tracked notes consumed: {}", new_details.consumed_notes.len());
+    println!("Tracked accounts updated: {}", new_details.updated_accounts.len());
+    println!("Commited transactions: {}", new_details.committed_transactions.len());
+
+    resubmit_queued_transactions(client).await;
+
+    let queued_transactions = client
+        .get_unsubmitted_transactions()
+        .await?
+        .into_iter()
+        .filter(|transaction| transaction.is_queued())
+        .count();
+    if queued_transactions > 0 {
+        println!("Transactions queued for resubmission: {queued_transactions}");
+    }
+    Ok(())
+}
+
+async fn sync_and_run_due_payments(client: &mut Client<impl FeltRng>) -> Result<(), String> {
+    sync(client).await?;
+
+    let executions = client.run_due_payments().await?;
+    if !executions.is_empty() {
+        print_payment_executions(&executions);
     }
+    Ok(())
 }
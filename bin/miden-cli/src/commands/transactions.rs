@@ -1,8 +1,31 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::Subcommand;
 use miden_client::{
-    crypto::FeltRng, store::TransactionFilter, transactions::TransactionRecord, Client,
+    accounts::AccountId,
+    crypto::FeltRng,
+    store::TransactionFilter,
+    transactions::{
+        DebugDump, ProvenTransaction, TransactionDebugOutput, TransactionPhase, TransactionRecord,
+        TransactionRequest, TransactionResult, UnsubmittedTransaction,
+    },
+    utils::{Deserializable, Serializable},
+    Client,
 };
 
-use crate::{create_dynamic_table, Parser};
+use crate::{
+    commands::new_transactions::{
+        confirm_and_submit_transaction, print_transaction_details, ProverArg, ProverArgs,
+    },
+    config::CliConfig,
+    create_dynamic_table,
+    request_file::TransactionRequestFile,
+    utils::get_input_acc_id_by_prefix_or_default,
+    Parser,
+};
 
 #[derive(Default, Debug, Parser, Clone)]
 #[clap(about = "Manage and view transactions. Defaults to `list` command.")]
@@ -10,19 +33,282 @@ pub struct TransactionCmd {
     /// List currently tracked transactions
     #[clap(short, long, group = "action")]
     list: bool,
+
+    #[clap(subcommand)]
+    command: Option<TransactionSubcommand>,
+}
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum TransactionSubcommand {
+    Exec(ExecTransactionCmd),
+    /// Prove a stored transaction with the prover set in the config, without submitting it
+    Prove {
+        /// ID (or a prefix of the ID) of the stored transaction
+        id: String,
+    },
+    /// Submit a stored transaction, proving it first if it has not been proven yet
+    Submit {
+        /// ID (or a prefix of the ID) of the stored transaction
+        id: String,
+        /// Prover used if the transaction has not been proven yet: `local`, `remote` (the
+        /// `remote_prover_endpoint` of the config) or the URL of a proving service. Defaults to
+        /// the prover set in the config
+        #[clap(long, value_name = "local|remote|URL")]
+        prover: Option<ProverArg>,
+    },
+    /// Export the witness of a stored transaction so it can be proven elsewhere
+    ExportWitness {
+        /// ID (or a prefix of the ID) of the stored transaction
+        id: String,
+        /// Path of the file the witness is written to
+        #[clap(short, long)]
+        file: PathBuf,
+    },
+    /// Import a proven transaction generated from an exported witness
+    ImportProof {
+        /// Path of the file that contains the proven transaction
+        #[clap(short, long)]
+        file: PathBuf,
+    },
+    /// Discard a stored transaction without submitting it
+    Discard {
+        /// ID (or a prefix of the ID) of the stored transaction
+        id: String,
+    },
 }
 
 impl TransactionCmd {
-    pub async fn execute(&self, client: Client<impl FeltRng>) -> Result<(), String> {
-        list_transactions(client).await?;
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
+        match &self.command {
+            Some(TransactionSubcommand::Exec(exec_cmd)) => {
+                exec_cmd.execute(client, cli_config).await
+            },
+            Some(TransactionSubcommand::Prove { id }) => {
+                let transaction = get_unsubmitted_transaction_with_id_prefix(&client, id).await?;
+                client.prove_stored_transaction(transaction.id()).await?;
+                println!("Transaction {} proven.", transaction.id());
+                Ok(())
+            },
+            Some(TransactionSubcommand::Submit { id, prover }) => {
+                let transaction = get_unsubmitted_transaction_with_id_prefix(&client, id).await?;
+                println!("Submitting transaction {}...", transaction.id());
+                match prover {
+                    Some(prover) => {
+                        client
+                            .submit_stored_transaction_with_prover(
+                                transaction.id(),
+                                prover.tx_prover(cli_config)?,
+                            )
+                            .await?
+                    },
+                    None => client.submit_stored_transaction(transaction.id()).await?,
+                }
+                println!("Succesfully submitted transaction.");
+                Ok(())
+            },
+            Some(TransactionSubcommand::ExportWitness { id, file }) => {
+                let transaction = get_unsubmitted_transaction_with_id_prefix(&client, id).await?;
+                let witness = client.export_transaction_witness(transaction.id()).await?;
+                fs::write(file, witness.to_bytes()).map_err(|err| {
+                    format!("Failed to write witness file {}: {err}", file.display())
+                })?;
+                println!(
+                    "Witness of transaction {} exported to {}.",
+                    transaction.id(),
+                    file.display()
+                );
+                Ok(())
+            },
+            Some(TransactionSubcommand::ImportProof { file }) => {
+                let contents = fs::read(file).map_err(|err| {
+                    format!("Failed to read proof file {}: {err}", file.display())
+                })?;
+                let proven_transaction =
+                    ProvenTransaction::read_from_bytes(&contents).map_err(|err| {
+                        format!("Failed to parse proof file {}: {err}", file.display())
+                    })?;
+                let transaction_id = client.import_proven_transaction(proven_transaction).await?;
+                println!("Proof of transaction {transaction_id} imported.");
+                Ok(())
+            },
+            Some(TransactionSubcommand::Discard { id }) => {
+                let transaction = get_unsubmitted_transaction_with_id_prefix(&client, id).await?;
+                client.discard_stored_transaction(transaction.id()).await?;
+                println!("Transaction {} discarded.", transaction.id());
+                Ok(())
+            },
+            None => list_transactions(client).await,
+        }
+    }
+}
+
+// EXECUTE TRANSACTION REQUEST
+// ================================================================================================
+
+#[derive(Debug, Parser, Clone)]
+/// Execute the transaction described by a TOML or JSON request file
+pub struct ExecTransactionCmd {
+    /// The account ID that executes the transaction or its hex prefix. If none is provided, the
+    /// default account's ID is used instead
+    #[clap(short = 'a', long = "account")]
+    account_id: Option<String>,
+    /// Path to the request file. Files with a `.json` extension are parsed as JSON, any other
+    /// file as TOML
+    #[clap(short, long)]
+    request: PathBuf,
+    /// Flag to submit the executed transaction without asking for confirmation
+    #[clap(short, long, default_value_t = false)]
+    force: bool,
+    /// Store the executed transaction without proving or submitting it. It can be resumed later
+    /// with `miden tx prove`, `miden tx export-witness` or `miden tx submit`
+    #[clap(long, default_value_t = false, conflicts_with_all = ["force", "prover"])]
+    save_only: bool,
+    #[clap(flatten)]
+    prover: ProverArgs,
+    /// Write the output of the `debug` instructions run by the transaction to this file, grouped
+    /// by input note and followed by the cycles spent in each phase
+    #[clap(long, value_name = "FILE")]
+    debug_out: Option<PathBuf>,
+}
+
+impl ExecTransactionCmd {
+    pub async fn execute(
+        &self,
+        mut client: Client<impl FeltRng>,
+        cli_config: &CliConfig,
+    ) -> Result<(), String> {
+        let account_id =
+            get_input_acc_id_by_prefix_or_default(&client, self.account_id.clone()).await?;
+
+        let base_dir = self.request.parent().unwrap_or(Path::new("."));
+        let transaction_request = TransactionRequestFile::load(&self.request)?
+            .into_transaction_request(&client, base_dir)
+            .await?;
+        let tx_prover = self.prover.tx_prover(cli_config)?;
+
+        println!("Executing transaction...");
+        let transaction_result = match &self.debug_out {
+            Some(debug_out) => {
+                execute_with_debug_output(&mut client, account_id, transaction_request, debug_out)
+                    .await?
+            },
+            None => client.new_transaction(account_id, transaction_request).await?,
+        };
+
+        if !self.save_only {
+            return confirm_and_submit_transaction(
+                &mut client,
+                transaction_result,
+                self.force,
+                tx_prover,
+            )
+            .await;
+        }
+
+        print_transaction_details(&transaction_result)?;
+
+        let transaction_id = client.save_transaction(transaction_result).await?;
+        println!("\nTransaction stored, it can be submitted with `miden tx submit`.");
+        println!("Transaction ID: {transaction_id}");
+
         Ok(())
     }
 }
 
+/// Executes the transaction request and writes the output of the `debug` instructions it runs to
+/// `debug_out`.
+///
+/// The dumps are grouped by the phase of the transaction they were taken in, with a section for
+/// each input note. The cycles spent in each phase are appended once the transaction is executed.
+/// If the execution fails, the error is appended instead, after the dumps taken before the failure.
+async fn execute_with_debug_output(
+    client: &mut Client<impl FeltRng>,
+    account_id: AccountId,
+    transaction_request: TransactionRequest,
+    debug_out: &Path,
+) -> Result<TransactionResult, String> {
+    let mut debug_output = TransactionDebugOutput::default();
+    let result = client
+        .new_transaction_with_debug_output(account_id, transaction_request, &mut debug_output)
+        .await;
+
+    let mut contents = String::new();
+    let mut phase = None;
+    for entry in debug_output.entries() {
+        if phase != Some(entry.phase) {
+            let header = match entry.phase {
+                TransactionPhase::Prologue => "prologue".to_string(),
+                TransactionPhase::NotesProcessing => "notes processing".to_string(),
+                TransactionPhase::Note(note_id) => format!("note {}", note_id.to_hex()),
+                TransactionPhase::TransactionScript => "transaction script".to_string(),
+                TransactionPhase::Epilogue => "epilogue".to_string(),
+            };
+            contents.push_str(&format!("== {header} ==\n"));
+            phase = Some(entry.phase);
+        }
+
+        contents.push_str(&format!("clk {} (context {}):\n", entry.clk, entry.context));
+        match &entry.dump {
+            DebugDump::Stack(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    contents.push_str(&format!("  stack[{index}]: {item}\n"));
+                }
+            },
+            DebugDump::Memory(words) => {
+                for (addr, word) in words {
+                    let word: Vec<String> = word.iter().map(ToString::to_string).collect();
+                    contents.push_str(&format!("  mem[{addr}]: [{}]\n", word.join(", ")));
+                }
+            },
+        }
+    }
+
+    if let Some(error) = debug_output.error() {
+        contents.push_str(&format!("\nExecution stopped: {error}\n"));
+    }
+
+    match &result {
+        Ok(transaction_result) => {
+            let stats = transaction_result.stats();
+            contents.push_str(&format!(
+                "\nTransaction {} executed in {} cycles:\n",
+                transaction_result.executed_transaction().id(),
+                stats.total_cycles()
+            ));
+            contents.push_str(&format!("prologue: {}\n", stats.prologue_cycles()));
+            contents.push_str(&format!("notes processing: {}\n", stats.notes_processing_cycles()));
+            for (note_id, cycles) in stats.note_execution_cycles() {
+                contents.push_str(&format!("  note {}: {cycles}\n", note_id.to_hex()));
+            }
+            contents.push_str(&format!("transaction script: {}\n", stats.tx_script_cycles()));
+            contents.push_str(&format!("epilogue: {}\n", stats.epilogue_cycles()));
+        },
+        Err(err) => contents.push_str(&format!("\nTransaction execution failed: {err}\n")),
+    }
+
+    fs::write(debug_out, contents).map_err(|err| {
+        format!("Failed to write debug output file {}: {err}", debug_out.display())
+    })?;
+
+    println!("Debug output written to {}.", debug_out.display());
+    Ok(result?)
+}
+
 // LIST TRANSACTIONS
 // ================================================================================================
 async fn list_transactions(client: Client<impl FeltRng>) -> Result<(), String> {
-    let transactions = client.get_transactions(TransactionFilter::All).await?;
+    let mut transactions: Vec<TransactionRecord> = client
+        .get_unsubmitted_transactions()
+        .await?
+        .iter()
+        .map(TransactionRecord::from)
+        .collect();
+    transactions.extend(client.get_transactions(TransactionFilter::All).await?);
+
     print_transactions_summary(&transactions);
     Ok(())
 }
@@ -58,3 +344,33 @@ where
 
     println!("{table}");
 }
+
+/// Returns the stored transaction whose ID starts with `id_prefix`.
+///
+/// # Errors
+///
+/// Returns an error if no stored transaction matches the prefix, or if more than one does.
+async fn get_unsubmitted_transaction_with_id_prefix(
+    client: &Client<impl FeltRng>,
+    id_prefix: &str,
+) -> Result<UnsubmittedTransaction, String> {
+    let mut matches: Vec<UnsubmittedTransaction> = client
+        .get_unsubmitted_transactions()
+        .await?
+        .into_iter()
+        .filter(|transaction| transaction.id().to_string().starts_with(id_prefix))
+        .collect();
+
+    match matches.len() {
+        0 => Err(format!("No stored transaction found with ID prefix {id_prefix}")),
+        1 => Ok(matches.pop().expect("there is exactly one match")),
+        _ => Err(format!(
+            "Multiple stored transactions found with ID prefix {id_prefix}: {}",
+            matches
+                .iter()
+                .map(|transaction| transaction.id().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
@@ -8,6 +8,7 @@ use figment::{
 use miden_client::{
     config::{Endpoint, RpcConfig},
     store::sqlite_store::config::SqliteStoreConfig,
+    transactions::{DEFAULT_COOLDOWN, DEFAULT_FAILURE_THRESHOLD},
 };
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +29,12 @@ pub struct CliConfig {
     pub token_symbol_map_filepath: PathBuf,
     /// RPC endpoint for the proving service. If this is not present, a local prover will be used.
     pub remote_prover_endpoint: Option<Endpoint>,
+    /// Settings to fall back to other provers when the proving service fails. If this is not
+    /// present, only the prover described by `remote_prover_endpoint` is used.
+    pub prover: Option<ProverConfig>,
+    /// Settings to sign transactions on an offline machine. If this is not present, transactions
+    /// are signed with the keys stored by the client.
+    pub air_gapped_signer: Option<AirGappedSignerConfig>,
 }
 
 // Make `ClientConfig` a provider itself for composability.
@@ -54,6 +61,72 @@ impl Default for CliConfig {
             default_account_id: None,
             token_symbol_map_filepath: Path::new(TOKEN_SYMBOL_MAP_FILEPATH).to_path_buf(),
             remote_prover_endpoint: None,
+            prover: None,
+            air_gapped_signer: None,
         }
     }
 }
+
+// PROVER CONFIG
+// ================================================================================================
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ProverConfig {
+    /// Number of seconds to wait for a proof from the prover at `remote_prover_endpoint` before
+    /// trying the next prover. If this is not present, the prover is waited for until it
+    /// responds.
+    #[serde(default)]
+    pub remote_prover_timeout_secs: Option<u64>,
+    /// Remote provers tried in order, after the one at `remote_prover_endpoint`.
+    #[serde(default)]
+    pub remote: Vec<RemoteProverConfig>,
+    /// Whether to prove locally when every remote prover fails.
+    #[serde(default = "default_local_fallback")]
+    pub local_fallback: bool,
+    /// Number of consecutive failures after which a prover is skipped.
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: usize,
+    /// Number of proofs a skipped prover is skipped for before it's tried again.
+    #[serde(default = "default_cooldown")]
+    pub cooldown: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RemoteProverConfig {
+    /// RPC endpoint of the proving service.
+    pub endpoint: Endpoint,
+    /// Number of seconds to wait for a proof before trying the next prover. If this is not
+    /// present, the prover is waited for until it responds.
+    pub timeout_secs: Option<u64>,
+}
+
+fn default_local_fallback() -> bool {
+    true
+}
+
+fn default_failure_threshold() -> usize {
+    DEFAULT_FAILURE_THRESHOLD
+}
+
+fn default_cooldown() -> usize {
+    DEFAULT_COOLDOWN
+}
+
+// AIR-GAPPED SIGNER CONFIG
+// ================================================================================================
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct AirGappedSignerConfig {
+    /// Directory through which signing requests and signatures are exchanged with the offline
+    /// machine.
+    pub dir: PathBuf,
+    /// Number of seconds to wait for a signature before failing the transaction. It has to be
+    /// greater than zero, as a transaction can't be completed with a signature provided after it
+    /// failed.
+    #[serde(default = "default_signing_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_signing_timeout_secs() -> u64 {
+    300
+}
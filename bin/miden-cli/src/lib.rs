@@ -1,16 +1,17 @@
-use std::{env, sync::Arc};
+use std::{env, sync::Arc, time::Duration};
 
 use clap::Parser;
 use comfy_table::{presets, Attribute, Cell, ContentArrangement, Table};
 use miden_client::{
     accounts::AccountHeader,
+    auth::{AirGappedAuthenticator, TransactionAuthenticator},
     crypto::{FeltRng, RpoRandomCoin},
-    rpc::TonicRpcClient,
+    rpc::{InstrumentedRpcClient, TonicRpcClient},
     store::{
         sqlite_store::SqliteStore, NoteFilter as ClientNoteFilter, OutputNoteRecord, Store,
         StoreAuthenticator,
     },
-    transactions::{LocalTransactionProver, TransactionProver},
+    transactions::{FallbackTransactionProver, LocalTransactionProver, TransactionProver},
     Client, ClientError, Felt, IdPrefixFetchError,
 };
 use miden_tx_prover::RemoteTransactionProver;
@@ -22,18 +23,23 @@ use commands::{
     import::ImportCmd,
     init::InitCmd,
     new_account::{NewFaucetCmd, NewWalletCmd},
-    new_transactions::{ConsumeNotesCmd, MintCmd, SendCmd, SwapCmd},
+    new_transactions::{ConsumeNotesCmd, MintCmd, SendBatchCmd, SendCmd, SwapCmd},
     notes::NotesCmd,
+    payments::PaymentsCmd,
+    policy::PolicyCmd,
+    sign::SignCmd,
+    swaps::SwapsCmd,
     sync::SyncCmd,
     tags::TagsCmd,
     transactions::TransactionCmd,
 };
 
-use self::utils::load_config_file;
+use self::{config::CliConfig, utils::load_config_file};
 
 mod config;
 mod faucet_details_map;
 mod info;
+mod request_file;
 mod utils;
 
 /// Config file name
@@ -73,8 +79,13 @@ pub enum Command {
     Transaction(TransactionCmd),
     Mint(MintCmd),
     Send(SendCmd),
+    SendBatch(SendBatchCmd),
     Swap(SwapCmd),
+    Swaps(SwapsCmd),
     ConsumeNotes(ConsumeNotesCmd),
+    Sign(SignCmd),
+    Policy(PolicyCmd),
+    Payments(PaymentsCmd),
 }
 
 /// CLI entry point
@@ -108,22 +119,38 @@ impl Cli {
         let coin_seed: [u64; 4] = rng.gen();
 
         let rng = RpoRandomCoin::new(coin_seed.map(Felt::new));
-        let authenticator = StoreAuthenticator::new_with_rng(store.clone() as Arc<dyn Store>, rng);
-
-        let tx_prover: Arc<dyn TransactionProver> = match &cli_config.remote_prover_endpoint {
-            Some(proving_url) => Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
-            None => Arc::new(LocalTransactionProver::new(Default::default())),
+        let authenticator: Arc<dyn TransactionAuthenticator> = match &cli_config.air_gapped_signer {
+            Some(signer_config) => {
+                if signer_config.timeout_secs == 0 {
+                    return Err("The air-gapped signer timeout should be greater than zero".into());
+                }
+                Arc::new(AirGappedAuthenticator::new(
+                    &signer_config.dir,
+                    Duration::from_secs(signer_config.timeout_secs),
+                ))
+            },
+            None => {
+                Arc::new(StoreAuthenticator::new_with_rng(store.clone() as Arc<dyn Store>, rng))
+            },
         };
 
-        let client = Client::new(
-            Box::new(TonicRpcClient::new(&cli_config.rpc)),
+        let tx_prover = create_tx_prover(&cli_config);
+
+        let mut client = Client::new(
+            Box::new(InstrumentedRpcClient::new(TonicRpcClient::new(&cli_config.rpc))),
             rng,
             store as Arc<dyn Store>,
-            Arc::new(authenticator),
-            tx_prover as Arc<dyn TransactionProver>,
+            authenticator,
+            tx_prover,
             in_debug_mode,
         );
 
+        // Retry the submission of queued transactions. The sync command does this once the chain
+        // tip is updated instead.
+        if !matches!(self.action, Command::Sync(_)) {
+            resubmit_queued_transactions(&mut client).await;
+        }
+
         // Execute CLI command
         match &self.action {
             Command::Account(account) => account.execute(client).await,
@@ -135,16 +162,75 @@ impl Cli {
             Command::Notes(notes) => notes.execute(client).await,
             Command::Sync(sync) => sync.execute(client).await,
             Command::Tags(tags) => tags.execute(client).await,
-            Command::Transaction(transaction) => transaction.execute(client).await,
+            Command::Transaction(transaction) => transaction.execute(client, &cli_config).await,
             Command::Export(cmd) => cmd.execute(client).await,
-            Command::Mint(mint) => mint.execute(client).await,
-            Command::Send(send) => send.execute(client).await,
-            Command::Swap(swap) => swap.execute(client).await,
-            Command::ConsumeNotes(consume_notes) => consume_notes.execute(client).await,
+            Command::Mint(mint) => mint.execute(client, &cli_config).await,
+            Command::Send(send) => send.execute(client, &cli_config).await,
+            Command::SendBatch(send_batch) => send_batch.execute(client, &cli_config).await,
+            Command::Swap(swap) => swap.execute(client, &cli_config).await,
+            Command::Swaps(swaps) => swaps.execute(client).await,
+            Command::ConsumeNotes(consume_notes) => {
+                consume_notes.execute(client, &cli_config).await
+            },
+            Command::Sign(sign) => sign.execute(client).await,
+            Command::Policy(policy) => policy.execute(client).await,
+            Command::Payments(payments) => payments.execute(client).await,
         }
     }
 }
 
+/// Resubmits the queued transactions that are due. Failures are reported without failing the
+/// command, as the transactions stay queued for the next attempt.
+pub(crate) async fn resubmit_queued_transactions(client: &mut Client<impl FeltRng>) {
+    match client.resubmit_queued_transactions().await {
+        Ok(transaction_ids) => {
+            for transaction_id in transaction_ids {
+                println!("Queued transaction {transaction_id} was submitted.");
+            }
+        },
+        Err(err) => println!("Failed to resubmit queued transactions: {err}"),
+    }
+}
+
+/// Returns the prover described by the config. When the `[prover]` section is present, the
+/// provers are tried in order: the one at `remote_prover_endpoint`, the remote provers of the
+/// section and, if enabled, the local prover.
+fn create_tx_prover(cli_config: &CliConfig) -> Arc<dyn TransactionProver> {
+    let Some(prover_config) = &cli_config.prover else {
+        return match &cli_config.remote_prover_endpoint {
+            Some(proving_url) => Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
+            None => Arc::new(LocalTransactionProver::new(Default::default())),
+        };
+    };
+
+    let primary_timeout = prover_config.remote_prover_timeout_secs.map(Duration::from_secs);
+    let remote_provers = cli_config
+        .remote_prover_endpoint
+        .iter()
+        .map(|endpoint| (endpoint, primary_timeout))
+        .chain(
+            prover_config
+                .remote
+                .iter()
+                .map(|remote| (&remote.endpoint, remote.timeout_secs.map(Duration::from_secs))),
+        );
+
+    let mut tx_prover = FallbackTransactionProver::new()
+        .with_failure_threshold(prover_config.failure_threshold)
+        .with_cooldown(prover_config.cooldown);
+    for (endpoint, timeout) in remote_provers {
+        let endpoint = endpoint.to_string();
+        let remote_prover = Arc::new(RemoteTransactionProver::new(&endpoint));
+        tx_prover = tx_prover.with_prover(endpoint, remote_prover, timeout);
+    }
+    if prover_config.local_fallback {
+        let local_prover = Arc::new(LocalTransactionProver::new(Default::default()));
+        tx_prover = tx_prover.with_prover("local", local_prover, None);
+    }
+
+    Arc::new(tx_prover)
+}
+
 pub fn create_dynamic_table(headers: &[&str]) -> Table {
     let header_cells = headers
         .iter()
@@ -0,0 +1,280 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miden_client::{
+    crypto::{Digest, FeltRng},
+    notes::{get_input_note_with_id_prefix, Note, NoteDetails, NoteFile, NoteTag},
+    transactions::{NoteArgs, TransactionRequest},
+    utils::Deserializable,
+    Client, Felt, StarkField, Word,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::parse_account_id;
+
+// TRANSACTION REQUEST FILE
+// ================================================================================================
+
+/// A human-writable description of a [TransactionRequest], loaded from a TOML or JSON file.
+///
+/// Every section is optional. Paths to note and script files are resolved relative to the
+/// directory that contains the request file. See `docs/cli-reference.md` for an example.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransactionRequestFile {
+    /// Notes consumed by the transaction.
+    #[serde(default)]
+    pub input_notes: Vec<InputNoteEntry>,
+    /// Custom transaction script. If not set, the client builds an authentication script.
+    pub script: Option<ScriptEntry>,
+    /// Notes the transaction is expected to create.
+    #[serde(default)]
+    pub expected_output_notes: Vec<NoteFileEntry>,
+    /// Notes that may be created when the output notes of the transaction are consumed.
+    #[serde(default)]
+    pub expected_future_notes: Vec<FutureNoteEntry>,
+    /// Entries added to the advice map of the transaction.
+    #[serde(default)]
+    pub advice_map: Vec<AdviceMapEntry>,
+    /// IDs of public accounts whose state is used by the transaction.
+    #[serde(default)]
+    pub foreign_accounts: Vec<String>,
+    /// Number of blocks after the reference block in which the transaction expires. It can't be
+    /// combined with a custom script.
+    pub expiration_delta: Option<u16>,
+}
+
+/// A note consumed by the transaction. Exactly one of `id` and `file` must be set.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputNoteEntry {
+    /// ID (or ID prefix) of a note tracked by the client.
+    pub id: Option<String>,
+    /// Path to a note file containing the full note. Notes loaded from files are always consumed
+    /// as unauthenticated notes.
+    pub file: Option<PathBuf>,
+    /// Whether a note tracked by the client is consumed without an inclusion proof.
+    #[serde(default)]
+    pub unauthenticated: bool,
+    /// Arguments passed to the note script.
+    pub args: Option<[u64; 4]>,
+}
+
+/// The source of a custom transaction script. Exactly one of `source` and `path` must be set.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptEntry {
+    /// MASM source of the script.
+    pub source: Option<String>,
+    /// Path to a file containing the MASM source of the script.
+    pub path: Option<PathBuf>,
+    /// Inputs made available to the script through the advice map.
+    #[serde(default)]
+    pub inputs: Vec<AdviceMapEntry>,
+}
+
+/// A note loaded from a file containing the full note.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoteFileEntry {
+    pub file: PathBuf,
+}
+
+/// A note that may be created in the future, loaded from a note file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FutureNoteEntry {
+    pub file: PathBuf,
+    /// Tag of the note. Overrides the tag stored in the note file, if any.
+    pub tag: Option<u32>,
+}
+
+/// An advice map entry, with its key in hex format.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdviceMapEntry {
+    pub key: String,
+    pub values: Vec<u64>,
+}
+
+impl TransactionRequestFile {
+    /// Loads a request file. Files with a `.json` extension are parsed as JSON, and any other
+    /// file is parsed as TOML.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read request file {}: {err}", path.display()))?;
+
+        if path.extension().is_some_and(|extension| extension == "json") {
+            serde_json::from_str(&contents)
+                .map_err(|err| format!("Failed to parse request file {}: {err}", path.display()))
+        } else {
+            toml::from_str(&contents)
+                .map_err(|err| format!("Failed to parse request file {}: {err}", path.display()))
+        }
+    }
+
+    /// Builds the [TransactionRequest] described by the file. Relative paths are resolved against
+    /// `base_dir`.
+    pub async fn into_transaction_request(
+        self,
+        client: &Client<impl FeltRng>,
+        base_dir: &Path,
+    ) -> Result<TransactionRequest, String> {
+        let mut authenticated_notes = Vec::new();
+        let mut unauthenticated_notes = Vec::new();
+
+        for entry in self.input_notes {
+            let args = entry.args.map(parse_word).transpose()?;
+
+            match (entry.id, entry.file) {
+                (Some(note_id), None) => {
+                    let note_record = get_input_note_with_id_prefix(client, &note_id)
+                        .await
+                        .map_err(|err| err.to_string())?;
+
+                    if entry.unauthenticated {
+                        let note: Note = note_record.try_into().map_err(|err| {
+                            format!("Note {note_id} can't be consumed as unauthenticated: {err}")
+                        })?;
+                        unauthenticated_notes.push((note, args));
+                    } else {
+                        authenticated_notes.push((note_record.id(), args));
+                    }
+                },
+                (None, Some(file)) => {
+                    let note = read_full_note(&base_dir.join(file))?;
+                    unauthenticated_notes.push((note, args));
+                },
+                _ => return Err("Input notes must set exactly one of `id` and `file`".to_string()),
+            }
+        }
+
+        let mut transaction_request = TransactionRequest::new()
+            .with_authenticated_input_notes(authenticated_notes)
+            .with_unauthenticated_input_notes(unauthenticated_notes);
+
+        if let Some(script) = self.script {
+            let source = match (script.source, script.path) {
+                (Some(source), None) => source,
+                (None, Some(path)) => {
+                    let path = base_dir.join(path);
+                    fs::read_to_string(&path).map_err(|err| {
+                        format!("Failed to read script file {}: {err}", path.display())
+                    })?
+                },
+                _ => {
+                    return Err("The script must set exactly one of `source` and `path`".to_string())
+                },
+            };
+
+            let inputs = script
+                .inputs
+                .into_iter()
+                .map(|entry| Ok((parse_digest(&entry.key)?.into(), parse_felts(entry.values)?)))
+                .collect::<Result<Vec<(Word, Vec<Felt>)>, String>>()?;
+
+            let tx_script = client.compile_tx_script(inputs, &source)?;
+            transaction_request = transaction_request
+                .with_custom_script(tx_script)
+                .map_err(|err| err.to_string())?;
+        }
+
+        let expected_output_notes = self
+            .expected_output_notes
+            .into_iter()
+            .map(|entry| read_full_note(&base_dir.join(entry.file)))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let mut expected_future_notes = Vec::new();
+        for entry in self.expected_future_notes {
+            let (details, file_tag) = read_note_details(&base_dir.join(&entry.file))?;
+            let tag = entry.tag.map(NoteTag::from).or(file_tag).ok_or_else(|| {
+                format!(
+                    "Future note in {} has no tag, set one with the `tag` field",
+                    entry.file.display()
+                )
+            })?;
+            expected_future_notes.push((details, tag));
+        }
+
+        let advice_map = self
+            .advice_map
+            .into_iter()
+            .map(|entry| Ok((parse_digest(&entry.key)?, parse_felts(entry.values)?)))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let mut foreign_accounts = Vec::new();
+        for account_id in self.foreign_accounts {
+            foreign_accounts.push(parse_account_id(client, &account_id).await?);
+        }
+
+        transaction_request = transaction_request
+            .with_expected_output_notes(expected_output_notes)
+            .with_expected_future_notes(expected_future_notes)
+            .extend_advice_map(advice_map)
+            .with_public_foreign_accounts(foreign_accounts)
+            .map_err(|err| err.to_string())?;
+
+        if let Some(expiration_delta) = self.expiration_delta {
+            transaction_request = transaction_request
+                .with_expiration_delta(expiration_delta)
+                .map_err(|err| err.to_string())?;
+        }
+
+        Ok(transaction_request)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn read_note_file(path: &Path) -> Result<NoteFile, String> {
+    let contents = fs::read(path)
+        .map_err(|err| format!("Failed to read note file {}: {err}", path.display()))?;
+    NoteFile::read_from_bytes(&contents)
+        .map_err(|err| format!("Failed to parse note file {}: {err}", path.display()))
+}
+
+/// Reads a note file that contains a full note (that is, a note exported with its proof).
+fn read_full_note(path: &Path) -> Result<Note, String> {
+    match read_note_file(path)? {
+        NoteFile::NoteWithProof(note, _) => Ok(note),
+        _ => Err(format!("Note file {} does not contain the full note", path.display())),
+    }
+}
+
+/// Reads the details of a note and, if available, its tag from a note file.
+fn read_note_details(path: &Path) -> Result<(NoteDetails, Option<NoteTag>), String> {
+    match read_note_file(path)? {
+        NoteFile::NoteDetails { details, tag, .. } => Ok((details, tag)),
+        NoteFile::NoteWithProof(note, _) => {
+            let tag = note.metadata().tag();
+            Ok((note.into(), Some(tag)))
+        },
+        NoteFile::NoteId(_) => {
+            Err(format!("Note file {} only contains the note ID", path.display()))
+        },
+    }
+}
+
+fn parse_digest(hex: &str) -> Result<Digest, String> {
+    Digest::try_from(hex).map_err(|err| format!("Invalid advice map key {hex}: {err}"))
+}
+
+fn parse_felt(value: u64) -> Result<Felt, String> {
+    if value >= Felt::MODULUS {
+        return Err(format!("Value {value} is not a valid field element"));
+    }
+    Ok(Felt::new(value))
+}
+
+fn parse_felts(values: Vec<u64>) -> Result<Vec<Felt>, String> {
+    values.into_iter().map(parse_felt).collect()
+}
+
+fn parse_word(values: [u64; 4]) -> Result<NoteArgs, String> {
+    let [a, b, c, d] = values;
+    Ok([parse_felt(a)?, parse_felt(b)?, parse_felt(c)?, parse_felt(d)?])
+}
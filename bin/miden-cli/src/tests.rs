@@ -9,6 +9,10 @@ use std::{
 use assert_cmd::Command;
 use miden_client::{
     accounts::{Account, AccountId, AccountStorageMode, AccountTemplate},
+    auth::{
+        AuthSecretKey, SigningRequest, SigningResponse, REQUEST_FILE_EXTENSION,
+        SIGNATURE_FILE_EXTENSION,
+    },
     config::RpcConfig,
     crypto::RpoRandomCoin,
     rpc::TonicRpcClient,
@@ -18,8 +22,10 @@ use miden_client::{
     },
     testing::ACCOUNT_ID_OFF_CHAIN_SENDER,
     transactions::{LocalTransactionProver, ProvingOptions},
-    Client, Felt,
+    utils::{Deserializable, Serializable},
+    Client, Felt, Word,
 };
+use miden_objects::accounts::AccountDelta;
 use rand::Rng;
 use uuid::Uuid;
 
@@ -423,6 +429,155 @@ async fn test_cli_export_import_account() {
     ));
 }
 
+// This tests that `miden sign` finds the key requested by a signing request when an account
+// without keys is tracked before the account that holds the key.
+#[tokio::test]
+async fn test_cli_sign_skips_accounts_without_keys() {
+    const ACCOUNT_FILENAME: &str = "keyless_account.acc";
+
+    let store_path_1 = create_test_store_path();
+    let mut temp_dir_1 = temp_dir();
+    temp_dir_1.push(format!("{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir(temp_dir_1.clone()).unwrap();
+
+    let store_path_2 = create_test_store_path();
+    let mut temp_dir_2 = temp_dir();
+    temp_dir_2.push(format!("{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir(temp_dir_2.clone()).unwrap();
+
+    let mut init_cmd = Command::cargo_bin("miden").unwrap();
+    init_cmd.args(["init", "--store-path", store_path_1.to_str().unwrap()]);
+    init_cmd.current_dir(&temp_dir_1).assert().success();
+
+    let mut init_cmd = Command::cargo_bin("miden").unwrap();
+    init_cmd.args(["init", "--store-path", store_path_2.to_str().unwrap()]);
+    init_cmd.current_dir(&temp_dir_2).assert().success();
+
+    // Export a wallet of the first client and track it without keys in the second one
+    let mut create_wallet_cmd = Command::cargo_bin("miden").unwrap();
+    create_wallet_cmd.args(["new-wallet", "-s", "private"]);
+    create_wallet_cmd.current_dir(&temp_dir_1).assert().success();
+
+    let keyless_account_id = {
+        let client = create_test_client_with_store_path(&store_path_1).await;
+        client.get_account_headers().await.unwrap().first().unwrap().0.id().to_hex()
+    };
+
+    let mut export_cmd = Command::cargo_bin("miden").unwrap();
+    export_cmd.args(["export", &keyless_account_id, "--account", "--filename", ACCOUNT_FILENAME]);
+    export_cmd.current_dir(&temp_dir_1).assert().success();
+    std::fs::copy(temp_dir_1.join(ACCOUNT_FILENAME), temp_dir_2.join(ACCOUNT_FILENAME)).unwrap();
+
+    let mut import_cmd = Command::cargo_bin("miden").unwrap();
+    import_cmd.args(["import", "--without-keys", ACCOUNT_FILENAME]);
+    import_cmd.current_dir(&temp_dir_2).assert().success();
+
+    // Then create a wallet that holds its key
+    let mut create_wallet_cmd = Command::cargo_bin("miden").unwrap();
+    create_wallet_cmd.args(["new-wallet", "-s", "private"]);
+    create_wallet_cmd.current_dir(&temp_dir_2).assert().success();
+
+    let pub_key = {
+        let client = create_test_client_with_store_path(&store_path_2).await;
+        let accounts = client.get_account_headers().await.unwrap();
+        assert_eq!(accounts.first().unwrap().0.id().to_hex(), keyless_account_id);
+
+        let AuthSecretKey::RpoFalcon512(secret_key) =
+            client.get_account_auth(accounts.last().unwrap().0.id()).await.unwrap();
+        Word::from(secret_key.public_key())
+    };
+
+    let request = SigningRequest::new(
+        pub_key,
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        AccountDelta::default(),
+    );
+    let request_path = temp_dir_2.join(format!("{}.{REQUEST_FILE_EXTENSION}", request.id()));
+    std::fs::write(&request_path, request.to_bytes()).unwrap();
+
+    let mut sign_cmd = Command::cargo_bin("miden").unwrap();
+    sign_cmd.args(["sign", "--request", request_path.to_str().unwrap(), "--force"]);
+    sign_cmd.current_dir(&temp_dir_2).assert().success();
+
+    let signature = std::fs::read(request_path.with_extension(SIGNATURE_FILE_EXTENSION)).unwrap();
+    let response = SigningResponse::read_from_bytes(&signature).unwrap();
+    assert_eq!(response.request_id(), request.id());
+}
+
+// This tests that a transaction described in a request file can be executed. To do so it:
+//
+// 1. Creates a faucet and a wallet and mints a note to the wallet
+// 2. Writes a request file that consumes the note
+// 3. Executes the request with `tx exec`
+#[tokio::test]
+async fn test_cli_exec_request_file() {
+    let store_path = create_test_store_path();
+    let mut temp_dir = temp_dir();
+    temp_dir.push(format!("{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir(temp_dir.clone()).unwrap();
+
+    let mut init_cmd = Command::cargo_bin("miden").unwrap();
+    init_cmd.args(["init", "--store-path", store_path.to_str().unwrap()]);
+    init_cmd.current_dir(&temp_dir).assert().success();
+
+    let mut create_faucet_cmd = Command::cargo_bin("miden").unwrap();
+    create_faucet_cmd.args([
+        "new-faucet",
+        "-s",
+        "private",
+        "-t",
+        "BTC",
+        "-d",
+        "8",
+        "-m",
+        "1000000000000",
+    ]);
+    create_faucet_cmd.current_dir(&temp_dir).assert().success();
+
+    let mut create_wallet_cmd = Command::cargo_bin("miden").unwrap();
+    create_wallet_cmd.args(["new-wallet", "-s", "private"]);
+    create_wallet_cmd.current_dir(&temp_dir).assert().success();
+
+    let (fungible_faucet_account_id, wallet_account_id) = {
+        let client = create_test_client_with_store_path(&store_path).await;
+        let accounts = client.get_account_headers().await.unwrap();
+
+        let faucet_id = accounts.iter().find(|(acc, _)| acc.id().is_faucet()).unwrap().0.id();
+        let wallet_id = accounts.iter().find(|(acc, _)| !acc.id().is_faucet()).unwrap().0.id();
+        (faucet_id.to_hex(), wallet_id.to_hex())
+    };
+
+    sync_cli(&temp_dir);
+    mint_cli(&temp_dir, &wallet_account_id, &fungible_faucet_account_id);
+    sync_until_no_notes(&store_path, &temp_dir, NoteFilter::Expected).await;
+
+    let note_id = {
+        let client = create_test_client_with_store_path(&store_path).await;
+        let notes = client.get_input_notes(NoteFilter::Committed).await.unwrap();
+        notes.first().unwrap().id().to_hex()
+    };
+
+    let mut request_path = temp_dir.clone();
+    request_path.push("request.toml");
+    std::fs::write(
+        &request_path,
+        format!("expiration_delta = 10\n\n[[input_notes]]\nid = \"{note_id}\"\n"),
+    )
+    .unwrap();
+
+    let mut exec_cmd = Command::cargo_bin("miden").unwrap();
+    exec_cmd.args([
+        "tx",
+        "exec",
+        "--account",
+        &wallet_account_id,
+        "--request",
+        request_path.to_str().unwrap(),
+        "--force",
+    ]);
+    exec_cmd.current_dir(&temp_dir).assert().success();
+}
+
 #[test]
 fn test_cli_empty_commands() {
     let store_path = create_test_store_path();
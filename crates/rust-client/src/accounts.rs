@@ -4,19 +4,20 @@
 //! Accounts can be created or imported. Once they are tracked by the client, their state will be
 //! updated accordingly on every transaction, and validated against the rollup on every sync.
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
-use miden_lib::AuthScheme;
+use miden_lib::{accounts::auth::RpoFalcon512, transaction::TransactionKernel, AuthScheme};
 pub use miden_objects::accounts::{
     Account, AccountCode, AccountData, AccountHeader, AccountId, AccountStorage,
     AccountStorageMode, AccountType, StorageSlot, StorageSlotType,
 };
 use miden_objects::{
-    accounts::AuthSecretKey,
+    accounts::{AccountBuilder, AccountComponent, AuthSecretKey},
     assets::TokenSymbol,
     crypto::{dsa::rpo_falcon512::SecretKey, rand::FeltRng},
-    Felt, Word,
+    Digest, Felt, Word,
 };
+use once_cell::race::OnceBox;
 
 use super::Client;
 use crate::ClientError;
@@ -43,6 +44,13 @@ pub enum AccountTemplate {
         /// Specifies the type of storage used by the account.
         storage_mode: AccountStorageMode,
     },
+
+    /// The `NonFungibleFaucet` variant represents an account designed to issue non-fungible
+    /// assets. See [basic_non_fungible_faucet_component] for the procedures it exposes.
+    NonFungibleFaucet {
+        /// Specifies the type of storage used by the account.
+        storage_mode: AccountStorageMode,
+    },
 }
 
 impl<R: FeltRng> Client<R> {
@@ -65,6 +73,9 @@ impl<R: FeltRng> Client<R> {
                 max_supply,
                 storage_mode,
             } => self.new_fungible_faucet(token_symbol, decimals, max_supply, storage_mode).await,
+            AccountTemplate::NonFungibleFaucet { storage_mode } => {
+                self.new_non_fungible_faucet(storage_mode).await
+            },
         }?;
 
         self.store.add_note_tag((&account_and_seed.0).try_into()?).await?;
@@ -165,6 +176,29 @@ impl<R: FeltRng> Client<R> {
         Ok((account, seed))
     }
 
+    async fn new_non_fungible_faucet(
+        &mut self,
+        account_storage_mode: AccountStorageMode,
+    ) -> Result<(Account, Word), ClientError> {
+        let key_pair = SecretKey::with_rng(&mut self.rng);
+
+        // we need to use an initial seed to create the faucet account
+        let mut init_seed = [0u8; 32];
+        self.rng.fill_bytes(&mut init_seed);
+
+        let (account, seed) = AccountBuilder::new()
+            .init_seed(init_seed)
+            .account_type(AccountType::NonFungibleFaucet)
+            .storage_mode(account_storage_mode)
+            .with_component(RpoFalcon512::new(key_pair.public_key()))
+            .with_component(basic_non_fungible_faucet_component())
+            .build()?;
+
+        self.insert_account(&account, Some(seed), &AuthSecretKey::RpoFalcon512(key_pair))
+            .await?;
+        Ok((account, seed))
+    }
+
     /// Inserts a new account into the client's store.
     ///
     /// # Errors
@@ -176,6 +210,32 @@ impl<R: FeltRng> Client<R> {
         account: &Account,
         account_seed: Option<Word>,
         auth_info: &AuthSecretKey,
+    ) -> Result<(), ClientError> {
+        self.store_account(account, account_seed, Some(auth_info)).await
+    }
+
+    /// Inserts a new account into the client's store without its secret key.
+    ///
+    /// The client can execute transactions against the account as long as its authenticator can
+    /// sign them without the store, like the `AirGappedAuthenticator` does.
+    ///
+    /// # Errors
+    ///
+    /// If an account is new and no seed is provided, the function errors out because the client
+    /// cannot execute transactions against new accounts for which it does not know the seed.
+    pub async fn import_account_without_auth(
+        &mut self,
+        account: &Account,
+        account_seed: Option<Word>,
+    ) -> Result<(), ClientError> {
+        self.store_account(account, account_seed, None).await
+    }
+
+    async fn store_account(
+        &mut self,
+        account: &Account,
+        account_seed: Option<Word>,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), ClientError> {
         if account.is_new() && account_seed.is_none() {
             return Err(ClientError::ImportNewAccountWithoutSeed);
@@ -248,6 +308,69 @@ impl<R: FeltRng> Client<R> {
     }
 }
 
+// NON-FUNGIBLE FAUCET COMPONENT
+// ================================================================================================
+
+/// Source code of the component exposed by the non-fungible faucets created by the client.
+///
+/// The `distribute` procedure mints the non-fungible asset on top of the stack and adds it to a
+/// new note built from the rest of the inputs.
+const BASIC_NON_FUNGIBLE_FAUCET_CODE: &str = "
+    use.miden::faucet
+    use.miden::tx
+
+    #! Mints a non-fungible asset and distributes it to the specified recipient in a new note.
+    #!
+    #! Inputs:  [ASSET, tag, aux, note_type, execution_hint, RECIPIENT, pad(4)]
+    #! Outputs: [note_idx, pad(15)]
+    export.distribute
+        exec.faucet::mint
+        # => [ASSET, tag, aux, note_type, execution_hint, RECIPIENT, pad(4)]
+
+        movdnw.2
+        # => [tag, aux, note_type, execution_hint, RECIPIENT, ASSET, pad(4)]
+
+        exec.tx::create_note
+        # => [note_idx, ASSET, pad(11)]
+
+        movdn.4 exec.tx::add_asset_to_note
+        # => [ASSET, note_idx, pad(11)]
+
+        dropw
+        # => [note_idx, pad(15)]
+    end
+";
+
+/// Returns the account component used by the client to build non-fungible faucets.
+///
+/// It only supports [AccountType::NonFungibleFaucet] accounts and exposes a single `distribute`
+/// procedure, which mints a non-fungible asset issued by the faucet and sends it in a new note.
+pub fn basic_non_fungible_faucet_component() -> AccountComponent {
+    AccountComponent::compile(
+        BASIC_NON_FUNGIBLE_FAUCET_CODE,
+        TransactionKernel::assembler(),
+        vec![],
+    )
+    .expect("basic non-fungible faucet code should compile")
+    .with_supported_type(AccountType::NonFungibleFaucet)
+}
+
+/// Returns the MAST root of the `distribute` procedure of [basic_non_fungible_faucet_component].
+///
+/// The component is only compiled the first time the root is requested.
+pub(crate) fn non_fungible_faucet_distribute_root() -> Digest {
+    static DISTRIBUTE_ROOT: OnceBox<Digest> = OnceBox::new();
+
+    *DISTRIBUTE_ROOT.get_or_init(|| {
+        let root = basic_non_fungible_faucet_component()
+            .mast_forest()
+            .procedure_digests()
+            .next()
+            .expect("basic non-fungible faucet component should export a procedure");
+        Box::new(root)
+    })
+}
+
 // TESTS
 // ================================================================================================
 
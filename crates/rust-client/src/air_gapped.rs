@@ -0,0 +1,334 @@
+//! Provides the types used to sign transactions on a machine that holds the keys but has no
+//! network access.
+//!
+//! The [AirGappedAuthenticator] exports a [SigningRequest] for every signature needed while
+//! executing a transaction. The request is signed on the offline machine with
+//! [SigningRequest::sign], and the resulting [SigningResponse] is handed back to the
+//! authenticator, which completes the execution.
+//!
+//! The message of a request is a commitment computed by the transaction kernel, which the offline
+//! machine can't recompute from the request. The [AccountDelta] included in the request is only
+//! informative: nothing binds it to the message, so it should only be trusted as much as the
+//! machine that exported the request.
+//!
+//! Signatures are only accepted while the execution that requested them waits. Accepting a
+//! signature after the execution ended is not supported: the VM can't suspend an execution until
+//! the signature arrives, and executing the transaction again usually produces a different
+//! message, as its output notes get new serial numbers.
+
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::{AccountDelta, AuthSecretKey},
+    crypto::hash::rpo::Rpo256,
+    Digest, Felt, Word,
+};
+use miden_tx::{
+    auth::signatures::get_falcon_signature,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AuthenticationError,
+};
+use rand::Rng;
+
+/// Extension of the files that contain signing requests.
+pub const REQUEST_FILE_EXTENSION: &str = "request";
+
+/// Extension of the files that contain signing responses.
+pub const SIGNATURE_FILE_EXTENSION: &str = "signature";
+
+// SIGNING REQUEST
+// ================================================================================================
+
+/// A request for a signature over a transaction message, exported so that it can be signed
+/// outside of the process that executes the transaction.
+///
+/// The signature only commits to the message. The account delta is reported by the process that
+/// exported the request, and isn't authenticated by the signature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningRequest {
+    pub_key: Word,
+    message: Word,
+    account_delta: AccountDelta,
+}
+
+impl SigningRequest {
+    /// Returns a new [SigningRequest].
+    pub fn new(pub_key: Word, message: Word, account_delta: AccountDelta) -> Self {
+        Self { pub_key, message, account_delta }
+    }
+
+    /// Returns the identifier of the request, computed from the public key and the message.
+    pub fn id(&self) -> Digest {
+        Rpo256::merge(&[self.pub_key.into(), self.message.into()])
+    }
+
+    /// Returns the public key of the key that should sign the request.
+    pub fn pub_key(&self) -> Word {
+        self.pub_key
+    }
+
+    /// Returns the message to be signed.
+    pub fn message(&self) -> Word {
+        self.message
+    }
+
+    /// Returns the changes to the account made by the transaction, so they can be reviewed
+    /// before signing.
+    ///
+    /// The delta is reported by the process that exported the request and isn't bound to the
+    /// message, so a compromised exporter can show a delta that differs from the one the
+    /// signature authorizes.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Signs the request with the provided secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [AuthenticationError::UnknownKey] if the secret key does not correspond to the
+    /// requested public key.
+    pub fn sign(
+        &self,
+        secret_key: &AuthSecretKey,
+        rng: &mut impl Rng,
+    ) -> Result<SigningResponse, AuthenticationError> {
+        let AuthSecretKey::RpoFalcon512(key) = secret_key;
+        if Word::from(key.public_key()) != self.pub_key {
+            return Err(AuthenticationError::UnknownKey(Digest::from(self.pub_key).to_string()));
+        }
+
+        let signature = get_falcon_signature(key, self.message, rng)?;
+
+        Ok(SigningResponse {
+            pub_key: self.pub_key,
+            message: self.message,
+            signature,
+        })
+    }
+}
+
+impl Serializable for SigningRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.pub_key.write_into(target);
+        self.message.write_into(target);
+        self.account_delta.write_into(target);
+    }
+}
+
+impl Deserializable for SigningRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key = Word::read_from(source)?;
+        let message = Word::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+
+        Ok(Self { pub_key, message, account_delta })
+    }
+}
+
+// SIGNING RESPONSE
+// ================================================================================================
+
+/// A signature produced for a [SigningRequest].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningResponse {
+    pub_key: Word,
+    message: Word,
+    signature: Vec<Felt>,
+}
+
+impl SigningResponse {
+    /// Returns the identifier of the request this response was produced for.
+    pub fn request_id(&self) -> Digest {
+        Rpo256::merge(&[self.pub_key.into(), self.message.into()])
+    }
+
+    /// Returns the signature, in the format expected by the transaction kernel.
+    pub fn signature(&self) -> &[Felt] {
+        &self.signature
+    }
+}
+
+impl Serializable for SigningResponse {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.pub_key.write_into(target);
+        self.message.write_into(target);
+        self.signature.write_into(target);
+    }
+}
+
+impl Deserializable for SigningResponse {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key = Word::read_from(source)?;
+        let message = Word::read_from(source)?;
+        let signature = Vec::<Felt>::read_from(source)?;
+
+        Ok(Self { pub_key, message, signature })
+    }
+}
+
+// AIR-GAPPED AUTHENTICATOR
+// ================================================================================================
+
+#[cfg(feature = "std")]
+pub use authenticator::AirGappedAuthenticator;
+
+#[cfg(feature = "std")]
+mod authenticator {
+    use alloc::vec::Vec;
+    use std::{
+        fs, io,
+        path::{Path, PathBuf},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use miden_objects::{accounts::AccountDelta, Felt, Word};
+    use miden_tx::{
+        auth::TransactionAuthenticator,
+        utils::{Deserializable, Serializable},
+        AuthenticationError,
+    };
+
+    use super::{
+        SigningRequest, SigningResponse, REQUEST_FILE_EXTENSION, SIGNATURE_FILE_EXTENSION,
+    };
+
+    /// How often the signatures directory is checked while waiting for a signature.
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// A [TransactionAuthenticator] that never has access to secret keys.
+    ///
+    /// Whenever a signature is needed, the authenticator writes a [SigningRequest] to
+    /// `<dir>/<request ID>.request` and waits, up to the configured timeout, for the matching
+    /// [SigningResponse] to be placed in `<dir>/<request ID>.signature`.
+    ///
+    /// The signature has to be provided while the execution waits for it. Request IDs depend on
+    /// the message, which commits to the transaction's output notes, and output notes usually
+    /// get random serial numbers. Executing the transaction again then produces a different
+    /// request, so a signature provided after the timeout can't be used.
+    ///
+    /// [TransactionAuthenticator::get_signature] is synchronous, as the VM requests signatures
+    /// in the middle of an execution, so waiting for a signature blocks the thread that executes
+    /// the transaction. Other tasks of a single-threaded async executor don't make progress in
+    /// the meantime, so clients that run other tasks alongside executions should execute
+    /// transactions on a dedicated thread.
+    pub struct AirGappedAuthenticator {
+        dir: PathBuf,
+        timeout: Duration,
+    }
+
+    impl AirGappedAuthenticator {
+        /// Returns a new authenticator that exchanges files through `dir`, and waits up to
+        /// `timeout` for every signature before failing the execution.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `timeout` is zero, as the signature could never be provided in time.
+        pub fn new(dir: impl Into<PathBuf>, timeout: Duration) -> Self {
+            assert!(!timeout.is_zero(), "air-gapped signing timeout should be greater than zero");
+            Self { dir: dir.into(), timeout }
+        }
+
+        /// Returns the path of the file the request is exported to.
+        pub fn request_path(&self, request: &SigningRequest) -> PathBuf {
+            self.dir.join(request.id().to_hex()).with_extension(REQUEST_FILE_EXTENSION)
+        }
+
+        /// Returns the path of the file the signature for the request is expected in.
+        pub fn signature_path(&self, request: &SigningRequest) -> PathBuf {
+            self.dir.join(request.id().to_hex()).with_extension(SIGNATURE_FILE_EXTENSION)
+        }
+
+        fn export_request(&self, request: &SigningRequest) -> Result<(), AuthenticationError> {
+            let path = self.request_path(request);
+            if path.exists() {
+                return Ok(());
+            }
+
+            fs::create_dir_all(&self.dir)
+                .and_then(|_| fs::write(&path, request.to_bytes()))
+                .map_err(|err| {
+                    AuthenticationError::InternalError(format!(
+                        "failed to write signing request {}: {err}",
+                        path.display()
+                    ))
+                })
+        }
+
+        fn read_response(
+            path: &Path,
+            request: &SigningRequest,
+        ) -> Result<Option<SigningResponse>, AuthenticationError> {
+            let contents = match fs::read(path) {
+                Ok(contents) => contents,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => {
+                    return Err(AuthenticationError::InternalError(format!(
+                        "failed to read signature file {}: {err}",
+                        path.display()
+                    )))
+                },
+            };
+
+            let response = SigningResponse::read_from_bytes(&contents).map_err(|err| {
+                AuthenticationError::InternalError(format!(
+                    "failed to parse signature file {}: {err}",
+                    path.display()
+                ))
+            })?;
+
+            if response.request_id() != request.id() {
+                return Err(AuthenticationError::RejectedSignature(format!(
+                    "signature file {} does not correspond to signing request {}",
+                    path.display(),
+                    request.id()
+                )));
+            }
+
+            Ok(Some(response))
+        }
+    }
+
+    impl TransactionAuthenticator for AirGappedAuthenticator {
+        /// Exports a signing request and returns the signature provided for it.
+        ///
+        /// Blocks the calling thread until the signature is provided or the timeout elapses.
+        ///
+        /// # Errors
+        /// If no signature is provided before the timeout, [AuthenticationError::RejectedSignature]
+        /// is returned and the request is removed. If the signatures directory can't be read or
+        /// written, [AuthenticationError::InternalError] is returned.
+        fn get_signature(
+            &self,
+            pub_key: Word,
+            message: Word,
+            account_delta: &AccountDelta,
+        ) -> Result<Vec<Felt>, AuthenticationError> {
+            let request = SigningRequest::new(pub_key, message, account_delta.clone());
+            let signature_path = self.signature_path(&request);
+
+            self.export_request(&request)?;
+
+            let start = Instant::now();
+            loop {
+                if let Some(response) = Self::read_response(&signature_path, &request)? {
+                    let _ = fs::remove_file(self.request_path(&request));
+                    return Ok(response.signature);
+                }
+
+                let elapsed = start.elapsed();
+                if elapsed >= self.timeout {
+                    let _ = fs::remove_file(self.request_path(&request));
+                    return Err(AuthenticationError::RejectedSignature(format!(
+                        "no signature was placed in {} within {} seconds",
+                        signature_path.display(),
+                        self.timeout.as_secs()
+                    )));
+                }
+
+                thread::sleep(POLL_INTERVAL.min(self.timeout - elapsed));
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Provides code related to configuring the client.
 
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
 use core::fmt::{self, Debug};
 
 use serde::{Deserialize, Serialize};
@@ -127,26 +130,98 @@ pub struct RpcConfig {
     /// Timeout for the RPC api requests, in milliseconds.
     #[serde(default = "default_timeout")]
     pub timeout_ms: u64,
+    /// Additional metadata headers attached to every request sent to the node.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// API key sent to the node as a bearer token in the `authorization` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// TLS settings used when connecting to the node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<RpcTlsConfig>,
+    /// Maximum number of note IDs sent in a single `GetNotesById` request. Larger requests are
+    /// split into chunks of this size.
+    #[serde(default = "default_notes_chunk_size")]
+    pub notes_chunk_size: usize,
+    /// Maximum number of chunked requests that can be in flight at the same time.
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+}
+
+impl RpcConfig {
+    /// Returns the metadata headers to attach to every request, including the `authorization`
+    /// header built from the API key if one was provided.
+    pub fn request_headers(&self) -> BTreeMap<String, String> {
+        let mut headers = self.headers.clone();
+        if let Some(api_key) = &self.api_key {
+            headers.insert(AUTHORIZATION_HEADER.to_string(), format!("Bearer {api_key}"));
+        }
+        headers
+    }
 }
 
 const fn default_timeout() -> u64 {
     10000
 }
 
+/// Default maximum number of note IDs sent in a single `GetNotesById` request.
+pub const DEFAULT_NOTES_CHUNK_SIZE: usize = 100;
+
+const fn default_notes_chunk_size() -> usize {
+    DEFAULT_NOTES_CHUNK_SIZE
+}
+
+/// Default maximum number of chunked RPC requests that can be in flight at the same time.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
+
+const fn default_max_concurrent_requests() -> usize {
+    DEFAULT_MAX_CONCURRENT_REQUESTS
+}
+
 impl Default for RpcConfig {
     fn default() -> Self {
         Self {
             endpoint: Endpoint::default(),
             timeout_ms: 10000,
+            headers: BTreeMap::new(),
+            api_key: None,
+            tls: None,
+            notes_chunk_size: DEFAULT_NOTES_CHUNK_SIZE,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
         }
     }
 }
 
+const AUTHORIZATION_HEADER: &str = "authorization";
+
+// RPC TLS CONFIG
+// ================================================================================================
+
+/// TLS settings for the RPC client.
+///
+/// Certificates and keys are expected to be PEM encoded files. These settings are only used by
+/// native clients, in the browser TLS is handled by the user agent.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RpcTlsConfig {
+    /// Path to a custom root CA certificate used to verify the node's certificate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert_path: Option<String>,
+    /// Path to the client certificate used for mutual TLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert_path: Option<String>,
+    /// Path to the private key of the client certificate used for mutual TLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key_path: Option<String>,
+    /// Domain name the node's certificate is verified against. Defaults to the endpoint's host.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+}
+
 #[cfg(test)]
 mod test {
     use alloc::string::ToString;
 
-    use crate::config::{Endpoint, MIDEN_NODE_PORT};
+    use crate::config::{Endpoint, RpcConfig, DEFAULT_NOTES_CHUNK_SIZE, MIDEN_NODE_PORT};
 
     #[test]
     fn test_endpoint_parsing_with_hostname_only() {
@@ -249,4 +324,26 @@ mod test {
         let endpoint = Endpoint::try_from("some.test.domain:8000/hello");
         assert!(endpoint.is_err());
     }
+
+    #[test]
+    fn test_rpc_config_request_headers() {
+        let rpc_config: RpcConfig = serde_json::from_str(
+            r#"{
+                "endpoint": { "protocol": "https", "host": "some.test.domain", "port": 443 },
+                "headers": { "x-tenant-id": "tenant" },
+                "api_key": "secret",
+                "tls": { "ca_cert_path": "ca.pem" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(rpc_config.timeout_ms, 10000);
+        assert_eq!(rpc_config.notes_chunk_size, DEFAULT_NOTES_CHUNK_SIZE);
+        assert_eq!(rpc_config.tls.as_ref().unwrap().ca_cert_path, Some("ca.pem".to_string()));
+
+        let headers = rpc_config.request_headers();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get("x-tenant-id"), Some(&"tenant".to_string()));
+        assert_eq!(headers.get("authorization"), Some(&"Bearer secret".to_string()));
+    }
 }
@@ -5,8 +5,8 @@ use alloc::{
 use core::fmt;
 
 use miden_objects::{
-    accounts::AccountId, crypto::merkle::MerkleError, notes::NoteId, AccountError, AssetError,
-    NoteError, TransactionScriptError,
+    accounts::AccountId, crypto::merkle::MerkleError, notes::NoteId, transaction::TransactionId,
+    AccountError, AssetError, NoteError, TransactionScriptError,
 };
 use miden_tx::{
     utils::{DeserializationError, HexParseError},
@@ -14,10 +14,12 @@ use miden_tx::{
 };
 
 use crate::{
-    notes::NoteScreenerError,
+    notes::{NoteScreenerError, SwapOrderStatus},
     rpc::RpcError,
     store::{NoteRecordError, StoreError},
-    transactions::{TransactionRequestError, TransactionScriptBuilderError},
+    transactions::{
+        SpendingPolicyViolation, TransactionRequestError, TransactionScriptBuilderError,
+    },
 };
 
 // CLIENT ERROR
@@ -38,14 +40,21 @@ pub enum ClientError {
     NoteImportError(String),
     NoteRecordError(NoteRecordError),
     NoConsumableNoteForAccount(AccountId),
+    RecurringPaymentNotFound(u32),
     RpcError(RpcError),
     NoteScreenerError(NoteScreenerError),
+    SharedClientError(String),
+    SpendingPolicyViolation(AccountId, SpendingPolicyViolation),
     StoreError(StoreError),
+    SwapOrderNotCancellable(NoteId),
+    SwapOrderNotFound(NoteId),
+    SwapOrderNotOpen(NoteId, SwapOrderStatus),
     TransactionExecutorError(TransactionExecutorError),
     TransactionProvingError(TransactionProverError),
     TransactionRequestError(TransactionRequestError),
     TransactionScriptBuilderError(TransactionScriptBuilderError),
     TransactionScriptError(TransactionScriptError),
+    UnsubmittedTransactionNotFound(TransactionId),
 }
 
 impl fmt::Display for ClientError {
@@ -80,9 +89,27 @@ impl fmt::Display for ClientError {
             ClientError::NoteError(err) => write!(f, "Note error: {err}"),
             ClientError::NoteImportError(err) => write!(f, "Error importing note: {err}"),
             ClientError::NoteRecordError(err) => write!(f, "Note record error: {err}"),
+            ClientError::RecurringPaymentNotFound(payment_id) => {
+                write!(f, "No recurring payment with ID {payment_id} is stored")
+            },
             ClientError::RpcError(err) => write!(f, "RPC api error: {err}"),
             ClientError::NoteScreenerError(err) => write!(f, "Note screener error: {err}"),
+            ClientError::SharedClientError(err) => write!(f, "Shared client error: {err}"),
+            ClientError::SpendingPolicyViolation(account_id, violation) => {
+                write!(f, "Spending policy of account {account_id} violated: {violation}")
+            },
             ClientError::StoreError(err) => write!(f, "Store error: {err}"),
+            ClientError::SwapOrderNotCancellable(note_id) => write!(
+                f,
+                "The SWAP order for note {note_id} can't be cancelled, as the SWAP note script \
+                 doesn't let its creator reclaim the offered asset"
+            ),
+            ClientError::SwapOrderNotFound(note_id) => {
+                write!(f, "Note {note_id} is not a SWAP note created by the client")
+            },
+            ClientError::SwapOrderNotOpen(note_id, status) => {
+                write!(f, "The SWAP order for note {note_id} can't be cancelled, as it is {status}")
+            },
             ClientError::TransactionExecutorError(err) => {
                 write!(f, "Transaction executor error: {err}")
             },
@@ -98,6 +125,9 @@ impl fmt::Display for ClientError {
             ClientError::TransactionScriptError(err) => {
                 write!(f, "Transaction script error: {err}")
             },
+            ClientError::UnsubmittedTransactionNotFound(transaction_id) => {
+                write!(f, "No unsubmitted transaction with ID {transaction_id} is stored")
+            },
         }
     }
 }
@@ -9,9 +9,12 @@ pub use alloc::boxed::Box;
 extern crate std;
 
 pub mod accounts;
+mod air_gapped;
 pub mod config;
 pub mod notes;
 pub mod rpc;
+#[cfg(feature = "shared")]
+pub mod shared;
 pub mod store;
 pub mod sync;
 pub mod transactions;
@@ -33,7 +36,10 @@ pub mod assets {
         accounts::delta::{
             AccountVaultDelta, FungibleAssetDelta, NonFungibleAssetDelta, NonFungibleDeltaAction,
         },
-        assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, TokenSymbol},
+        assets::{
+            Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails,
+            TokenSymbol,
+        },
     };
 }
 
@@ -42,6 +48,12 @@ pub mod assets {
 pub mod auth {
     pub use miden_objects::accounts::AuthSecretKey;
     pub use miden_tx::auth::{BasicAuthenticator, TransactionAuthenticator};
+
+    #[cfg(feature = "std")]
+    pub use crate::air_gapped::AirGappedAuthenticator;
+    pub use crate::air_gapped::{
+        SigningRequest, SigningResponse, REQUEST_FILE_EXTENSION, SIGNATURE_FILE_EXTENSION,
+    };
 }
 
 /// Provides types for working with blocks within the Miden rollup network.
@@ -85,13 +97,14 @@ pub mod testing {
     pub use miden_objects::{accounts::account_id::testing::*, testing::*};
 }
 
-use alloc::sync::Arc;
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 
-use miden_objects::crypto::rand::FeltRng;
+use miden_objects::{accounts::AccountId, crypto::rand::FeltRng};
 use miden_tx::{auth::TransactionAuthenticator, DataStore, TransactionExecutor, TransactionProver};
 use rpc::NodeRpcClient;
 use store::{data_store::ClientDataStore, Store};
 use tracing::info;
+use transactions::{AccountInterface, BasicAccountInterface, ForeignAccountInputs};
 
 // MIDEN CLIENT
 // ================================================================================================
@@ -116,6 +129,17 @@ pub struct Client<R: FeltRng> {
     /// An instance of [TransactionProver] which delegates proving.
     tx_prover: Arc<dyn TransactionProver>,
     tx_executor: TransactionExecutor,
+    /// The authenticator used by the transaction executor, kept to build executors that run
+    /// transactions on top of state that wasn't applied to the store yet.
+    authenticator: Arc<dyn TransactionAuthenticator>,
+    /// Whether transaction executors are instantiated in debug mode.
+    in_debug_mode: bool,
+    /// Verified state of the foreign accounts used by previous transactions, reused by later
+    /// transactions until the next sync that moves the sync height.
+    foreign_account_cache: BTreeMap<AccountId, ForeignAccountInputs>,
+    /// Interfaces detected on the client's accounts to build their transaction scripts, in order
+    /// of preference.
+    account_interfaces: Vec<Arc<dyn AccountInterface>>,
 }
 
 impl<R: FeltRng> Client<R> {
@@ -156,9 +180,8 @@ impl<R: FeltRng> Client<R> {
         }
 
         let data_store = Arc::new(ClientDataStore::new(store.clone())) as Arc<dyn DataStore>;
-        let authenticator = Some(authenticator);
-        let tx_executor =
-            TransactionExecutor::new(data_store, authenticator).with_debug_mode(in_debug_mode);
+        let tx_executor = TransactionExecutor::new(data_store, Some(authenticator.clone()))
+            .with_debug_mode(in_debug_mode);
 
         Self {
             store,
@@ -166,6 +189,13 @@ impl<R: FeltRng> Client<R> {
             rpc_api,
             tx_executor,
             tx_prover,
+            authenticator,
+            in_debug_mode,
+            foreign_account_cache: BTreeMap::new(),
+            account_interfaces: BasicAccountInterface::ALL
+                .into_iter()
+                .map(|interface| Arc::new(interface) as Arc<dyn AccountInterface>)
+                .collect(),
         }
     }
 
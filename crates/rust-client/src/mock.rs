@@ -1,9 +1,11 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
+    string::ToString,
     sync::Arc,
     vec::Vec,
 };
-use std::env::temp_dir;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::{env::temp_dir, sync::Mutex};
 
 use async_trait::async_trait;
 use miden_lib::transaction::TransactionKernel;
@@ -12,20 +14,22 @@ use miden_objects::{
         account_id::testing::{
             ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
         },
-        AccountId,
+        Account, AccountHeader, AccountId,
     },
     assets::{FungibleAsset, NonFungibleAsset},
     block::Block,
     crypto::{
-        merkle::{Mmr, MmrProof},
+        merkle::{LeafIndex, Mmr, MmrProof, SimpleSmt},
         rand::RpoRandomCoin,
     },
     notes::{Note, NoteId, NoteTag},
     testing::notes::NoteBuilder,
-    transaction::{InputNote, ProvenTransaction},
-    BlockHeader, Digest, Felt, Word,
+    transaction::{InputNote, ProvenTransaction, TransactionId},
+    BlockHeader, Digest, Felt, Word, ACCOUNT_TREE_DEPTH,
+};
+use miden_tx::{
+    auth::TransactionAuthenticator, testing::mock_chain::MockChain, LocalTransactionProver,
 };
-use miden_tx::{testing::mock_chain::MockChain, LocalTransactionProver};
 use rand::Rng;
 use tonic::Response;
 use uuid::Uuid;
@@ -36,13 +40,15 @@ use crate::{
             note::NoteSyncRecord,
             responses::{NullifierUpdate, SyncNoteResponse, SyncStateResponse},
         },
-        AccountDetails, AccountProofs, NodeRpcClient, NoteDetails, NoteInclusionDetails, RpcError,
+        AccountDetails, AccountProof, AccountProofs, AccountUpdateSummary, NodeRpcClient,
+        NodeRpcClientEndpoint, NoteDetails, NoteInclusionDetails, RpcError, StateHeaders,
         StateSyncInfo,
     },
     store::{
         sqlite_store::{config::SqliteStoreConfig, SqliteStore},
         StoreAuthenticator,
     },
+    sync::get_nullifier_prefix,
     Client,
 };
 
@@ -57,6 +63,26 @@ pub struct MockRpcApi {
     pub notes: BTreeMap<NoteId, InputNote>,
     pub blocks: Vec<Block>,
     pub mock_chain: MockChain,
+    /// Public accounts included in the chain since genesis, whose state and proofs are served.
+    pub public_accounts: BTreeMap<AccountId, Account>,
+    /// When set, transaction submissions fail as if the node could not be reached. It's shared
+    /// between clones, so it can be toggled after the client is created.
+    pub submission_unavailable: Arc<AtomicBool>,
+    /// When set, transaction submissions are accepted by the node but fail as if the response
+    /// timed out. It's shared between clones, like `submission_unavailable`.
+    pub submission_timed_out: Arc<AtomicBool>,
+    /// When set, requests for the state of accounts fail as if the node could not be reached.
+    /// It's shared between clones, like `submission_unavailable`.
+    pub account_updates_unavailable: Arc<AtomicBool>,
+    /// The transactions accepted by the node, and the latest state hash of their accounts.
+    pub submitted_transactions: Arc<Mutex<SubmittedTransactions>>,
+}
+
+/// Transactions accepted by the [MockRpcApi], shared between its clones.
+#[derive(Default)]
+pub struct SubmittedTransactions {
+    pub transaction_ids: BTreeSet<TransactionId>,
+    pub account_hashes: BTreeMap<AccountId, Digest>,
 }
 impl Default for MockRpcApi {
     fn default() -> Self {
@@ -66,11 +92,25 @@ impl Default for MockRpcApi {
 impl MockRpcApi {
     /// Creates a new `MockRpcApi` instance with pre-populated blocks and notes.
     pub fn new() -> Self {
-        let mock_chain = MockChain::new();
+        Self::with_public_accounts(vec![])
+    }
+
+    /// Creates a new `MockRpcApi` instance with pre-populated blocks and notes, whose chain
+    /// includes the state of `public_accounts`.
+    pub fn with_public_accounts(public_accounts: Vec<Account>) -> Self {
+        let mock_chain = MockChain::with_accounts(&public_accounts);
         let mut api = Self {
             notes: BTreeMap::new(),
             blocks: vec![],
             mock_chain,
+            public_accounts: public_accounts
+                .into_iter()
+                .map(|account| (account.id(), account))
+                .collect(),
+            submission_unavailable: Arc::new(AtomicBool::new(false)),
+            submission_timed_out: Arc::new(AtomicBool::new(false)),
+            account_updates_unavailable: Arc::new(AtomicBool::new(false)),
+            submitted_transactions: Arc::new(Mutex::new(SubmittedTransactions::default())),
         };
 
         let note_first = NoteBuilder::new(
@@ -103,7 +143,11 @@ impl MockRpcApi {
     }
 
     /// Seals a block with the given notes and nullifiers.
-    fn seal_block(&mut self, notes: Vec<Note>, nullifiers: Vec<miden_objects::notes::Nullifier>) {
+    pub fn seal_block(
+        &mut self,
+        notes: Vec<Note>,
+        nullifiers: Vec<miden_objects::notes::Nullifier>,
+    ) {
         for note in notes {
             self.mock_chain.add_note(note);
         }
@@ -190,7 +234,7 @@ impl MockRpcApi {
             if note.location().map_or(false, |loc| loc.block_num() == block_num) {
                 let proof = note.proof()?;
                 Some(NoteSyncRecord {
-                    note_index: 0,
+                    note_index: proof.location().node_index_in_block().into(),
                     note_id: Some(note.id().into()),
                     metadata: Some((*note.note().metadata()).into()),
                     merkle_path: Some(proof.note_path().clone().into()),
@@ -225,12 +269,18 @@ impl NodeRpcClient for MockRpcApi {
         block_num: u32,
         _account_ids: &[AccountId],
         _note_tags: &[NoteTag],
-        _nullifiers_tags: &[u16],
+        nullifiers_tags: &[u16],
     ) -> Result<StateSyncInfo, RpcError> {
         // Match request -> response through block_num
         let response = self.get_sync_state_request(block_num);
 
-        Ok(response.try_into().unwrap())
+        let mut response: StateSyncInfo = response.try_into().unwrap();
+        // Like the node, only return nullifiers matching the requested prefixes
+        response.nullifiers.retain(|nullifier_update| {
+            nullifiers_tags.contains(&get_nullifier_prefix(&nullifier_update.nullifier))
+        });
+
+        Ok(response)
     }
 
     /// Creates and executes a [GetBlockHeaderByNumberRequest].
@@ -285,27 +335,95 @@ impl NodeRpcClient for MockRpcApi {
 
     async fn submit_proven_transaction(
         &mut self,
-        _proven_transaction: ProvenTransaction,
+        proven_transaction: ProvenTransaction,
     ) -> std::result::Result<(), RpcError> {
-        // TODO: add some basic validations to test error cases
+        if self.submission_unavailable.load(Ordering::Relaxed) {
+            return Err(RpcError::ConnectionError("node unavailable".to_string()));
+        }
+
+        let mut submitted_transactions = self.submitted_transactions.lock().unwrap();
+        if !submitted_transactions.transaction_ids.insert(proven_transaction.id()) {
+            return Err(RpcError::TransactionRejected("transaction already submitted".to_string()));
+        }
+        submitted_transactions.account_hashes.insert(
+            proven_transaction.account_id(),
+            proven_transaction.account_update().final_state_hash(),
+        );
+
+        if self.submission_timed_out.load(Ordering::Relaxed) {
+            return Err(RpcError::ConnectionError("request timed out".to_string()));
+        }
+
         Ok(())
     }
 
     async fn get_account_update(
         &mut self,
-        _account_id: AccountId,
+        account_id: AccountId,
     ) -> Result<AccountDetails, RpcError> {
-        panic!("shouldn't be used for now")
+        if self.account_updates_unavailable.load(Ordering::Relaxed) {
+            return Err(RpcError::ConnectionError("node unavailable".to_string()));
+        }
+
+        if let Some(account) = self.public_accounts.get(&account_id) {
+            return Ok(AccountDetails::Public(
+                account.clone(),
+                AccountUpdateSummary::new(account.hash(), self.blocks.len() as u32),
+            ));
+        }
+
+        let submitted_transactions = self.submitted_transactions.lock().unwrap();
+        let hash = submitted_transactions.account_hashes.get(&account_id).ok_or_else(|| {
+            RpcError::RequestError(
+                NodeRpcClientEndpoint::GetAccountDetails.to_string(),
+                format!("Account ID {account_id} was not found"),
+            )
+        })?;
+
+        Ok(AccountDetails::Private(
+            account_id,
+            AccountUpdateSummary::new(*hash, self.blocks.len() as u32),
+        ))
     }
 
     async fn get_account_proofs(
         &mut self,
-        _account_ids: &BTreeSet<AccountId>,
-        _code_commitments: &[Digest],
-        _include_headers: bool,
+        account_ids: &BTreeSet<AccountId>,
+        code_commitments: &[Digest],
+        include_headers: bool,
     ) -> Result<AccountProofs, RpcError> {
-        // TODO: Implement fully
-        Ok((self.blocks.last().unwrap().header().block_num(), vec![]))
+        // The account tree of the chain only contains the public accounts
+        let account_tree = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(
+            self.public_accounts
+                .values()
+                .map(|account| (u64::from(account.id()), Word::from(account.hash()))),
+        )
+        .unwrap();
+
+        let mut proofs = vec![];
+        for account_id in account_ids {
+            let account = self.public_accounts.get(account_id).ok_or_else(|| {
+                RpcError::RequestError(
+                    NodeRpcClientEndpoint::GetAccountProofs.to_string(),
+                    format!("Account ID {account_id} was not found"),
+                )
+            })?;
+
+            let state_headers = include_headers.then(|| StateHeaders {
+                account_header: AccountHeader::from(account),
+                storage_header: account.storage().get_header(),
+                code: (!code_commitments.contains(&account.code().commitment()))
+                    .then(|| account.code().clone()),
+            });
+
+            let merkle_proof =
+                account_tree.open(&LeafIndex::new_max_depth(u64::from(*account_id))).path;
+            let proof = AccountProof::new(*account_id, merkle_proof, account.hash(), state_headers)
+                .map_err(|_| RpcError::InvalidResponse("Inconsistent account proof".into()))?;
+            proofs.push(proof);
+        }
+
+        Ok((self.blocks.last().unwrap().header().block_num(), proofs))
     }
 
     async fn check_nullifiers_by_prefix(
@@ -321,6 +439,29 @@ impl NodeRpcClient for MockRpcApi {
 // ================================================================================================
 
 pub async fn create_test_client() -> (MockClient, MockRpcApi) {
+    create_test_client_with_authenticator(None).await
+}
+
+/// Creates a test client whose transactions are signed by `authenticator`, or by a
+/// [StoreAuthenticator] over the client's store if it's `None`.
+pub async fn create_test_client_with_authenticator(
+    authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+) -> (MockClient, MockRpcApi) {
+    let rpc_api = MockRpcApi::new();
+    let client = create_client(Box::new(rpc_api.clone()), authenticator).await;
+    (client, rpc_api)
+}
+
+/// Creates a test client that communicates with the node through `rpc_api`, such as a
+/// [MockRpcApi] with public accounts or a wrapper around one.
+pub async fn create_test_client_with_rpc_api(rpc_api: Box<dyn NodeRpcClient + Send>) -> MockClient {
+    create_client(rpc_api, None).await
+}
+
+async fn create_client(
+    rpc_api: Box<dyn NodeRpcClient + Send>,
+    authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+) -> MockClient {
     let store: SqliteStoreConfig = create_test_store_path()
         .into_os_string()
         .into_string()
@@ -336,14 +477,14 @@ pub async fn create_test_client() -> (MockClient, MockRpcApi) {
 
     let rng = RpoRandomCoin::new(coin_seed.map(Felt::new));
 
-    let authenticator = StoreAuthenticator::new_with_rng(store.clone(), rng);
-    let rpc_api = MockRpcApi::new();
-    let boxed_rpc_api = Box::new(rpc_api.clone());
+    let authenticator = authenticator.unwrap_or_else(|| {
+        Arc::new(StoreAuthenticator::new_with_rng(store.clone(), rng))
+            as Arc<dyn TransactionAuthenticator>
+    });
 
     let prover = Arc::new(LocalTransactionProver::default());
 
-    let client = MockClient::new(boxed_rpc_api, rng, store, Arc::new(authenticator), prover, true);
-    (client, rpc_api)
+    MockClient::new(rpc_api, rng, store, authenticator, prover, true)
 }
 
 pub fn create_test_store_path() -> std::path::PathBuf {
@@ -6,6 +6,7 @@ use miden_objects::{
 };
 
 use crate::{
+    rpc::verification::verify_note_inclusion_proof,
     store::{input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState},
     sync::NoteTagRecord,
     Client, ClientError,
@@ -156,16 +157,29 @@ impl<R: FeltRng> Client<R> {
             let block_height = inclusion_proof.location().block_num();
             let current_block_num = self.get_sync_height().await?;
 
-            let mut note_changed =
-                note_record.inclusion_proof_received(inclusion_proof, metadata)?;
-
-            if block_height < current_block_num {
+            let block_header = if block_height < current_block_num {
                 let mut current_partial_mmr = self.build_current_partial_mmr(true).await?;
 
                 let block_header = self
                     .get_and_store_authenticated_block(block_height, &mut current_partial_mmr)
                     .await?;
 
+                verify_note_inclusion_proof(
+                    note_record.id(),
+                    &metadata,
+                    &inclusion_proof,
+                    &block_header,
+                )?;
+
+                Some(block_header)
+            } else {
+                None
+            };
+
+            let mut note_changed =
+                note_record.inclusion_proof_received(inclusion_proof, metadata)?;
+
+            if let Some(block_header) = block_header {
                 note_changed |= note_record.block_header_received(block_header)?;
             }
 
@@ -212,6 +226,13 @@ impl<R: FeltRng> Client<R> {
                     )
                     .await?;
 
+                verify_note_inclusion_proof(
+                    note_record.id(),
+                    &metadata,
+                    &inclusion_proof,
+                    &block_header,
+                )?;
+
                 let note_changed =
                     note_record.inclusion_proof_received(inclusion_proof, metadata)?;
 
@@ -15,6 +15,7 @@ pub mod script_roots;
 
 mod import;
 mod note_screener;
+mod swap;
 
 // RE-EXPORTS
 // ================================================================================================
@@ -25,13 +26,14 @@ pub use miden_lib::notes::{
 };
 pub use miden_objects::{
     notes::{
-        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteFile, NoteId,
+        Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteFile, NoteId,
         NoteInclusionProof, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
         Nullifier,
     },
     NoteError,
 };
 pub use note_screener::{NoteConsumability, NoteRelevance, NoteScreener, NoteScreenerError};
+pub use swap::{SwapOrder, SwapOrderStatus};
 
 // MIDEN CLIENT
 // ================================================================================================
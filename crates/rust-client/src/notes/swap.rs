@@ -0,0 +1,274 @@
+//! Contains the Client APIs to track and cancel the SWAP notes created by the client's accounts.
+
+use alloc::{collections::BTreeMap, string::ToString, vec, vec::Vec};
+use core::fmt;
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    crypto::rand::FeltRng,
+    notes::{NoteAssets, NoteId},
+    Digest, Word,
+};
+
+use super::script_roots::SWAP;
+use crate::{
+    store::{InputNoteRecord, NoteFilter, OutputNoteRecord},
+    transactions::TransactionResult,
+    Client, ClientError,
+};
+
+// SWAP ORDER
+// ================================================================================================
+
+/// Status of a [SwapOrder].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwapOrderStatus {
+    /// The SWAP note has not been consumed yet.
+    Open,
+    /// The SWAP note was consumed by another account, and the payback note is waiting to be
+    /// consumed.
+    Filled,
+    /// The payback note was consumed by one of the client's accounts.
+    Consumed,
+}
+
+impl fmt::Display for SwapOrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwapOrderStatus::Open => write!(f, "Open"),
+            SwapOrderStatus::Filled => write!(f, "Filled"),
+            SwapOrderStatus::Consumed => write!(f, "Consumed"),
+        }
+    }
+}
+
+/// A SWAP note created by one of the client's accounts, along with the payback note that is
+/// created when the SWAP note is consumed.
+#[derive(Clone, Debug)]
+pub struct SwapOrder {
+    swap_note: OutputNoteRecord,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    payback_note_id: NoteId,
+    status: SwapOrderStatus,
+}
+
+impl SwapOrder {
+    /// Returns the ID of the SWAP note.
+    pub fn id(&self) -> NoteId {
+        self.swap_note.id()
+    }
+
+    /// Returns the ID of the account that created the SWAP note.
+    pub fn creator(&self) -> AccountId {
+        self.swap_note.metadata().sender()
+    }
+
+    /// Returns the record of the SWAP note.
+    pub fn swap_note(&self) -> &OutputNoteRecord {
+        &self.swap_note
+    }
+
+    /// Returns the asset carried by the SWAP note.
+    pub fn offered_asset(&self) -> Asset {
+        self.offered_asset
+    }
+
+    /// Returns the asset requested in exchange for the offered asset.
+    pub fn requested_asset(&self) -> Asset {
+        self.requested_asset
+    }
+
+    /// Returns the ID of the payback note that is created when the SWAP note is consumed.
+    pub fn payback_note_id(&self) -> NoteId {
+        self.payback_note_id
+    }
+
+    /// Returns the status of the order.
+    pub fn status(&self) -> SwapOrderStatus {
+        self.status
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // SWAP ORDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the SWAP notes created by the client's accounts, along with the status of their
+    /// payback notes.
+    ///
+    /// Only SWAP notes whose recipient is known to the client are returned, which is always the
+    /// case for notes created with
+    /// [TransactionRequest::swap](crate::transactions::TransactionRequest::swap).
+    pub async fn get_swap_orders(&self) -> Result<Vec<SwapOrder>, ClientError> {
+        let swap_notes: Vec<_> = self
+            .store
+            .get_output_notes(NoteFilter::All)
+            .await?
+            .into_iter()
+            .filter_map(|swap_note| {
+                let (offered_asset, requested_asset, payback_note_id) =
+                    parse_swap_note(&swap_note)?;
+                Some((swap_note, offered_asset, requested_asset, payback_note_id))
+            })
+            .collect();
+
+        if swap_notes.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // The payback notes of all orders are fetched at once
+        let payback_note_ids =
+            swap_notes.iter().map(|(_, _, _, payback_note_id)| *payback_note_id).collect();
+        let payback_notes: BTreeMap<NoteId, InputNoteRecord> = self
+            .store
+            .get_input_notes(NoteFilter::List(payback_note_ids))
+            .await?
+            .into_iter()
+            .map(|note| (note.id(), note))
+            .collect();
+
+        let swap_orders = swap_notes
+            .into_iter()
+            .map(|(swap_note, offered_asset, requested_asset, payback_note_id)| {
+                let status = swap_order_status(&swap_note, payback_notes.get(&payback_note_id));
+                SwapOrder {
+                    swap_note,
+                    offered_asset,
+                    requested_asset,
+                    payback_note_id,
+                    status,
+                }
+            })
+            .collect();
+
+        Ok(swap_orders)
+    }
+
+    /// Cancels the SWAP order for `note_id`, reclaiming the offered asset.
+    ///
+    /// SWAP notes created with
+    /// [TransactionRequest::swap](crate::transactions::TransactionRequest::swap) use the standard
+    /// SWAP note script, which has no recall path: consuming the note with the creator's
+    /// account fills the order like any other account would, sending the requested asset back
+    /// to the creator. Because of this, open orders for these notes can't be cancelled.
+    ///
+    /// # Errors
+    ///
+    /// - [ClientError::SwapOrderNotFound] if `note_id` isn't a SWAP note created by the client.
+    /// - [ClientError::SwapOrderNotOpen] if the SWAP note was already consumed.
+    /// - [ClientError::SwapOrderNotCancellable] if the SWAP note can't be reclaimed by its creator.
+    pub async fn cancel_swap(&mut self, note_id: NoteId) -> Result<TransactionResult, ClientError> {
+        let swap_order = self
+            .get_swap_orders()
+            .await?
+            .into_iter()
+            .find(|swap_order| swap_order.id() == note_id)
+            .ok_or(ClientError::SwapOrderNotFound(note_id))?;
+
+        if swap_order.status() != SwapOrderStatus::Open {
+            return Err(ClientError::SwapOrderNotOpen(note_id, swap_order.status()));
+        }
+
+        Err(ClientError::SwapOrderNotCancellable(note_id))
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the status of the SWAP order for `swap_note`, based on the local state of the SWAP
+/// note and its payback note.
+fn swap_order_status(
+    swap_note: &OutputNoteRecord,
+    payback_note: Option<&InputNoteRecord>,
+) -> SwapOrderStatus {
+    match payback_note {
+        Some(note) if note.is_consumed() || note.is_processing() => SwapOrderStatus::Consumed,
+        Some(note) if note.is_committed() => SwapOrderStatus::Filled,
+        _ if swap_note.is_consumed() => SwapOrderStatus::Filled,
+        _ => SwapOrderStatus::Open,
+    }
+}
+
+/// Returns the offered asset, the requested asset and the ID of the payback note of a SWAP note,
+/// or `None` if the record isn't a SWAP note with a known recipient.
+///
+/// The inputs of a SWAP note are the payback note's recipient digest, the requested asset and the
+/// payback note's tag.
+fn parse_swap_note(swap_note: &OutputNoteRecord) -> Option<(Asset, Asset, NoteId)> {
+    let recipient = swap_note.recipient()?;
+    if recipient.script().hash().to_string() != SWAP {
+        return None;
+    }
+
+    let inputs = recipient.inputs().values();
+    if inputs.len() != 9 {
+        return None;
+    }
+
+    let offered_asset = *swap_note.assets().iter().next()?;
+    let payback_recipient = Digest::from([inputs[0], inputs[1], inputs[2], inputs[3]]);
+    let requested_asset =
+        Asset::try_from(Word::from([inputs[4], inputs[5], inputs[6], inputs[7]])).ok()?;
+
+    let payback_assets = NoteAssets::new(vec![requested_asset]).ok()?;
+    let payback_note_id = NoteId::new(payback_recipient, payback_assets.commitment());
+
+    Some((offered_asset, requested_asset, payback_note_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use miden_lib::notes::create_swap_note;
+    use miden_objects::{
+        accounts::{AccountId, AccountType},
+        assets::FungibleAsset,
+        crypto::rand::RpoRandomCoin,
+        notes::NoteType,
+        ZERO,
+    };
+
+    use super::parse_swap_note;
+    use crate::store::OutputNoteRecord;
+
+    #[test]
+    fn swap_note_parsing() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let offered_faucet_id = AccountId::new_dummy([1u8; 32], AccountType::FungibleFaucet);
+        let requested_faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let offered_asset = FungibleAsset::new(offered_faucet_id, 100).unwrap().into();
+        let requested_asset = FungibleAsset::new(requested_faucet_id, 50).unwrap().into();
+        let mut rng = RpoRandomCoin::new(Default::default());
+
+        let (swap_note, payback_note_details) = create_swap_note(
+            sender_id,
+            offered_asset,
+            requested_asset,
+            NoteType::Private,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+
+        let record = OutputNoteRecord::from_full_note(swap_note, 0);
+        assert_eq!(
+            parse_swap_note(&record),
+            Some((offered_asset, requested_asset, payback_note_details.id()))
+        );
+
+        // SWAP notes whose recipient is unknown can't be parsed
+        let (partial_note, _) = create_swap_note(
+            sender_id,
+            offered_asset,
+            requested_asset,
+            NoteType::Private,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        let record = OutputNoteRecord::from_partial_note(partial_note.into(), 0);
+        assert_eq!(parse_swap_note(&record), None);
+    }
+}
@@ -1,7 +1,15 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt;
 
-use miden_objects::{accounts::AccountId, utils::DeserializationError, NoteError};
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteId, Nullifier},
+    utils::DeserializationError,
+    NoteError,
+};
 
 // RPC ERROR
 // ================================================================================================
@@ -12,8 +20,14 @@ pub enum RpcError {
     ConnectionError(String),
     DeserializationError(String),
     ExpectedDataMissing(String),
+    InvalidAccountProof(AccountId, u32),
+    InvalidBlockHeader(u32),
+    InvalidNoteInclusionProof(NoteId, u32),
+    InvalidNullifierUpdate(Nullifier, u32),
     InvalidResponse(String),
+    NotesByIdChunkError(Vec<NoteId>, String),
     RequestError(String, String),
+    TransactionRejected(String),
 }
 
 impl fmt::Display for RpcError {
@@ -35,12 +49,47 @@ impl fmt::Display for RpcError {
             RpcError::ExpectedDataMissing(err) => {
                 write!(f, "RPC API response missing an expected field: {err}")
             },
+            RpcError::InvalidAccountProof(account_id, block_num) => {
+                write!(
+                    f,
+                    "account proof for account {account_id} does not match the account root of block {block_num}"
+                )
+            },
+            RpcError::InvalidBlockHeader(block_num) => {
+                write!(
+                    f,
+                    "block header {block_num} does not match the locally authenticated chain MMR"
+                )
+            },
+            RpcError::InvalidNoteInclusionProof(note_id, block_num) => {
+                write!(
+                    f,
+                    "inclusion proof for note {note_id} does not match the note root of block {block_num}"
+                )
+            },
+            RpcError::InvalidNullifierUpdate(nullifier, block_num) => {
+                write!(
+                    f,
+                    "nullifier {} reported at block {block_num} does not match the sync request",
+                    nullifier.to_hex()
+                )
+            },
             RpcError::InvalidResponse(err) => {
                 write!(f, "RPC API response is invalidw: {err}")
             },
+            RpcError::NotesByIdChunkError(note_ids, err) => {
+                write!(
+                    f,
+                    "failed to fetch notes with IDs {}: {err}",
+                    note_ids.iter().map(|id| id.to_hex()).collect::<Vec<_>>().join(", ")
+                )
+            },
             RpcError::RequestError(endpoint, err) => {
                 write!(f, "RPC request failed for {endpoint}: {err}")
             },
+            RpcError::TransactionRejected(err) => {
+                write!(f, "the node rejected the transaction: {err}")
+            },
         }
     }
 }
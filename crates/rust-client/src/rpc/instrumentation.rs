@@ -0,0 +1,245 @@
+use alloc::{boxed::Box, collections::BTreeSet, sync::Arc, vec::Vec};
+use core::{future::Future, time::Duration};
+use std::time::Instant;
+
+use async_trait::async_trait;
+use miden_objects::{
+    accounts::AccountId,
+    crypto::merkle::MmrProof,
+    notes::{NoteId, NoteTag, Nullifier},
+    transaction::ProvenTransaction,
+    BlockHeader, Digest,
+};
+use tracing::{field, info_span, warn, Instrument};
+
+use super::{
+    AccountDetails, AccountProofs, NodeRpcClient, NodeRpcClientEndpoint, NoteDetails, NoteSyncInfo,
+    RpcError, StateSyncInfo,
+};
+
+// RPC METRICS
+// ================================================================================================
+
+/// Outcome of a single call to the node.
+#[derive(Debug)]
+pub enum RpcCallOutcome<'a> {
+    /// The call returned a response.
+    Success,
+    /// The call failed with the contained error.
+    Failure(&'a RpcError),
+}
+
+impl RpcCallOutcome<'_> {
+    /// Returns a short label for the outcome, suitable for use as a metric label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RpcCallOutcome::Success => "ok",
+            RpcCallOutcome::Failure(_) => "error",
+        }
+    }
+}
+
+/// Hook called by [InstrumentedRpcClient] after every call to the node.
+///
+/// Implementations can use it to export latency histograms and error counters to their own
+/// telemetry. The hook is called synchronously from the RPC call, so it should not block.
+pub trait RpcMetrics {
+    /// Records a call to `endpoint` that took `duration`.
+    ///
+    /// `request_size` is the number of items sent in the request (for example, the number of
+    /// note IDs for [NodeRpcClientEndpoint::GetNotesById]).
+    fn record(
+        &self,
+        endpoint: NodeRpcClientEndpoint,
+        request_size: usize,
+        duration: Duration,
+        outcome: RpcCallOutcome<'_>,
+    );
+}
+
+// INSTRUMENTED RPC CLIENT
+// ================================================================================================
+
+/// A [NodeRpcClient] that wraps another implementation and instruments every call it makes.
+///
+/// Each call runs inside a `rpc_request` tracing span that records the endpoint name, the request
+/// size, the call's duration in milliseconds and its outcome. If a [RpcMetrics] hook is set, it is
+/// also called once per request.
+pub struct InstrumentedRpcClient<C> {
+    inner: C,
+    metrics: Option<Arc<dyn RpcMetrics + Send + Sync>>,
+}
+
+impl<C: NodeRpcClient> InstrumentedRpcClient<C> {
+    /// Returns a new [InstrumentedRpcClient] that wraps `inner`.
+    pub fn new(inner: C) -> Self {
+        Self { inner, metrics: None }
+    }
+
+    /// Sets the hook called with the metrics of every request.
+    pub fn with_metrics(mut self, metrics: Arc<dyn RpcMetrics + Send + Sync>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Returns a reference to the wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns the wrapped client.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+#[async_trait(?Send)]
+impl<C: NodeRpcClient> NodeRpcClient for InstrumentedRpcClient<C> {
+    async fn submit_proven_transaction(
+        &mut self,
+        proven_transaction: ProvenTransaction,
+    ) -> Result<(), RpcError> {
+        let request = self.inner.submit_proven_transaction(proven_transaction);
+        record_call(self.metrics.as_deref(), NodeRpcClientEndpoint::SubmitProvenTx, 1, request)
+            .await
+    }
+
+    async fn get_block_header_by_number(
+        &mut self,
+        block_num: Option<u32>,
+        include_mmr_proof: bool,
+    ) -> Result<(BlockHeader, Option<MmrProof>), RpcError> {
+        let request = self.inner.get_block_header_by_number(block_num, include_mmr_proof);
+        record_call(
+            self.metrics.as_deref(),
+            NodeRpcClientEndpoint::GetBlockHeaderByNumber,
+            1,
+            request,
+        )
+        .await
+    }
+
+    async fn get_notes_by_id(&mut self, note_ids: &[NoteId]) -> Result<Vec<NoteDetails>, RpcError> {
+        let request = self.inner.get_notes_by_id(note_ids);
+        record_call(
+            self.metrics.as_deref(),
+            NodeRpcClientEndpoint::GetNotesById,
+            note_ids.len(),
+            request,
+        )
+        .await
+    }
+
+    async fn sync_state(
+        &mut self,
+        block_num: u32,
+        account_ids: &[AccountId],
+        note_tags: &[NoteTag],
+        nullifiers_tags: &[u16],
+    ) -> Result<StateSyncInfo, RpcError> {
+        let request_size = account_ids.len() + note_tags.len() + nullifiers_tags.len();
+        let request = self.inner.sync_state(block_num, account_ids, note_tags, nullifiers_tags);
+        record_call(
+            self.metrics.as_deref(),
+            NodeRpcClientEndpoint::SyncState,
+            request_size,
+            request,
+        )
+        .await
+    }
+
+    async fn get_account_update(
+        &mut self,
+        account_id: AccountId,
+    ) -> Result<AccountDetails, RpcError> {
+        let request = self.inner.get_account_update(account_id);
+        record_call(self.metrics.as_deref(), NodeRpcClientEndpoint::GetAccountDetails, 1, request)
+            .await
+    }
+
+    async fn sync_notes(
+        &mut self,
+        block_num: u32,
+        note_tags: &[NoteTag],
+    ) -> Result<NoteSyncInfo, RpcError> {
+        let request = self.inner.sync_notes(block_num, note_tags);
+        record_call(
+            self.metrics.as_deref(),
+            NodeRpcClientEndpoint::SyncNotes,
+            note_tags.len(),
+            request,
+        )
+        .await
+    }
+
+    async fn check_nullifiers_by_prefix(
+        &mut self,
+        prefix: &[u16],
+    ) -> Result<Vec<(Nullifier, u32)>, RpcError> {
+        let request = self.inner.check_nullifiers_by_prefix(prefix);
+        record_call(
+            self.metrics.as_deref(),
+            NodeRpcClientEndpoint::CheckNullifiersByPrefix,
+            prefix.len(),
+            request,
+        )
+        .await
+    }
+
+    async fn get_account_proofs(
+        &mut self,
+        account_ids: &BTreeSet<AccountId>,
+        code_commitments: &[Digest],
+        include_headers: bool,
+    ) -> Result<AccountProofs, RpcError> {
+        let request = self.inner.get_account_proofs(account_ids, code_commitments, include_headers);
+        record_call(
+            self.metrics.as_deref(),
+            NodeRpcClientEndpoint::GetAccountProofs,
+            account_ids.len(),
+            request,
+        )
+        .await
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Awaits `request` inside a `rpc_request` span and reports its duration and outcome to the span
+/// and to the `metrics` hook.
+async fn record_call<T>(
+    metrics: Option<&(dyn RpcMetrics + Send + Sync)>,
+    endpoint: NodeRpcClientEndpoint,
+    request_size: usize,
+    request: impl Future<Output = Result<T, RpcError>>,
+) -> Result<T, RpcError> {
+    let span = info_span!(
+        "rpc_request",
+        endpoint = %endpoint,
+        request_size,
+        duration_ms = field::Empty,
+        outcome = field::Empty,
+    );
+
+    let start = Instant::now();
+    let result = request.instrument(span.clone()).await;
+    let duration = start.elapsed();
+
+    let outcome = match &result {
+        Ok(_) => RpcCallOutcome::Success,
+        Err(err) => {
+            warn!(parent: &span, %endpoint, error = %err, "RPC request failed");
+            RpcCallOutcome::Failure(err)
+        },
+    };
+
+    span.record("duration_ms", duration.as_millis() as u64);
+    span.record("outcome", outcome.as_str());
+
+    if let Some(metrics) = metrics {
+        metrics.record(endpoint, request_size, duration, outcome);
+    }
+
+    result
+}
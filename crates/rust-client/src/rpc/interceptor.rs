@@ -0,0 +1,54 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use tonic::{
+    metadata::{AsciiMetadataKey, AsciiMetadataValue},
+    service::Interceptor,
+    Request, Status,
+};
+
+use super::RpcError;
+
+// METADATA INTERCEPTOR
+// ================================================================================================
+
+/// Interceptor that attaches a fixed set of metadata headers (such as the `authorization` header)
+/// to every request sent to the node.
+#[derive(Clone, Debug, Default)]
+pub struct MetadataInterceptor {
+    metadata: Vec<(AsciiMetadataKey, AsciiMetadataValue)>,
+}
+
+impl MetadataInterceptor {
+    /// Returns a new [MetadataInterceptor] that attaches the provided headers to every request.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [RpcError::ConnectionError] if any of the header names or values is not a valid
+    /// ASCII metadata entry.
+    pub fn new(headers: &BTreeMap<String, String>) -> Result<Self, RpcError> {
+        let mut metadata = Vec::with_capacity(headers.len());
+        for (key, value) in headers {
+            let metadata_key = AsciiMetadataKey::from_bytes(key.to_lowercase().as_bytes())
+                .map_err(|err| {
+                    RpcError::ConnectionError(format!("invalid metadata header `{key}`: {err}"))
+                })?;
+            let metadata_value = AsciiMetadataValue::try_from(value.as_str()).map_err(|err| {
+                RpcError::ConnectionError(format!(
+                    "invalid value for metadata header `{key}`: {err}"
+                ))
+            })?;
+            metadata.push((metadata_key, metadata_value));
+        }
+
+        Ok(Self { metadata })
+    }
+}
+
+impl Interceptor for MetadataInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        for (key, value) in self.metadata.iter() {
+            request.metadata_mut().insert(key.clone(), value.clone());
+        }
+        Ok(request)
+    }
+}
@@ -3,6 +3,8 @@
 //! transactions.
 
 use alloc::{boxed::Box, collections::BTreeSet, vec::Vec};
+#[cfg(any(feature = "tonic", feature = "web-tonic"))]
+use alloc::{collections::BTreeMap, string::ToString};
 use core::fmt;
 
 use async_trait::async_trait;
@@ -18,12 +20,22 @@ use miden_objects::{
     BlockHeader, Digest,
 };
 
+pub(crate) mod verification;
+
 #[cfg(all(feature = "tonic", feature = "web-tonic"))]
 compile_error!("features `tonic` and `web-tonic` are mutually exclusive");
 
 #[cfg(any(feature = "tonic", feature = "web-tonic"))]
 mod domain;
 
+#[cfg(any(feature = "tonic", feature = "web-tonic"))]
+mod interceptor;
+
+#[cfg(feature = "std")]
+mod instrumentation;
+#[cfg(feature = "std")]
+pub use instrumentation::{InstrumentedRpcClient, RpcCallOutcome, RpcMetrics};
+
 #[cfg(feature = "tonic")]
 mod tonic_client;
 #[cfg(test)]
@@ -269,6 +281,9 @@ pub trait NodeRpcClient {
     ///
     /// For any NoteType::Private note, the return data is only the [NoteMetadata], whereas
     /// for NoteType::Onchain notes, the return data includes all details.
+    ///
+    /// Implementations are expected to split large lists of IDs into several requests and to
+    /// return the notes in the order in which they were requested.
     async fn get_notes_by_id(&mut self, note_ids: &[NoteId]) -> Result<Vec<NoteDetails>, RpcError>;
 
     /// Fetches info from the node necessary to perform a state sync using the
@@ -336,6 +351,76 @@ pub trait NodeRpcClient {
     }
 }
 
+// CHUNKED REQUESTS
+// ================================================================================================
+
+/// Fetches note details for `note_ids` by calling `fetch_chunk` with chunks of at most
+/// `chunk_size` IDs, keeping up to `concurrency` requests in flight at the same time.
+///
+/// Duplicated IDs are only requested once and the returned notes follow the order of `note_ids`,
+/// regardless of the order in which chunks complete or the node returns notes. Notes that were not
+/// requested are discarded.
+///
+/// # Errors
+///
+/// If any chunk fails, a [RpcError::NotesByIdChunkError] is returned with the IDs of every note
+/// in the failed chunks alongside their errors.
+#[cfg(any(feature = "tonic", feature = "web-tonic"))]
+pub(crate) async fn fetch_notes_in_chunks<F, Fut>(
+    note_ids: &[NoteId],
+    chunk_size: usize,
+    concurrency: usize,
+    fetch_chunk: F,
+) -> Result<Vec<NoteDetails>, RpcError>
+where
+    F: Fn(Vec<NoteId>) -> Fut,
+    Fut: core::future::Future<Output = Result<Vec<NoteDetails>, RpcError>>,
+{
+    use futures::{stream, StreamExt};
+
+    let mut positions = BTreeMap::new();
+    let mut unique_note_ids = Vec::with_capacity(note_ids.len());
+    for note_id in note_ids {
+        if !positions.contains_key(note_id) {
+            positions.insert(*note_id, unique_note_ids.len());
+            unique_note_ids.push(*note_id);
+        }
+    }
+
+    let chunk_results: Vec<(Vec<NoteId>, Result<Vec<NoteDetails>, RpcError>)> =
+        stream::iter(unique_note_ids.chunks(chunk_size.max(1)).map(<[NoteId]>::to_vec))
+            .map(|chunk| {
+                let request = fetch_chunk(chunk.clone());
+                async move { (chunk, request.await) }
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+    let mut notes = Vec::with_capacity(unique_note_ids.len());
+    let mut failed_note_ids = Vec::new();
+    let mut errors = Vec::new();
+    for (chunk, result) in chunk_results {
+        match result {
+            Ok(chunk_notes) => notes.extend(chunk_notes),
+            Err(err) => {
+                failed_note_ids.extend(chunk);
+                errors.push(err.to_string());
+            },
+        }
+    }
+
+    if !failed_note_ids.is_empty() {
+        return Err(RpcError::NotesByIdChunkError(failed_note_ids, errors.join("; ")));
+    }
+
+    notes.retain(|note| positions.contains_key(&note.id()));
+    notes.sort_by_key(|note| positions[&note.id()]);
+    notes.dedup_by_key(|note| note.id());
+
+    Ok(notes)
+}
+
 // SYNC NOTE
 // ================================================================================================
 
@@ -451,12 +536,13 @@ impl CommittedNote {
 // ================================================================================================
 //
 /// RPC methods for the Miden protocol.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NodeRpcClientEndpoint {
     CheckNullifiersByPrefix,
     GetAccountDetails,
     GetAccountProofs,
     GetBlockHeaderByNumber,
+    GetNotesById,
     SyncState,
     SubmitProvenTx,
     SyncNotes,
@@ -473,6 +559,7 @@ impl fmt::Display for NodeRpcClientEndpoint {
             NodeRpcClientEndpoint::GetBlockHeaderByNumber => {
                 write!(f, "get_block_header_by_number")
             },
+            NodeRpcClientEndpoint::GetNotesById => write!(f, "get_notes_by_id"),
             NodeRpcClientEndpoint::SyncState => write!(f, "sync_state"),
             NodeRpcClientEndpoint::SubmitProvenTx => write!(f, "submit_proven_transaction"),
             NodeRpcClientEndpoint::SyncNotes => write!(f, "sync_notes"),
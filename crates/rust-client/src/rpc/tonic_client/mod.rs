@@ -1,6 +1,6 @@
 use alloc::{
     boxed::Box,
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
@@ -25,15 +25,22 @@ use miden_objects::{
     BlockHeader, Digest,
 };
 use miden_tx::utils::Serializable;
-use tonic::transport::Channel;
+use tonic::{
+    service::interceptor::InterceptedService,
+    transport::{Certificate, Channel, ClientTlsConfig, Identity},
+};
 use tracing::info;
 
 use super::{
-    AccountDetails, AccountProof, AccountProofs, AccountUpdateSummary, CommittedNote,
-    NodeRpcClient, NodeRpcClientEndpoint, NoteDetails, NoteInclusionDetails, NoteSyncInfo,
-    NullifierUpdate, StateSyncInfo, TransactionUpdate,
+    fetch_notes_in_chunks, interceptor::MetadataInterceptor, AccountDetails, AccountProof,
+    AccountProofs, AccountUpdateSummary, CommittedNote, NodeRpcClient, NodeRpcClientEndpoint,
+    NoteDetails, NoteInclusionDetails, NoteSyncInfo, NullifierUpdate, StateSyncInfo,
+    TransactionUpdate,
+};
+use crate::{
+    config::{RpcConfig, RpcTlsConfig},
+    rpc::RpcError,
 };
-use crate::{config::RpcConfig, rpc::RpcError};
 #[rustfmt::skip]
 pub mod generated;
 
@@ -44,9 +51,13 @@ pub mod generated;
 ///
 /// Wraps the ApiClient which defers establishing a connection with a node until necessary
 pub struct TonicRpcClient {
-    rpc_api: Option<ApiClient<Channel>>,
+    rpc_api: Option<ApiClient<InterceptedService<Channel, MetadataInterceptor>>>,
     endpoint: String,
     timeout_ms: u64,
+    headers: BTreeMap<String, String>,
+    tls: Option<RpcTlsConfig>,
+    notes_chunk_size: usize,
+    max_concurrent_requests: usize,
 }
 
 impl TonicRpcClient {
@@ -56,21 +67,35 @@ impl TonicRpcClient {
             rpc_api: None,
             endpoint: config.endpoint.to_string(),
             timeout_ms: config.timeout_ms,
+            headers: config.request_headers(),
+            tls: config.tls.clone(),
+            notes_chunk_size: config.notes_chunk_size,
+            max_concurrent_requests: config.max_concurrent_requests,
         }
     }
 
     /// Takes care of establishing the RPC connection if not connected yet and returns a reference
     /// to the inner ApiClient
-    async fn rpc_api(&mut self) -> Result<&mut ApiClient<Channel>, RpcError> {
+    async fn rpc_api(
+        &mut self,
+    ) -> Result<&mut ApiClient<InterceptedService<Channel, MetadataInterceptor>>, RpcError> {
         if self.rpc_api.is_some() {
             Ok(self.rpc_api.as_mut().unwrap())
         } else {
-            let endpoint = tonic::transport::Endpoint::try_from(self.endpoint.clone())
+            let mut endpoint = tonic::transport::Endpoint::try_from(self.endpoint.clone())
                 .map_err(|err| RpcError::ConnectionError(err.to_string()))?
                 .timeout(Duration::from_millis(self.timeout_ms));
-            let rpc_api = ApiClient::connect(endpoint)
+            if let Some(tls) = &self.tls {
+                endpoint = endpoint
+                    .tls_config(build_tls_config(tls)?)
+                    .map_err(|err| RpcError::ConnectionError(err.to_string()))?;
+            }
+            let interceptor = MetadataInterceptor::new(&self.headers)?;
+            let channel = endpoint
+                .connect()
                 .await
                 .map_err(|err| RpcError::ConnectionError(err.to_string()))?;
+            let rpc_api = ApiClient::with_interceptor(channel, interceptor);
             Ok(self.rpc_api.insert(rpc_api))
         }
     }
@@ -86,12 +111,24 @@ impl NodeRpcClient for TonicRpcClient {
             transaction: proven_transaction.to_bytes(),
         };
         let rpc_api = self.rpc_api().await?;
-        rpc_api.submit_proven_transaction(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::SubmitProvenTx.to_string(),
-                err.to_string(),
-            )
-        })?;
+        rpc_api
+            .submit_proven_transaction(request)
+            .await
+            .map_err(|err| match err.code() {
+                // The node could not be reached or didn't answer in time, so the transaction can
+                // be submitted again later
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => {
+                    RpcError::ConnectionError(err.to_string())
+                },
+                // The node checked the transaction and found it invalid
+                tonic::Code::InvalidArgument => {
+                    RpcError::TransactionRejected(err.message().to_string())
+                },
+                _ => RpcError::RequestError(
+                    NodeRpcClientEndpoint::SubmitProvenTx.to_string(),
+                    err.to_string(),
+                ),
+            })?;
 
         Ok(())
     }
@@ -145,54 +182,15 @@ impl NodeRpcClient for TonicRpcClient {
     }
 
     async fn get_notes_by_id(&mut self, note_ids: &[NoteId]) -> Result<Vec<NoteDetails>, RpcError> {
-        let request = GetNotesByIdRequest {
-            note_ids: note_ids.iter().map(|id| id.inner().into()).collect(),
-        };
-        let rpc_api = self.rpc_api().await?;
-        let api_response = rpc_api.get_notes_by_id(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::GetBlockHeaderByNumber.to_string(),
-                err.to_string(),
-            )
-        })?;
-
-        let rpc_notes = api_response.into_inner().notes;
-        let mut response_notes = Vec::with_capacity(rpc_notes.len());
-        for note in rpc_notes {
-            let inclusion_details = {
-                let merkle_path = note
-                    .merkle_path
-                    .ok_or(RpcError::ExpectedDataMissing("Notes.MerklePath".into()))?
-                    .try_into()?;
-
-                NoteInclusionDetails::new(note.block_num, note.note_index as u16, merkle_path)
-            };
-
-            let note = match note.details {
-                // On-chain notes include details
-                Some(details) => {
-                    let note = Note::read_from_bytes(&details)?;
-
-                    NoteDetails::Public(note, inclusion_details)
-                },
-                // Off-chain notes do not have details
-                None => {
-                    let note_metadata = note
-                        .metadata
-                        .ok_or(RpcError::ExpectedDataMissing("Metadata".into()))?
-                        .try_into()?;
-
-                    let note_id: Digest = note
-                        .note_id
-                        .ok_or(RpcError::ExpectedDataMissing("Notes.NoteId".into()))?
-                        .try_into()?;
-
-                    NoteDetails::Private(NoteId::from(note_id), note_metadata, inclusion_details)
-                },
-            };
-            response_notes.push(note)
-        }
-        Ok(response_notes)
+        let rpc_api = self.rpc_api().await?.clone();
+
+        fetch_notes_in_chunks(
+            note_ids,
+            self.notes_chunk_size,
+            self.max_concurrent_requests,
+            |chunk| get_notes_chunk(rpc_api.clone(), chunk),
+        )
+        .await
     }
 
     /// Sends a sync state request to the Miden node, validates and converts the response
@@ -416,6 +414,61 @@ impl NodeRpcClient for TonicRpcClient {
     }
 }
 
+// NOTES BY ID
+// ================================================================================================
+
+/// Sends a single `GetNotesById` request for `note_ids` and converts the response into a list of
+/// [NoteDetails].
+async fn get_notes_chunk(
+    mut rpc_api: ApiClient<InterceptedService<Channel, MetadataInterceptor>>,
+    note_ids: Vec<NoteId>,
+) -> Result<Vec<NoteDetails>, RpcError> {
+    let request = GetNotesByIdRequest {
+        note_ids: note_ids.iter().map(|id| id.inner().into()).collect(),
+    };
+    let api_response = rpc_api.get_notes_by_id(request).await.map_err(|err| {
+        RpcError::RequestError(NodeRpcClientEndpoint::GetNotesById.to_string(), err.to_string())
+    })?;
+
+    let rpc_notes = api_response.into_inner().notes;
+    let mut response_notes = Vec::with_capacity(rpc_notes.len());
+    for note in rpc_notes {
+        let inclusion_details = {
+            let merkle_path = note
+                .merkle_path
+                .ok_or(RpcError::ExpectedDataMissing("Notes.MerklePath".into()))?
+                .try_into()?;
+
+            NoteInclusionDetails::new(note.block_num, note.note_index as u16, merkle_path)
+        };
+
+        let note = match note.details {
+            // On-chain notes include details
+            Some(details) => {
+                let note = Note::read_from_bytes(&details)?;
+
+                NoteDetails::Public(note, inclusion_details)
+            },
+            // Off-chain notes do not have details
+            None => {
+                let note_metadata = note
+                    .metadata
+                    .ok_or(RpcError::ExpectedDataMissing("Metadata".into()))?
+                    .try_into()?;
+
+                let note_id: Digest = note
+                    .note_id
+                    .ok_or(RpcError::ExpectedDataMissing("Notes.NoteId".into()))?
+                    .try_into()?;
+
+                NoteDetails::Private(NoteId::from(note_id), note_metadata, inclusion_details)
+            },
+        };
+        response_notes.push(note)
+    }
+    Ok(response_notes)
+}
+
 // NOTE SYNC INFO CONVERSION
 // ================================================================================================
 
@@ -584,3 +637,43 @@ impl TryFrom<SyncStateResponse> for StateSyncInfo {
         })
     }
 }
+
+// TLS CONFIGURATION
+// ================================================================================================
+
+/// Builds the tonic [ClientTlsConfig] from the provided [RpcTlsConfig], reading the PEM encoded
+/// certificates and keys from disk. The platform's native roots are always trusted, alongside the
+/// custom root CA if one was provided.
+fn build_tls_config(tls: &RpcTlsConfig) -> Result<ClientTlsConfig, RpcError> {
+    let read_pem = |path: &str| {
+        std::fs::read(path).map_err(|err| {
+            RpcError::ConnectionError(format!("failed to read TLS file `{path}`: {err}"))
+        })
+    };
+
+    let mut tls_config = ClientTlsConfig::new().with_native_roots();
+
+    if let Some(domain_name) = &tls.domain_name {
+        tls_config = tls_config.domain_name(domain_name.clone());
+    }
+
+    if let Some(ca_cert_path) = &tls.ca_cert_path {
+        tls_config = tls_config.ca_certificate(Certificate::from_pem(read_pem(ca_cert_path)?));
+    }
+
+    match (&tls.client_cert_path, &tls.client_key_path) {
+        (Some(cert_path), Some(key_path)) => {
+            let identity = Identity::from_pem(read_pem(cert_path)?, read_pem(key_path)?);
+            tls_config = tls_config.identity(identity);
+        },
+        (None, None) => {},
+        _ => {
+            return Err(RpcError::ConnectionError(
+                "both `client_cert_path` and `client_key_path` must be set for mutual TLS"
+                    .to_string(),
+            ))
+        },
+    }
+
+    Ok(tls_config)
+}
@@ -0,0 +1,127 @@
+//! Provides functions to verify data received from the node against chain data the client has
+//! already authenticated.
+//!
+//! The genesis block header is the client's root of trust. Every other block header is
+//! authenticated against the client's chain MMR, either through the MMR delta received on sync or
+//! through an MMR proof. Once a header is authenticated, its roots are used to verify the account
+//! proofs, note inclusion paths and nullifiers the node returns for that block.
+
+use miden_objects::{
+    crypto::merkle::{MerklePath, MmrPeaks},
+    notes::{NoteHeader, NoteId, NoteInclusionProof, NoteMetadata},
+    BlockHeader,
+};
+
+use super::{AccountProof, NullifierUpdate, RpcError};
+use crate::sync::get_nullifier_prefix;
+
+// BLOCK HEADERS
+// ================================================================================================
+
+/// Verifies that the `block_header` commits to the chain described by `mmr_peaks`.
+///
+/// `mmr_peaks` must be the peaks of the client's chain MMR containing every block prior to
+/// `block_header` (that is, with a forest equal to the header's block number).
+pub(crate) fn verify_block_header(
+    block_header: &BlockHeader,
+    mmr_peaks: &MmrPeaks,
+) -> Result<(), RpcError> {
+    if mmr_peaks.num_leaves() != block_header.block_num() as usize
+        || mmr_peaks.hash_peaks() != block_header.chain_root()
+    {
+        return Err(RpcError::InvalidBlockHeader(block_header.block_num()));
+    }
+
+    Ok(())
+}
+
+// ACCOUNT PROOFS
+// ================================================================================================
+
+/// Verifies that the account hash of the `account_proof` is included in the account tree of the
+/// authenticated `block_header`.
+pub(crate) fn verify_account_proof(
+    account_proof: &AccountProof,
+    block_header: &BlockHeader,
+) -> Result<(), RpcError> {
+    let account_id = account_proof.account_id();
+    let computed_root = account_proof
+        .merkle_proof()
+        .compute_root(u64::from(account_id), account_proof.account_hash());
+
+    match computed_root {
+        Ok(root) if root == block_header.account_root() => Ok(()),
+        _ => Err(RpcError::InvalidAccountProof(account_id, block_header.block_num())),
+    }
+}
+
+// NOTE INCLUSION PROOFS
+// ================================================================================================
+
+/// Verifies that the note identified by `note_id` and `metadata` is included at `note_index` in
+/// the note tree of the authenticated `block_header`.
+pub(crate) fn verify_note_inclusion(
+    note_id: NoteId,
+    metadata: &NoteMetadata,
+    note_index: u16,
+    merkle_path: &MerklePath,
+    block_header: &BlockHeader,
+) -> Result<(), RpcError> {
+    let note_hash = NoteHeader::new(note_id, *metadata).hash();
+    let computed_root = merkle_path.compute_root(note_index.into(), note_hash);
+
+    match computed_root {
+        Ok(root) if root == block_header.note_root() => Ok(()),
+        _ => Err(RpcError::InvalidNoteInclusionProof(note_id, block_header.block_num())),
+    }
+}
+
+/// Verifies a [NoteInclusionProof] against the authenticated `block_header` of the block it
+/// references.
+pub(crate) fn verify_note_inclusion_proof(
+    note_id: NoteId,
+    metadata: &NoteMetadata,
+    inclusion_proof: &NoteInclusionProof,
+    block_header: &BlockHeader,
+) -> Result<(), RpcError> {
+    if inclusion_proof.location().block_num() != block_header.block_num() {
+        return Err(RpcError::InvalidNoteInclusionProof(note_id, block_header.block_num()));
+    }
+
+    verify_note_inclusion(
+        note_id,
+        metadata,
+        inclusion_proof.location().node_index_in_block(),
+        inclusion_proof.note_path(),
+        block_header,
+    )
+}
+
+// NULLIFIERS
+// ================================================================================================
+
+/// Verifies that the nullifier updates received on a state sync are consistent with the request
+/// and the authenticated `block_header` of the response.
+///
+/// Every nullifier must match one of the `requested_prefixes` and must have been created after
+/// `request_block_num` and no later than the response's block. The node does not include proofs
+/// against the header's nullifier root, so this is the extent of what can be checked locally.
+pub(crate) fn verify_nullifier_updates(
+    nullifiers: &[NullifierUpdate],
+    requested_prefixes: &[u16],
+    request_block_num: u32,
+    block_header: &BlockHeader,
+) -> Result<(), RpcError> {
+    for nullifier_update in nullifiers {
+        let block_num = nullifier_update.block_num;
+        let in_range = block_num > request_block_num && block_num <= block_header.block_num();
+        let requested =
+            requested_prefixes.contains(&get_nullifier_prefix(&nullifier_update.nullifier));
+
+        if !in_range || !requested {
+            return Err(RpcError::InvalidNullifierUpdate(nullifier_update.nullifier, block_num));
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use alloc::{
     boxed::Box,
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
@@ -24,12 +24,20 @@ use miden_objects::{
     BlockHeader, Digest,
 };
 use miden_tx::utils::Serializable;
+use tonic::service::interceptor::InterceptedService;
 use tonic_web_wasm_client::Client;
 
-use super::{AccountProof, AccountProofs, NoteSyncInfo};
-use crate::rpc::{
-    AccountDetails, AccountUpdateSummary, CommittedNote, NodeRpcClient, NodeRpcClientEndpoint,
-    NoteDetails, NoteInclusionDetails, NullifierUpdate, RpcError, StateSyncInfo, TransactionUpdate,
+use super::{
+    fetch_notes_in_chunks, interceptor::MetadataInterceptor, AccountProof, AccountProofs,
+    NoteSyncInfo,
+};
+use crate::{
+    config::{DEFAULT_MAX_CONCURRENT_REQUESTS, DEFAULT_NOTES_CHUNK_SIZE},
+    rpc::{
+        AccountDetails, AccountUpdateSummary, CommittedNote, NodeRpcClient, NodeRpcClientEndpoint,
+        NoteDetails, NoteInclusionDetails, NullifierUpdate, RpcError, StateSyncInfo,
+        TransactionUpdate,
+    },
 };
 
 #[rustfmt::skip]
@@ -37,16 +45,60 @@ pub mod generated;
 
 pub struct WebTonicRpcClient {
     endpoint: String,
+    interceptor: MetadataInterceptor,
+    notes_chunk_size: usize,
+    max_concurrent_requests: usize,
 }
 
 impl WebTonicRpcClient {
     pub fn new(endpoint: &str) -> Self {
-        Self { endpoint: endpoint.to_string() }
+        Self {
+            endpoint: endpoint.to_string(),
+            interceptor: MetadataInterceptor::default(),
+            notes_chunk_size: DEFAULT_NOTES_CHUNK_SIZE,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        }
+    }
+
+    /// Returns a new instance of [WebTonicRpcClient] that attaches the provided metadata headers
+    /// (for example, an `authorization` header) to every request.
+    ///
+    /// TLS settings are not configurable in the browser, where they are handled by the user
+    /// agent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [RpcError::ConnectionError] if any of the headers is not a valid ASCII metadata
+    /// entry.
+    pub fn new_with_headers(
+        endpoint: &str,
+        headers: &BTreeMap<String, String>,
+    ) -> Result<Self, RpcError> {
+        Ok(Self {
+            endpoint: endpoint.to_string(),
+            interceptor: MetadataInterceptor::new(headers)?,
+            notes_chunk_size: DEFAULT_NOTES_CHUNK_SIZE,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        })
     }
 
-    pub fn build_api_client(&self) -> ApiClient<Client> {
+    /// Sets the maximum number of note IDs sent in a single `GetNotesById` request and the
+    /// maximum number of those requests that can be in flight at the same time.
+    pub fn with_notes_chunking(
+        mut self,
+        chunk_size: usize,
+        max_concurrent_requests: usize,
+    ) -> Self {
+        self.notes_chunk_size = chunk_size;
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+
+    pub(crate) fn build_api_client(
+        &self,
+    ) -> ApiClient<InterceptedService<Client, MetadataInterceptor>> {
         let wasm_client = Client::new(self.endpoint.clone());
-        ApiClient::new(wasm_client)
+        ApiClient::with_interceptor(wasm_client, self.interceptor.clone())
     }
 }
 
@@ -62,12 +114,24 @@ impl NodeRpcClient for WebTonicRpcClient {
             transaction: proven_transaction.to_bytes(),
         };
 
-        query_client.submit_proven_transaction(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::SubmitProvenTx.to_string(),
-                err.to_string(),
-            )
-        })?;
+        query_client
+            .submit_proven_transaction(request)
+            .await
+            .map_err(|err| match err.code() {
+                // The node could not be reached or didn't answer in time, so the transaction can
+                // be submitted again later
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => {
+                    RpcError::ConnectionError(err.to_string())
+                },
+                // The node checked the transaction and found it invalid
+                tonic::Code::InvalidArgument => {
+                    RpcError::TransactionRejected(err.message().to_string())
+                },
+                _ => RpcError::RequestError(
+                    NodeRpcClientEndpoint::SubmitProvenTx.to_string(),
+                    err.to_string(),
+                ),
+            })?;
 
         Ok(())
     }
@@ -123,55 +187,13 @@ impl NodeRpcClient for WebTonicRpcClient {
     }
 
     async fn get_notes_by_id(&mut self, note_ids: &[NoteId]) -> Result<Vec<NoteDetails>, RpcError> {
-        let mut query_client = self.build_api_client();
-
-        let request = GetNotesByIdRequest {
-            note_ids: note_ids.iter().map(|id| id.inner().into()).collect(),
-        };
-
-        let api_response = query_client.get_notes_by_id(request).await.map_err(|err| {
-            RpcError::RequestError(
-                NodeRpcClientEndpoint::GetBlockHeaderByNumber.to_string(),
-                err.to_string(),
-            )
-        })?;
-
-        let rpc_notes = api_response.into_inner().notes;
-        let mut response_notes = Vec::with_capacity(rpc_notes.len());
-        for note in rpc_notes {
-            let inclusion_details = {
-                let merkle_path = note
-                    .merkle_path
-                    .ok_or(RpcError::ExpectedDataMissing("Notes.MerklePath".into()))?
-                    .try_into()?;
-
-                NoteInclusionDetails::new(note.block_num, note.note_index as u16, merkle_path)
-            };
-
-            let note = match note.details {
-                // On-chain notes include details
-                Some(details) => {
-                    let note = Note::read_from_bytes(&details)?;
-
-                    NoteDetails::Public(note, inclusion_details)
-                },
-                // Off-chain notes do not have details
-                None => {
-                    let note_metadata = note
-                        .metadata
-                        .ok_or(RpcError::ExpectedDataMissing("Metadata".into()))?
-                        .try_into()?;
-                    let note_id: miden_objects::Digest = note
-                        .note_id
-                        .ok_or(RpcError::ExpectedDataMissing("Notes.NoteId".into()))?
-                        .try_into()?;
-
-                    NoteDetails::Private(NoteId::from(note_id), note_metadata, inclusion_details)
-                },
-            };
-            response_notes.push(note)
-        }
-        Ok(response_notes)
+        fetch_notes_in_chunks(
+            note_ids,
+            self.notes_chunk_size,
+            self.max_concurrent_requests,
+            |chunk| get_notes_chunk(self.build_api_client(), chunk),
+        )
+        .await
     }
 
     /// Sends a sync state request to the Miden node, validates and converts the response
@@ -400,6 +422,60 @@ impl NodeRpcClient for WebTonicRpcClient {
     }
 }
 
+// NOTES BY ID
+// ================================================================================================
+
+/// Sends a single `GetNotesById` request for `note_ids` and converts the response into a list of
+/// [NoteDetails].
+async fn get_notes_chunk(
+    mut rpc_api: ApiClient<InterceptedService<Client, MetadataInterceptor>>,
+    note_ids: Vec<NoteId>,
+) -> Result<Vec<NoteDetails>, RpcError> {
+    let request = GetNotesByIdRequest {
+        note_ids: note_ids.iter().map(|id| id.inner().into()).collect(),
+    };
+    let api_response = rpc_api.get_notes_by_id(request).await.map_err(|err| {
+        RpcError::RequestError(NodeRpcClientEndpoint::GetNotesById.to_string(), err.to_string())
+    })?;
+
+    let rpc_notes = api_response.into_inner().notes;
+    let mut response_notes = Vec::with_capacity(rpc_notes.len());
+    for note in rpc_notes {
+        let inclusion_details = {
+            let merkle_path = note
+                .merkle_path
+                .ok_or(RpcError::ExpectedDataMissing("Notes.MerklePath".into()))?
+                .try_into()?;
+
+            NoteInclusionDetails::new(note.block_num, note.note_index as u16, merkle_path)
+        };
+
+        let note = match note.details {
+            // On-chain notes include details
+            Some(details) => {
+                let note = Note::read_from_bytes(&details)?;
+
+                NoteDetails::Public(note, inclusion_details)
+            },
+            // Off-chain notes do not have details
+            None => {
+                let note_metadata = note
+                    .metadata
+                    .ok_or(RpcError::ExpectedDataMissing("Metadata".into()))?
+                    .try_into()?;
+                let note_id: miden_objects::Digest = note
+                    .note_id
+                    .ok_or(RpcError::ExpectedDataMissing("Notes.NoteId".into()))?
+                    .try_into()?;
+
+                NoteDetails::Private(NoteId::from(note_id), note_metadata, inclusion_details)
+            },
+        };
+        response_notes.push(note)
+    }
+    Ok(response_notes)
+}
+
 // NOTE SYNC INFO CONVERSION
 // ================================================================================================
 
@@ -0,0 +1,324 @@
+//! Provides [SharedClient], a handle to a [Client] that can be cloned and used concurrently from
+//! multiple threads and tasks.
+//!
+//! The [Store] and [NodeRpcClient](crate::rpc::NodeRpcClient) traits return futures that are not
+//! `Send`, so a [Client] can't be moved between the worker threads of a multi-threaded runtime. A
+//! [SharedClient] instead builds the [Client] on a dedicated thread that runs its own
+//! single-threaded runtime and owns the client for its whole lifetime. Every call made through a
+//! handle is queued and executed against the client one at a time, which serializes access to the
+//! RPC connection and to store writes while letting any number of tasks hold a handle.
+//!
+//! Every call runs on the worker thread, one at a time, so a [SharedClient] doesn't execute reads
+//! concurrently either: it only lets the client be used from many tasks.
+//!
+//! Calls that only read the client (such as [SharedClient::get_account]) go through a separate
+//! queue. When both queues have pending calls, the worker alternates between them, so a read waits
+//! for at most one queued call that can change the client, and a steady stream of reads can't
+//! delay those calls indefinitely. Reads still wait for the call that is running, though: a read
+//! issued while a transaction is being proved locally completes once the proof is done.
+//!
+//! [Store]: crate::store::Store
+
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use core::{future::Future, pin::Pin};
+use std::thread;
+
+use miden_objects::{
+    accounts::{Account, AccountHeader, AccountId},
+    crypto::rand::FeltRng,
+    notes::NoteId,
+    Word,
+};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    notes::NoteFile,
+    store::{InputNoteRecord, NoteFilter},
+    sync::SyncSummary,
+    transactions::{TransactionRequest, TransactionResult},
+    Client, ClientError,
+};
+
+/// A pinned and boxed future that is not required to be `Send`.
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// A unit of work executed by the worker thread against the client it owns.
+type ClientJob<R> = Box<dyn for<'a> FnOnce(&'a mut Client<R>) -> LocalBoxFuture<'a, ()> + Send>;
+
+/// A unit of work that only reads the client, queued separately from [ClientJob]s.
+type ReadJob<R> = Box<dyn for<'a> FnOnce(&'a Client<R>) -> LocalBoxFuture<'a, ()> + Send>;
+
+/// A job taken from one of the queues of the worker thread.
+enum WorkerJob<R: FeltRng> {
+    Read(ReadJob<R>),
+    Write(ClientJob<R>),
+}
+
+// SHARED CLIENT
+// ================================================================================================
+
+/// A `Send + Sync` handle to a [Client] owned by a dedicated worker thread.
+///
+/// Handles are cheap to clone and every method takes `&self`, so a [SharedClient] can be stored in
+/// an `Arc` or in the state of a web service and used from any number of tasks. Calls are executed
+/// one at a time on the worker thread, in the order in which they are received, except that reads
+/// and calls that can change the client are taken alternately from their own queues. The worker
+/// thread stops once every handle has been dropped.
+pub struct SharedClient<R: FeltRng + 'static> {
+    sender: mpsc::UnboundedSender<ClientJob<R>>,
+    read_sender: mpsc::UnboundedSender<ReadJob<R>>,
+}
+
+impl<R: FeltRng + 'static> Clone for SharedClient<R> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            read_sender: self.read_sender.clone(),
+        }
+    }
+}
+
+impl<R: FeltRng + 'static> SharedClient<R> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Spawns the worker thread, builds the client on it by calling `init` and returns a handle
+    /// to it.
+    ///
+    /// The client is built on the worker thread because its components (such as the store) don't
+    /// need to be `Send`. Only the `init` closure has to be.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the worker thread or its runtime could not be started, or the error
+    /// returned by `init`.
+    pub async fn new<F, Fut>(init: F) -> Result<Self, ClientError>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Client<R>, ClientError>> + 'static,
+    {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<ClientJob<R>>();
+        let (read_sender, mut read_receiver) = mpsc::unbounded_channel::<ReadJob<R>>();
+        let (init_sender, init_receiver) = oneshot::channel();
+
+        thread::Builder::new()
+            .name("miden-client".to_string())
+            .spawn(move || {
+                let runtime =
+                    match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                        Ok(runtime) => runtime,
+                        Err(err) => {
+                            let _ = init_sender.send(Err(ClientError::SharedClientError(format!(
+                                "failed to start the client runtime: {err}"
+                            ))));
+                            return;
+                        },
+                    };
+
+                runtime.block_on(async move {
+                    let mut client = match init().await {
+                        Ok(client) => client,
+                        Err(err) => {
+                            let _ = init_sender.send(Err(err));
+                            return;
+                        },
+                    };
+
+                    if init_sender.send(Ok(())).is_err() {
+                        return;
+                    }
+
+                    // When both queues have pending jobs, the queue that wasn't served last goes
+                    // first, so neither of them can starve the other
+                    let mut read_next = true;
+                    loop {
+                        let job = if read_next {
+                            tokio::select! {
+                                biased;
+                                Some(read_job) = read_receiver.recv() => WorkerJob::Read(read_job),
+                                Some(job) = receiver.recv() => WorkerJob::Write(job),
+                                else => break,
+                            }
+                        } else {
+                            tokio::select! {
+                                biased;
+                                Some(job) = receiver.recv() => WorkerJob::Write(job),
+                                Some(read_job) = read_receiver.recv() => WorkerJob::Read(read_job),
+                                else => break,
+                            }
+                        };
+
+                        match job {
+                            WorkerJob::Read(read_job) => {
+                                read_job(&client).await;
+                                read_next = false;
+                            },
+                            WorkerJob::Write(job) => {
+                                job(&mut client).await;
+                                read_next = true;
+                            },
+                        }
+                    }
+                });
+            })
+            .map_err(|err| {
+                ClientError::SharedClientError(format!("failed to spawn the client thread: {err}"))
+            })?;
+
+        init_receiver.await.map_err(|_| worker_stopped())??;
+
+        Ok(Self { sender, read_sender })
+    }
+
+    // CLIENT ACCESS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes `f` against the shared client and returns its result.
+    ///
+    /// This can be used to call any [Client] method that doesn't have a counterpart on
+    /// [SharedClient]:
+    ///
+    /// ```ignore
+    /// let tags = shared_client
+    ///     .with_client(|client| Box::pin(async move { client.get_note_tags().await }))
+    ///     .await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::SharedClientError] if the worker thread has stopped, or the error
+    /// returned by `f`.
+    pub async fn with_client<T, F>(&self, f: F) -> Result<T, ClientError>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a mut Client<R>) -> LocalBoxFuture<'a, Result<T, ClientError>>
+            + Send
+            + 'static,
+    {
+        let (result_sender, result_receiver) = oneshot::channel();
+        let job = into_job(move |client| {
+            Box::pin(async move {
+                let _ = result_sender.send(f(client).await);
+            })
+        });
+
+        self.sender.send(job).map_err(|_| worker_stopped())?;
+        result_receiver.await.map_err(|_| worker_stopped())?
+    }
+
+    /// Executes `f` against the shared client without changing it and returns its result.
+    ///
+    /// Unlike [SharedClient::with_client], `f` waits for at most one call that is queued but not
+    /// yet running, so reads are not delayed by a backlog of long jobs such as proving batches of
+    /// transactions. It still runs on the worker thread, after the call that is running.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::SharedClientError] if the worker thread has stopped, or the error
+    /// returned by `f`.
+    pub async fn read_client<T, F>(&self, f: F) -> Result<T, ClientError>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a Client<R>) -> LocalBoxFuture<'a, Result<T, ClientError>>
+            + Send
+            + 'static,
+    {
+        let (result_sender, result_receiver) = oneshot::channel();
+        let job = into_read_job(move |client| {
+            Box::pin(async move {
+                let _ = result_sender.send(f(client).await);
+            })
+        });
+
+        self.read_sender.send(job).map_err(|_| worker_stopped())?;
+        result_receiver.await.map_err(|_| worker_stopped())?
+    }
+
+    // CLIENT METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Syncs the shared client with the node. See [Client::sync_state].
+    pub async fn sync_state(&self) -> Result<SyncSummary, ClientError> {
+        self.with_client(|client| Box::pin(client.sync_state())).await
+    }
+
+    /// Returns the block number of the last state sync block. See [Client::get_sync_height].
+    pub async fn get_sync_height(&self) -> Result<u32, ClientError> {
+        self.read_client(|client| Box::pin(client.get_sync_height())).await
+    }
+
+    /// Returns the headers of the tracked accounts. See [Client::get_account_headers].
+    pub async fn get_account_headers(
+        &self,
+    ) -> Result<Vec<(AccountHeader, Option<Word>)>, ClientError> {
+        self.read_client(|client| Box::pin(client.get_account_headers())).await
+    }
+
+    /// Returns the account with the specified ID. See [Client::get_account].
+    pub async fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), ClientError> {
+        self.read_client(move |client| Box::pin(client.get_account(account_id))).await
+    }
+
+    /// Returns the input notes that match the filter. See [Client::get_input_notes].
+    pub async fn get_input_notes(
+        &self,
+        filter: NoteFilter,
+    ) -> Result<Vec<InputNoteRecord>, ClientError> {
+        self.read_client(move |client| Box::pin(client.get_input_notes(filter))).await
+    }
+
+    /// Imports a note into the shared client. See [Client::import_note].
+    pub async fn import_note(&self, note_file: NoteFile) -> Result<NoteId, ClientError> {
+        self.with_client(move |client| Box::pin(client.import_note(note_file))).await
+    }
+
+    /// Executes a transaction against the specified account. See [Client::new_transaction].
+    pub async fn new_transaction(
+        &self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+    ) -> Result<TransactionResult, ClientError> {
+        self.with_client(move |client| {
+            Box::pin(client.new_transaction(account_id, transaction_request))
+        })
+        .await
+    }
+
+    /// Proves and submits an executed transaction. See [Client::submit_transaction].
+    pub async fn submit_transaction(
+        &self,
+        tx_result: TransactionResult,
+    ) -> Result<(), ClientError> {
+        self.with_client(move |client| Box::pin(client.submit_transaction(tx_result)))
+            .await
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Boxes `f` into a [ClientJob]. Taking the closure through a generic bound lets the compiler
+/// infer its higher-ranked signature.
+fn into_job<R, F>(f: F) -> ClientJob<R>
+where
+    R: FeltRng + 'static,
+    F: for<'a> FnOnce(&'a mut Client<R>) -> LocalBoxFuture<'a, ()> + Send + 'static,
+{
+    Box::new(f)
+}
+
+/// Boxes `f` into a [ReadJob], like [into_job] does for [ClientJob]s.
+fn into_read_job<R, F>(f: F) -> ReadJob<R>
+where
+    R: FeltRng + 'static,
+    F: for<'a> FnOnce(&'a Client<R>) -> LocalBoxFuture<'a, ()> + Send + 'static,
+{
+    Box::new(f)
+}
+
+fn worker_stopped() -> ClientError {
+    ClientError::SharedClientError("the client worker thread has stopped".to_string())
+}
@@ -6,7 +6,7 @@ use alloc::{
 };
 
 use miden_objects::{
-    accounts::AccountId,
+    accounts::{Account, AccountId},
     crypto::merkle::{InOrderIndex, MerklePath, PartialMmr},
     notes::NoteId,
     transaction::{ChainMmr, InputNote, InputNotes},
@@ -24,11 +24,34 @@ use crate::store::StoreError;
 pub(crate) struct ClientDataStore {
     /// Local database containing information about the accounts managed by this client.
     pub(crate) store: alloc::sync::Arc<dyn Store>,
+    /// Account state used instead of the stored one, for transactions that are executed on top of
+    /// transactions that weren't applied to the store yet.
+    pending_account: Option<Account>,
+    /// Input notes provided in addition to the stored ones, for unauthenticated notes that
+    /// aren't stored yet.
+    pending_notes: BTreeMap<NoteId, InputNoteRecord>,
 }
 
 impl ClientDataStore {
     pub fn new(store: alloc::sync::Arc<dyn Store>) -> Self {
-        Self { store }
+        Self {
+            store,
+            pending_account: None,
+            pending_notes: BTreeMap::new(),
+        }
+    }
+
+    /// Makes the data store provide `account` instead of the stored state of the account.
+    pub fn with_pending_account(mut self, account: Account) -> Self {
+        self.pending_account = Some(account);
+        self
+    }
+
+    /// Makes the data store provide `notes` in addition to the stored input notes, replacing
+    /// stored notes with the same ID.
+    pub fn with_pending_notes(mut self, notes: Vec<InputNoteRecord>) -> Self {
+        self.pending_notes.extend(notes.into_iter().map(|note| (note.id(), note)));
+        self
     }
 }
 #[async_trait::async_trait(?Send)]
@@ -39,13 +62,19 @@ impl DataStore for ClientDataStore {
         block_num: u32,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError> {
-        let input_note_records: BTreeMap<NoteId, InputNoteRecord> = self
+        let mut input_note_records: BTreeMap<NoteId, InputNoteRecord> = self
             .store
             .get_input_notes(NoteFilter::List(notes.to_vec()))
             .await?
             .into_iter()
             .map(|note_record| (note_record.id(), note_record))
             .collect();
+        input_note_records.extend(
+            notes
+                .iter()
+                .filter_map(|note_id| self.pending_notes.get_key_value(note_id))
+                .map(|(note_id, note_record)| (*note_id, note_record.clone())),
+        );
 
         // First validate that all notes were found and can be consumed
         for note_id in notes {
@@ -58,8 +87,14 @@ impl DataStore for ClientDataStore {
             }
         }
 
-        // Construct Account
-        let (account, seed) = self.store.get_account(account_id).await?;
+        // Construct Account. A new pending account has no changes over the stored one, which is
+        // used instead as it comes with the seed
+        let (account, seed) = match &self.pending_account {
+            Some(account) if account.id() == account_id && !account.is_new() => {
+                (account.clone(), None)
+            },
+            _ => self.store.get_account(account_id).await?,
+        };
 
         // Get header data
         let (block_header, _had_notes) = self.store.get_block_header_by_num(block_num).await?;
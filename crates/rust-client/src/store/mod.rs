@@ -9,15 +9,19 @@ use core::fmt::Debug;
 
 use async_trait::async_trait;
 use miden_objects::{
-    accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
+    accounts::{Account, AccountCode, AccountHeader, AccountId, AuthSecretKey},
     crypto::merkle::{InOrderIndex, MmrPeaks},
     notes::{NoteId, NoteTag, Nullifier},
+    transaction::TransactionId,
     BlockHeader, Digest, Word,
 };
 
 use crate::{
     sync::{NoteTagRecord, StateSyncUpdate},
-    transactions::{TransactionRecord, TransactionStoreUpdate},
+    transactions::{
+        RecurringPayment, SpendingPolicy, TransactionRecord, TransactionStoreUpdate,
+        UnsubmittedTransaction,
+    },
 };
 
 /// Contains [ClientDataStore] to automatically implement [DataStore] for anything that implements
@@ -82,8 +86,44 @@ pub trait Store: Send + Sync {
     /// - Updating the input notes that are being processed by the transaction
     /// - Inserting the new tracked tags into the store
     /// - Inserting the transaction into the store to track
+    /// - Removing the transaction from the unsubmitted transactions, if it was stored there
     async fn apply_transaction(&self, tx_update: TransactionStoreUpdate) -> Result<(), StoreError>;
 
+    /// Retrieves the transactions that were executed but not yet submitted to the network.
+    async fn get_unsubmitted_transactions(&self)
+        -> Result<Vec<UnsubmittedTransaction>, StoreError>;
+
+    /// Retrieves the unsubmitted transaction with the specified ID, or `None` if it isn't stored.
+    async fn get_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<UnsubmittedTransaction>, StoreError>;
+
+    /// Inserts the provided unsubmitted transaction into the store. If a transaction with the same
+    /// ID already exists, it will be replaced.
+    async fn upsert_unsubmitted_transaction(
+        &self,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<(), StoreError>;
+
+    /// Removes the unsubmitted transaction with the specified ID from the store. Removing a
+    /// transaction that is not stored is not an error.
+    async fn remove_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the recurring payments stored in the client.
+    async fn get_recurring_payments(&self) -> Result<Vec<RecurringPayment>, StoreError>;
+
+    /// Inserts the provided recurring payment into the store. If a payment with the same ID
+    /// already exists, it will be replaced.
+    async fn upsert_recurring_payment(&self, payment: &RecurringPayment) -> Result<(), StoreError>;
+
+    /// Removes the recurring payment with the specified ID from the store. Removing a payment
+    /// that doesn't exist is not an error.
+    async fn remove_recurring_payment(&self, payment_id: u32) -> Result<(), StoreError>;
+
     // NOTES
     // --------------------------------------------------------------------------------------------
 
@@ -261,12 +301,46 @@ pub trait Store: Send + Sync {
     /// Returns a `StoreError::AccountDataNotFound` if there is no account for the provided ID
     async fn get_account_auth(&self, account_id: AccountId) -> Result<AuthSecretKey, StoreError>;
 
-    /// Inserts an [Account] along with the seed used to create it and its [AuthSecretKey]
+    /// Inserts an [Account] along with the seed used to create it and its [AuthSecretKey]. The
+    /// key is `None` for accounts whose transactions are signed outside of the client.
     async fn insert_account(
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
+    ) -> Result<(), StoreError>;
+
+    /// Retrieves the [SpendingPolicy] of the account, or `None` if it doesn't have one.
+    async fn get_spending_policy(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<SpendingPolicy>, StoreError>;
+
+    /// Inserts the spending policy of the account. If the account already has a policy, it will be
+    /// replaced.
+    async fn upsert_spending_policy(
+        &self,
+        account_id: AccountId,
+        policy: &SpendingPolicy,
+    ) -> Result<(), StoreError>;
+
+    /// Removes the spending policy of the account. Removing a policy that doesn't exist is not an
+    /// error.
+    async fn remove_spending_policy(&self, account_id: AccountId) -> Result<(), StoreError>;
+
+    /// Retrieves the cached [AccountCode] of the specified foreign accounts. Accounts whose code
+    /// was never cached are not included in the returned map.
+    async fn get_foreign_account_code(
+        &self,
+        account_ids: Vec<AccountId>,
+    ) -> Result<BTreeMap<AccountId, AccountCode>, StoreError>;
+
+    /// Caches the [AccountCode] of a foreign account, replacing the code previously cached for
+    /// it. The code is stored by its commitment, so accounts sharing the same code share a copy.
+    async fn upsert_foreign_account_code(
+        &self,
+        account_id: AccountId,
+        code: AccountCode,
     ) -> Result<(), StoreError>;
 
     // SYNC
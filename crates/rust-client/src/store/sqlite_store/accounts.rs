@@ -1,4 +1,6 @@
 use alloc::{
+    collections::BTreeMap,
+    rc::Rc,
     string::{String, ToString},
     vec::Vec,
 };
@@ -9,10 +11,10 @@ use miden_objects::{
     Digest, Felt, Word,
 };
 use miden_tx::utils::{Deserializable, Serializable};
-use rusqlite::{params, Connection, Transaction};
+use rusqlite::{params, types::Value, Connection, Transaction};
 
 use super::SqliteStore;
-use crate::store::StoreError;
+use crate::{store::StoreError, transactions::SpendingPolicy};
 
 // TYPES
 // ================================================================================================
@@ -139,7 +141,7 @@ impl SqliteStore {
         conn: &mut Connection,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         let tx = conn.transaction()?;
 
@@ -147,7 +149,9 @@ impl SqliteStore {
         insert_account_storage(&tx, account.storage())?;
         insert_account_asset_vault(&tx, account.vault())?;
         insert_account_record(&tx, account, account_seed)?;
-        insert_account_auth(&tx, account.id(), auth_info)?;
+        if let Some(auth_info) = auth_info {
+            insert_account_auth(&tx, account.id(), auth_info)?;
+        }
 
         Ok(tx.commit()?)
     }
@@ -165,6 +169,98 @@ impl SqliteStore {
             .next()
             .ok_or(StoreError::AccountKeyNotFound(pub_key))?
     }
+
+    // SPENDING POLICIES
+    // --------------------------------------------------------------------------------------------
+
+    /// Retrieves the spending policy of the account, if it has one.
+    pub(super) fn get_spending_policy(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<Option<SpendingPolicy>, StoreError> {
+        let account_id_int: u64 = account_id.into();
+        const QUERY: &str = "SELECT policy FROM spending_policies WHERE account_id = ?";
+
+        conn.prepare(QUERY)?
+            .query_map(params![account_id_int as i64], |row| row.get::<_, Vec<u8>>(0))?
+            .map(|result| Ok(SpendingPolicy::read_from_bytes(&result?)?))
+            .next()
+            .transpose()
+    }
+
+    /// Inserts the spending policy of the account, replacing its previous policy.
+    pub(super) fn upsert_spending_policy(
+        conn: &mut Connection,
+        account_id: AccountId,
+        policy: &SpendingPolicy,
+    ) -> Result<(), StoreError> {
+        let account_id_int: u64 = account_id.into();
+        const QUERY: &str =
+            "INSERT OR REPLACE INTO spending_policies (account_id, policy) VALUES (?, ?)";
+        conn.execute(QUERY, params![account_id_int as i64, policy.to_bytes()])?;
+
+        Ok(())
+    }
+
+    /// Removes the spending policy of the account.
+    pub(super) fn remove_spending_policy(
+        conn: &mut Connection,
+        account_id: AccountId,
+    ) -> Result<(), StoreError> {
+        let account_id_int: u64 = account_id.into();
+        const QUERY: &str = "DELETE FROM spending_policies WHERE account_id = ?";
+        conn.execute(QUERY, params![account_id_int as i64])?;
+
+        Ok(())
+    }
+
+    // FOREIGN ACCOUNT CODE
+    // --------------------------------------------------------------------------------------------
+
+    /// Retrieves the cached code of the specified foreign accounts.
+    pub(super) fn get_foreign_account_code(
+        conn: &mut Connection,
+        account_ids: Vec<AccountId>,
+    ) -> Result<BTreeMap<AccountId, AccountCode>, StoreError> {
+        let account_id_list = account_ids
+            .into_iter()
+            .map(|account_id| Value::Integer(u64::from(account_id) as i64))
+            .collect::<Vec<Value>>();
+
+        const QUERY: &str = "SELECT foreign_account_code.account_id, account_code.code \
+            FROM foreign_account_code \
+            JOIN account_code ON foreign_account_code.code_root = account_code.root \
+            WHERE foreign_account_code.account_id IN rarray(?)";
+
+        conn.prepare(QUERY)?
+            .query_map(params![Rc::new(account_id_list)], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+            })?
+            .map(|result| {
+                let (account_id, code) = result?;
+                let account_id = AccountId::try_from(account_id as u64)?;
+                Ok((account_id, AccountCode::from_bytes(&code)?))
+            })
+            .collect()
+    }
+
+    /// Caches the code of a foreign account, replacing the code previously cached for it.
+    pub(super) fn upsert_foreign_account_code(
+        conn: &mut Connection,
+        account_id: AccountId,
+        code: &AccountCode,
+    ) -> Result<(), StoreError> {
+        let tx = conn.transaction()?;
+
+        insert_account_code(&tx, code)?;
+
+        let account_id_int: u64 = account_id.into();
+        const QUERY: &str =
+            "INSERT OR REPLACE INTO foreign_account_code (account_id, code_root) VALUES (?, ?)";
+        tx.execute(QUERY, params![account_id_int as i64, code.commitment().to_string()])?;
+
+        Ok(tx.commit()?)
+    }
 }
 
 // HELPERS
@@ -397,7 +493,13 @@ pub(super) fn parse_account_columns(
 #[cfg(test)]
 mod tests {
     use miden_objects::{
-        accounts::{AccountCode, AccountComponent, AccountId},
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountCode, AccountComponent, AccountId,
+        },
         crypto::dsa::rpo_falcon512::SecretKey,
         testing::account_component::BASIC_WALLET_CODE,
     };
@@ -451,6 +553,55 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_foreign_account_code_cache() {
+        let store = create_test_store().await;
+        let assembler = miden_lib::transaction::TransactionKernel::assembler();
+        let account_component = AccountComponent::compile(BASIC_WALLET_CODE, assembler, vec![])
+            .unwrap()
+            .with_supports_all_types();
+        let account_code = AccountCode::from_components(
+            &[account_component],
+            miden_objects::accounts::AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let other_account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        // Accounts with no cached code are not returned
+        let cached_codes =
+            Store::get_foreign_account_code(&store, vec![account_id, other_account_id])
+                .await
+                .unwrap();
+        assert!(cached_codes.is_empty());
+
+        Store::upsert_foreign_account_code(&store, account_id, account_code.clone())
+            .await
+            .unwrap();
+        Store::upsert_foreign_account_code(&store, other_account_id, account_code.clone())
+            .await
+            .unwrap();
+
+        let cached_codes = Store::get_foreign_account_code(&store, vec![account_id]).await.unwrap();
+        assert_eq!(cached_codes.len(), 1);
+        assert_eq!(cached_codes[&account_id].commitment(), account_code.commitment());
+
+        // Accounts with the same code share a single copy of it
+        store
+            .interact_with_connection(|conn| {
+                let code_count: usize = conn
+                    .query_row("SELECT Count(*) FROM account_code", [], |row| row.get(0))
+                    .unwrap();
+                assert_eq!(code_count, 1);
+
+                Ok(())
+            })
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_auth_info_serialization() {
         let exp_key_pair = SecretKey::new();
@@ -7,9 +7,10 @@ use std::{path::Path, string::ToString};
 
 use deadpool_sqlite::{Config, Hook, HookError, Pool, Runtime};
 use miden_objects::{
-    accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
+    accounts::{Account, AccountCode, AccountHeader, AccountId, AuthSecretKey},
     crypto::merkle::{InOrderIndex, MmrPeaks},
     notes::{NoteTag, Nullifier},
+    transaction::TransactionId,
     BlockHeader, Digest, Word,
 };
 use rusqlite::{vtab::array, Connection};
@@ -22,7 +23,10 @@ use super::{
 use crate::{
     store::StoreError,
     sync::{NoteTagRecord, StateSyncUpdate},
-    transactions::{TransactionRecord, TransactionStoreUpdate},
+    transactions::{
+        RecurringPayment, SpendingPolicy, TransactionRecord, TransactionStoreUpdate,
+        UnsubmittedTransaction,
+    },
 };
 
 mod accounts;
@@ -155,6 +159,62 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_unsubmitted_transactions(
+        &self,
+    ) -> Result<Vec<UnsubmittedTransaction>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_unsubmitted_transactions).await
+    }
+
+    async fn get_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<UnsubmittedTransaction>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_unsubmitted_transaction(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn upsert_unsubmitted_transaction(
+        &self,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<(), StoreError> {
+        let transaction = transaction.clone();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::upsert_unsubmitted_transaction(conn, &transaction)
+        })
+        .await
+    }
+
+    async fn remove_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::remove_unsubmitted_transaction(conn, transaction_id)
+        })
+        .await
+    }
+
+    async fn get_recurring_payments(&self) -> Result<Vec<RecurringPayment>, StoreError> {
+        self.interact_with_connection(SqliteStore::get_recurring_payments).await
+    }
+
+    async fn upsert_recurring_payment(&self, payment: &RecurringPayment) -> Result<(), StoreError> {
+        let payment = payment.clone();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::upsert_recurring_payment(conn, &payment)
+        })
+        .await
+    }
+
+    async fn remove_recurring_payment(&self, payment_id: u32) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::remove_recurring_payment(conn, payment_id)
+        })
+        .await
+    }
+
     async fn get_input_notes(
         &self,
         filter: NoteFilter,
@@ -235,13 +295,13 @@ impl Store for SqliteStore {
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         let account = account.clone();
-        let auth_info = auth_info.clone();
+        let auth_info = auth_info.cloned();
 
         self.interact_with_connection(move |conn| {
-            SqliteStore::insert_account(conn, &account, account_seed, &auth_info)
+            SqliteStore::insert_account(conn, &account, account_seed, auth_info.as_ref())
         })
         .await
     }
@@ -295,6 +355,56 @@ impl Store for SqliteStore {
             .await
     }
 
+    async fn get_spending_policy(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<SpendingPolicy>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_spending_policy(conn, account_id)
+        })
+        .await
+    }
+
+    async fn upsert_spending_policy(
+        &self,
+        account_id: AccountId,
+        policy: &SpendingPolicy,
+    ) -> Result<(), StoreError> {
+        let policy = policy.clone();
+        self.interact_with_connection(move |conn| {
+            SqliteStore::upsert_spending_policy(conn, account_id, &policy)
+        })
+        .await
+    }
+
+    async fn remove_spending_policy(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::remove_spending_policy(conn, account_id)
+        })
+        .await
+    }
+
+    async fn get_foreign_account_code(
+        &self,
+        account_ids: Vec<AccountId>,
+    ) -> Result<BTreeMap<AccountId, AccountCode>, StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::get_foreign_account_code(conn, account_ids)
+        })
+        .await
+    }
+
+    async fn upsert_foreign_account_code(
+        &self,
+        account_id: AccountId,
+        code: AccountCode,
+    ) -> Result<(), StoreError> {
+        self.interact_with_connection(move |conn| {
+            SqliteStore::upsert_foreign_account_code(conn, account_id, &code)
+        })
+        .await
+    }
+
     async fn get_unspent_input_note_nullifiers(&self) -> Result<Vec<Nullifier>, StoreError> {
         self.interact_with_connection(SqliteStore::get_unspent_input_note_nullifiers)
             .await
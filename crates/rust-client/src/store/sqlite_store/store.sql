@@ -27,6 +27,21 @@ CREATE TABLE account_auth (
     PRIMARY KEY (account_id)
 );
 
+-- Create spending_policies table
+CREATE TABLE spending_policies (
+    account_id UNSIGNED BIG INT NOT NULL,  -- ID of the account
+    policy BLOB NOT NULL,                  -- Serialized spending policy, including the spending of the current day
+    PRIMARY KEY (account_id)
+);
+
+-- Create foreign_account_code table
+CREATE TABLE foreign_account_code (
+    account_id UNSIGNED BIG INT NOT NULL,  -- ID of the foreign account
+    code_root TEXT NOT NULL,               -- Root of the account_code of the foreign account
+    PRIMARY KEY (account_id),
+    FOREIGN KEY (code_root) REFERENCES account_code(root)
+);
+
 -- Create accounts table
 CREATE TABLE accounts (
     id UNSIGNED BIG INT NOT NULL,  -- Account ID.
@@ -70,6 +85,25 @@ CREATE TABLE transaction_scripts (
     PRIMARY KEY (script_hash)
 );
 
+-- Create unsubmitted_transactions table
+CREATE TABLE unsubmitted_transactions (
+    id TEXT NOT NULL,                                -- Transaction ID (hash of various components)
+    account_id UNSIGNED BIG INT NOT NULL,            -- ID of the account against which the transaction was executed.
+    transaction_result BLOB NOT NULL,                -- Serialized executed transaction and relevant notes
+    proven_transaction BLOB NULL,                    -- Serialized proven transaction, NULL until the transaction is proven
+    submission_attempts UNSIGNED INT NOT NULL,       -- Number of failed attempts to submit the transaction
+    last_attempt_block UNSIGNED BIG INT NULL,        -- Sync height of the client when the last submission attempt failed
+    PRIMARY KEY (id)
+);
+
+-- Create recurring_payments table
+CREATE TABLE recurring_payments (
+    id UNSIGNED INT NOT NULL,                        -- ID of the payment, assigned by the client
+    account_id UNSIGNED BIG INT NOT NULL,            -- ID of the account that makes the payment
+    payment BLOB NOT NULL,                           -- Serialized payment, including its schedule and history
+    PRIMARY KEY (id)
+);
+
 -- Create input notes table
 CREATE TABLE input_notes (
     note_id TEXT NOT NULL,                                  -- the note id
@@ -8,7 +8,8 @@ use miden_objects::{
     accounts::AccountId,
     crypto::utils::{Deserializable, Serializable},
     transaction::{
-        ExecutedTransaction, OutputNotes, ToInputNoteCommitments, TransactionId, TransactionScript,
+        ExecutedTransaction, OutputNotes, ProvenTransaction, ToInputNoteCommitments, TransactionId,
+        TransactionScript,
     },
     Digest,
 };
@@ -21,7 +22,10 @@ use super::{
 use crate::{
     rpc::TransactionUpdate,
     store::{StoreError, TransactionFilter},
-    transactions::{TransactionRecord, TransactionStatus, TransactionStoreUpdate},
+    transactions::{
+        RecurringPayment, TransactionRecord, TransactionResult, TransactionStatus,
+        TransactionStoreUpdate, UnsubmittedTransaction,
+    },
 };
 
 pub(crate) const INSERT_TRANSACTION_QUERY: &str =
@@ -33,6 +37,9 @@ pub(crate) const INSERT_TRANSACTION_SCRIPT_QUERY: &str =
     "INSERT OR IGNORE INTO transaction_scripts (script_hash, script) \
     VALUES (?, ?)";
 
+const UNSUBMITTED_TRANSACTIONS_QUERY: &str = "SELECT transaction_result, proven_transaction, \
+    submission_attempts, last_attempt_block FROM unsubmitted_transactions";
+
 // TRANSACTIONS FILTERS
 // ================================================================================================
 
@@ -89,6 +96,10 @@ impl SqliteStore {
 
         // Transaction Data
         insert_proven_transaction_data(&tx, tx_update.executed_transaction())?;
+        tx.execute(
+            "DELETE FROM unsubmitted_transactions WHERE id = ?",
+            params![tx_update.executed_transaction().id().to_string()],
+        )?;
 
         // Account Data
         update_account(&tx, tx_update.updated_account())?;
@@ -105,6 +116,105 @@ impl SqliteStore {
         Ok(())
     }
 
+    /// Retrieves the transactions that were executed but not yet submitted to the network.
+    pub fn get_unsubmitted_transactions(
+        conn: &mut Connection,
+    ) -> Result<Vec<UnsubmittedTransaction>, StoreError> {
+        conn.prepare(UNSUBMITTED_TRANSACTIONS_QUERY)?
+            .query_map([], parse_unsubmitted_transaction_columns)
+            .expect("no binding parameters used in query")
+            .map(|result| Ok(result?).and_then(parse_unsubmitted_transaction))
+            .collect::<Result<Vec<UnsubmittedTransaction>, _>>()
+    }
+
+    /// Retrieves the unsubmitted transaction with the specified ID, if it exists.
+    pub fn get_unsubmitted_transaction(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<Option<UnsubmittedTransaction>, StoreError> {
+        let query = format!("{UNSUBMITTED_TRANSACTIONS_QUERY} WHERE id = ?");
+
+        conn.prepare(&query)?
+            .query_map(params![transaction_id.to_string()], parse_unsubmitted_transaction_columns)?
+            .map(|result| Ok(result?).and_then(parse_unsubmitted_transaction))
+            .next()
+            .transpose()
+    }
+
+    /// Inserts an unsubmitted transaction, replacing any transaction with the same ID.
+    pub fn upsert_unsubmitted_transaction(
+        conn: &mut Connection,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "INSERT OR REPLACE INTO unsubmitted_transactions \
+            (id, account_id, transaction_result, proven_transaction, submission_attempts, \
+            last_attempt_block) VALUES (?, ?, ?, ?, ?, ?)";
+
+        let account_id: u64 = transaction.account_id().into();
+        conn.execute(
+            QUERY,
+            params![
+                transaction.id().to_string(),
+                account_id as i64,
+                transaction.tx_result().to_bytes(),
+                transaction.proven_transaction().map(|proven| proven.to_bytes()),
+                transaction.submission_attempts(),
+                transaction.last_attempt_block(),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Removes the unsubmitted transaction with the specified ID, if it exists.
+    pub fn remove_unsubmitted_transaction(
+        conn: &mut Connection,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "DELETE FROM unsubmitted_transactions WHERE id = ?";
+        conn.execute(QUERY, params![transaction_id.to_string()])?;
+
+        Ok(())
+    }
+
+    /// Retrieves all the recurring payments.
+    pub fn get_recurring_payments(
+        conn: &mut Connection,
+    ) -> Result<Vec<RecurringPayment>, StoreError> {
+        const QUERY: &str = "SELECT payment FROM recurring_payments ORDER BY id";
+
+        conn.prepare(QUERY)?
+            .query_map([], |row| row.get::<_, Vec<u8>>(0))
+            .expect("no binding parameters used in query")
+            .map(|result| Ok(RecurringPayment::read_from_bytes(&result?)?))
+            .collect::<Result<Vec<RecurringPayment>, _>>()
+    }
+
+    /// Inserts a recurring payment, replacing any payment with the same ID.
+    pub fn upsert_recurring_payment(
+        conn: &mut Connection,
+        payment: &RecurringPayment,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str =
+            "INSERT OR REPLACE INTO recurring_payments (id, account_id, payment) VALUES (?, ?, ?)";
+
+        let account_id: u64 = payment.payment_data().account_id().into();
+        conn.execute(QUERY, params![payment.id(), account_id as i64, payment.to_bytes()])?;
+
+        Ok(())
+    }
+
+    /// Removes the recurring payment with the specified ID, if it exists.
+    pub fn remove_recurring_payment(
+        conn: &mut Connection,
+        payment_id: u32,
+    ) -> Result<(), StoreError> {
+        const QUERY: &str = "DELETE FROM recurring_payments WHERE id = ?";
+        conn.execute(QUERY, params![payment_id])?;
+
+        Ok(())
+    }
+
     /// Set the provided transactions as committed
     ///
     /// # Errors
@@ -314,3 +424,38 @@ fn parse_transaction(
         transaction_status,
     })
 }
+
+/// Parse the columns of an unsubmitted transaction row.
+fn parse_unsubmitted_transaction_columns(
+    row: &rusqlite::Row<'_>,
+) -> Result<(Vec<u8>, Option<Vec<u8>>, u32, Option<u32>), rusqlite::Error> {
+    let transaction_result: Vec<u8> = row.get(0)?;
+    let proven_transaction: Option<Vec<u8>> = row.get(1)?;
+    let submission_attempts: u32 = row.get(2)?;
+    let last_attempt_block: Option<u32> = row.get(3)?;
+
+    Ok((transaction_result, proven_transaction, submission_attempts, last_attempt_block))
+}
+
+/// Parse an unsubmitted transaction from its serialized transaction result, proof and submission
+/// attempts.
+fn parse_unsubmitted_transaction(
+    (transaction_result, proven_transaction, submission_attempts, last_attempt_block): (
+        Vec<u8>,
+        Option<Vec<u8>>,
+        u32,
+        Option<u32>,
+    ),
+) -> Result<UnsubmittedTransaction, StoreError> {
+    let tx_result = TransactionResult::read_from_bytes(&transaction_result)?;
+    let proven_transaction = proven_transaction
+        .map(|proven| ProvenTransaction::read_from_bytes(&proven))
+        .transpose()?;
+
+    Ok(UnsubmittedTransaction::from_parts(
+        tx_result,
+        proven_transaction,
+        submission_attempts,
+        last_attempt_block,
+    ))
+}
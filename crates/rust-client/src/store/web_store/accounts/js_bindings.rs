@@ -38,6 +38,12 @@ extern "C" {
     #[wasm_bindgen(js_name = fetchAndCacheAccountAuthByPubKey)]
     pub fn idxdb_fetch_and_cache_account_auth_by_pub_key(account_id: String) -> js_sys::Promise;
 
+    #[wasm_bindgen(js_name = getSpendingPolicy)]
+    pub fn idxdb_get_spending_policy(account_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getForeignAccountCode)]
+    pub fn idxdb_get_foreign_account_code(account_ids: Vec<String>) -> js_sys::Promise;
+
     // INSERTS
     // ================================================================================================
 
@@ -72,4 +78,20 @@ extern "C" {
         auth_info: Vec<u8>,
         pub_key: Vec<u8>,
     ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = upsertSpendingPolicy)]
+    pub fn idxdb_upsert_spending_policy(account_id: String, policy: Vec<u8>) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = upsertForeignAccountCode)]
+    pub fn idxdb_upsert_foreign_account_code(
+        account_id: String,
+        code_root: String,
+        code: Vec<u8>,
+    ) -> js_sys::Promise;
+
+    // DELETES
+    // ================================================================================================
+
+    #[wasm_bindgen(js_name = removeSpendingPolicy)]
+    pub fn idxdb_remove_spending_policy(account_id: String) -> js_sys::Promise;
 }
@@ -1,4 +1,5 @@
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
@@ -13,7 +14,7 @@ use serde_wasm_bindgen::from_value;
 use wasm_bindgen_futures::*;
 
 use super::WebStore;
-use crate::store::StoreError;
+use crate::{store::StoreError, transactions::SpendingPolicy};
 
 mod js_bindings;
 use js_bindings::*;
@@ -202,7 +203,7 @@ impl WebStore {
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         insert_account_code(account.code()).await.unwrap();
 
@@ -212,7 +213,9 @@ impl WebStore {
 
         insert_account_record(account, account_seed).await.unwrap();
 
-        insert_account_auth(account.id(), auth_info).await.unwrap();
+        if let Some(auth_info) = auth_info {
+            insert_account_auth(account.id(), auth_info).await.unwrap();
+        }
 
         Ok(())
     }
@@ -267,4 +270,73 @@ impl WebStore {
 
         Ok(auth_info)
     }
+
+    pub(crate) async fn get_spending_policy(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<SpendingPolicy>, StoreError> {
+        let promise = idxdb_get_spending_policy(account_id.to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let policy_idxdb: Option<SpendingPolicyIdxdbObject> = from_value(js_value).unwrap();
+
+        policy_idxdb
+            .map(|policy_idxdb| SpendingPolicy::read_from_bytes(&policy_idxdb.policy))
+            .transpose()
+            .map_err(StoreError::DataDeserializationError)
+    }
+
+    pub(crate) async fn upsert_spending_policy(
+        &self,
+        account_id: AccountId,
+        policy: &SpendingPolicy,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_upsert_spending_policy(account_id.to_string(), policy.to_bytes());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn remove_spending_policy(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_remove_spending_policy(account_id.to_string());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_foreign_account_code(
+        &self,
+        account_ids: Vec<AccountId>,
+    ) -> Result<BTreeMap<AccountId, AccountCode>, StoreError> {
+        let account_ids = account_ids.iter().map(|account_id| account_id.to_string()).collect();
+        let promise = idxdb_get_foreign_account_code(account_ids);
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let codes_idxdb: Vec<ForeignAccountCodeIdxdbObject> = from_value(js_value).unwrap();
+
+        codes_idxdb
+            .into_iter()
+            .map(|code_idxdb| {
+                let account_id = AccountId::from_hex(&code_idxdb.account_id)?;
+                let code = AccountCode::read_from_bytes(&code_idxdb.code)?;
+                Ok((account_id, code))
+            })
+            .collect()
+    }
+
+    pub(crate) async fn upsert_foreign_account_code(
+        &self,
+        account_id: AccountId,
+        code: AccountCode,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_upsert_foreign_account_code(
+            account_id.to_string(),
+            code.commitment().to_string(),
+            code.to_bytes(),
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
 }
@@ -4,6 +4,8 @@ import {
   accountVaults,
   accountAuths,
   accounts,
+  spendingPolicies,
+  foreignAccountCode,
 } from "./schema.js";
 
 // GET FUNCTIONS
@@ -298,6 +300,65 @@ export function getAccountAuthByPubKey(pubKey) {
 }
 
 var ACCOUNT_AUTH_MAP = new Map();
+
+export async function getForeignAccountCode(accountIds) {
+  try {
+    const foreignAccounts = await foreignAccountCode
+      .where("accountId")
+      .anyOf(accountIds)
+      .toArray();
+
+    const processedCodes = await Promise.all(
+      foreignAccounts.map(async (foreignAccount) => {
+        const codeRecord = await accountCodes
+          .where("root")
+          .equals(foreignAccount.codeRoot)
+          .first();
+
+        if (!codeRecord) {
+          return null;
+        }
+
+        const codeArrayBuffer = await codeRecord.code.arrayBuffer();
+        const codeBase64 = uint8ArrayToBase64(new Uint8Array(codeArrayBuffer));
+
+        return {
+          account_id: foreignAccount.accountId,
+          code: codeBase64,
+        };
+      })
+    );
+
+    return processedCodes.filter((code) => code !== null);
+  } catch (err) {
+    console.error("Error fetching foreign account code:", err);
+    throw err;
+  }
+}
+
+export async function getSpendingPolicy(accountId) {
+  try {
+    const record = await spendingPolicies
+      .where("accountId")
+      .equals(accountId)
+      .first();
+
+    if (!record) {
+      return null;
+    }
+
+    const policyArrayBuffer = await record.policy.arrayBuffer();
+    const policyBase64 = uint8ArrayToBase64(new Uint8Array(policyArrayBuffer));
+
+    return {
+      account_id: record.accountId,
+      policy: policyBase64,
+    };
+  } catch (err) {
+    console.error("Error fetching spending policy:", err);
+    throw err;
+  }
+}
 export async function fetchAndCacheAccountAuthByPubKey(accountId) {
   try {
     // Fetch all records matching the given id
@@ -449,6 +510,48 @@ export async function insertAccountAuth(accountId, authInfo, pubKey) {
   }
 }
 
+export async function upsertSpendingPolicy(accountId, policy) {
+  try {
+    const data = {
+      accountId: accountId,
+      policy: new Blob([new Uint8Array(policy)]),
+    };
+
+    await spendingPolicies.put(data);
+  } catch (error) {
+    console.error(`Error upserting spending policy for account: ${accountId}:`, error);
+    throw error;
+  }
+}
+
+export async function upsertForeignAccountCode(accountId, codeRoot, code) {
+  try {
+    // Accounts sharing the same code share the stored copy
+    await accountCodes.put({
+      root: codeRoot,
+      code: new Blob([new Uint8Array(code)]),
+    });
+
+    await foreignAccountCode.put({
+      accountId: accountId,
+      codeRoot: codeRoot,
+    });
+  } catch (error) {
+    console.error(`Error upserting code of foreign account: ${accountId}:`, error);
+    throw error;
+  }
+}
+
+// DELETE FUNCTIONS
+export async function removeSpendingPolicy(accountId) {
+  try {
+    await spendingPolicies.where("accountId").equals(accountId).delete();
+  } catch (error) {
+    console.error(`Error removing spending policy for account: ${accountId}:`, error);
+    throw error;
+  }
+}
+
 function uint8ArrayToBase64(bytes) {
   const binary = bytes.reduce(
     (acc, byte) => acc + String.fromCharCode(byte),
@@ -20,8 +20,12 @@ const Table = {
   AccountVaults: "accountVaults",
   AccountAuth: "accountAuth",
   Accounts: "accounts",
+  SpendingPolicies: "spendingPolicies",
+  ForeignAccountCode: "foreignAccountCode",
   Transactions: "transactions",
   TransactionScripts: "transactionScripts",
+  UnsubmittedTransactions: "unsubmittedTransactions",
+  RecurringPayments: "recurringPayments",
   InputNotes: "inputNotes",
   OutputNotes: "outputNotes",
   NotesScripts: "notesScripts",
@@ -44,8 +48,12 @@ db.version(1).stores({
     "vaultRoot",
     "accountHash"
   ),
+  [Table.SpendingPolicies]: indexes("accountId"),
+  [Table.ForeignAccountCode]: indexes("accountId"),
   [Table.Transactions]: indexes("id"),
   [Table.TransactionScripts]: indexes("scriptHash"),
+  [Table.UnsubmittedTransactions]: indexes("id"),
+  [Table.RecurringPayments]: indexes("id"),
   [Table.InputNotes]: indexes("noteId", "nullifier", "stateDiscriminant"),
   [Table.OutputNotes]: indexes(
     "noteId",
@@ -74,8 +82,12 @@ const accountStorages = db.table(Table.AccountStorage);
 const accountVaults = db.table(Table.AccountVaults);
 const accountAuths = db.table(Table.AccountAuth);
 const accounts = db.table(Table.Accounts);
+const spendingPolicies = db.table(Table.SpendingPolicies);
+const foreignAccountCode = db.table(Table.ForeignAccountCode);
 const transactions = db.table(Table.Transactions);
 const transactionScripts = db.table(Table.TransactionScripts);
+const unsubmittedTransactions = db.table(Table.UnsubmittedTransactions);
+const recurringPayments = db.table(Table.RecurringPayments);
 const inputNotes = db.table(Table.InputNotes);
 const outputNotes = db.table(Table.OutputNotes);
 const notesScripts = db.table(Table.NotesScripts);
@@ -91,8 +103,12 @@ export {
   accountVaults,
   accountAuths,
   accounts,
+  spendingPolicies,
+  foreignAccountCode,
   transactions,
   transactionScripts,
+  unsubmittedTransactions,
+  recurringPayments,
   inputNotes,
   outputNotes,
   notesScripts,
@@ -1,4 +1,9 @@
-import { transactions, transactionScripts } from "./schema.js";
+import {
+  transactions,
+  transactionScripts,
+  unsubmittedTransactions,
+  recurringPayments,
+} from "./schema.js";
 
 export async function getTransactions(filter) {
   let transactionRecords;
@@ -166,6 +171,143 @@ export async function insertProvenTransactionData(
   }
 }
 
+export async function getUnsubmittedTransactions() {
+  try {
+    const records = await unsubmittedTransactions.toArray();
+
+    return await Promise.all(records.map(processUnsubmittedTransaction));
+  } catch (err) {
+    console.error("Failed to get unsubmitted transactions: ", err);
+    throw err;
+  }
+}
+
+export async function getUnsubmittedTransaction(transactionId) {
+  try {
+    const record = await unsubmittedTransactions
+      .where("id")
+      .equals(transactionId)
+      .first();
+
+    if (!record) {
+      return null;
+    }
+
+    return await processUnsubmittedTransaction(record);
+  } catch (err) {
+    console.error("Failed to get unsubmitted transaction: ", err);
+    throw err;
+  }
+}
+
+async function processUnsubmittedTransaction(record) {
+  let transactionResultArrayBuffer =
+    await record.transactionResult.arrayBuffer();
+  let transactionResultBase64 = uint8ArrayToBase64(
+    new Uint8Array(transactionResultArrayBuffer)
+  );
+
+  let provenTransactionBase64 = null;
+  if (record.provenTransaction) {
+    let provenTransactionArrayBuffer =
+      await record.provenTransaction.arrayBuffer();
+    provenTransactionBase64 = uint8ArrayToBase64(
+      new Uint8Array(provenTransactionArrayBuffer)
+    );
+  }
+
+  return {
+    transaction_result: transactionResultBase64,
+    proven_transaction: provenTransactionBase64,
+    submission_attempts: record.submissionAttempts,
+    last_attempt_block: record.lastAttemptBlock
+      ? record.lastAttemptBlock
+      : null,
+  };
+}
+
+export async function upsertUnsubmittedTransaction(
+  transactionId,
+  accountId,
+  transactionResult,
+  provenTransaction,
+  submissionAttempts,
+  lastAttemptBlock
+) {
+  try {
+    let provenTransactionBlob = null;
+    if (provenTransaction) {
+      provenTransactionBlob = new Blob([new Uint8Array(provenTransaction)]);
+    }
+
+    const data = {
+      id: transactionId,
+      accountId: accountId,
+      transactionResult: new Blob([new Uint8Array(transactionResult)]),
+      provenTransaction: provenTransactionBlob,
+      submissionAttempts: submissionAttempts,
+      lastAttemptBlock: lastAttemptBlock ? lastAttemptBlock : null,
+    };
+
+    await unsubmittedTransactions.put(data);
+  } catch (err) {
+    console.error("Failed to upsert unsubmitted transaction: ", err);
+    throw err;
+  }
+}
+
+export async function removeUnsubmittedTransaction(transactionId) {
+  try {
+    await unsubmittedTransactions.where("id").equals(transactionId).delete();
+  } catch (err) {
+    console.error("Failed to remove unsubmitted transaction: ", err);
+    throw err;
+  }
+}
+
+export async function getRecurringPayments() {
+  try {
+    const records = await recurringPayments.orderBy("id").toArray();
+
+    return await Promise.all(
+      records.map(async (record) => {
+        let paymentArrayBuffer = await record.payment.arrayBuffer();
+
+        return {
+          payment: uint8ArrayToBase64(new Uint8Array(paymentArrayBuffer)),
+        };
+      })
+    );
+  } catch (err) {
+    console.error("Failed to get recurring payments: ", err);
+    throw err;
+  }
+}
+
+export async function upsertRecurringPayment(paymentId, accountId, payment) {
+  try {
+    const data = {
+      id: paymentId,
+      accountId: accountId,
+      payment: new Blob([new Uint8Array(payment)]),
+    };
+
+    await recurringPayments.put(data);
+  } catch (err) {
+    console.error("Failed to upsert recurring payment: ", err);
+    throw err;
+  }
+}
+
+export async function removeRecurringPayment(paymentId) {
+  try {
+    await recurringPayments.where("id").equals(paymentId).delete();
+  } catch (err) {
+    console.error("Failed to remove recurring payment: ", err);
+    throw err;
+  }
+}
+
 function uint8ArrayToBase64(bytes) {
   const binary = bytes.reduce(
     (acc, byte) => acc + String.fromCharCode(byte),
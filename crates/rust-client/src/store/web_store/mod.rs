@@ -1,9 +1,10 @@
 use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
 
 use miden_objects::{
-    accounts::{Account, AccountHeader, AccountId, AuthSecretKey},
+    accounts::{Account, AccountCode, AccountHeader, AccountId, AuthSecretKey},
     crypto::merkle::{InOrderIndex, MmrPeaks},
     notes::Nullifier,
+    transaction::TransactionId,
     BlockHeader, Digest, Word,
 };
 use tonic::async_trait;
@@ -16,7 +17,10 @@ use super::{
 };
 use crate::{
     sync::{NoteTagRecord, StateSyncUpdate},
-    transactions::{TransactionRecord, TransactionStoreUpdate},
+    transactions::{
+        RecurringPayment, SpendingPolicy, TransactionRecord, TransactionStoreUpdate,
+        UnsubmittedTransaction,
+    },
 };
 
 pub mod accounts;
@@ -78,6 +82,45 @@ impl Store for WebStore {
         self.apply_transaction(tx_update).await
     }
 
+    async fn get_unsubmitted_transactions(
+        &self,
+    ) -> Result<Vec<UnsubmittedTransaction>, StoreError> {
+        self.get_unsubmitted_transactions().await
+    }
+
+    async fn get_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<UnsubmittedTransaction>, StoreError> {
+        self.get_unsubmitted_transaction(transaction_id).await
+    }
+
+    async fn upsert_unsubmitted_transaction(
+        &self,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<(), StoreError> {
+        self.upsert_unsubmitted_transaction(transaction).await
+    }
+
+    async fn remove_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        self.remove_unsubmitted_transaction(transaction_id).await
+    }
+
+    async fn get_recurring_payments(&self) -> Result<Vec<RecurringPayment>, StoreError> {
+        self.get_recurring_payments().await
+    }
+
+    async fn upsert_recurring_payment(&self, payment: &RecurringPayment) -> Result<(), StoreError> {
+        self.upsert_recurring_payment(payment).await
+    }
+
+    async fn remove_recurring_payment(&self, payment_id: u32) -> Result<(), StoreError> {
+        self.remove_recurring_payment(payment_id).await
+    }
+
     // NOTES
     // --------------------------------------------------------------------------------------------
     async fn get_input_notes(
@@ -149,7 +192,7 @@ impl Store for WebStore {
         &self,
         account: &Account,
         account_seed: Option<Word>,
-        auth_info: &AuthSecretKey,
+        auth_info: Option<&AuthSecretKey>,
     ) -> Result<(), StoreError> {
         self.insert_account(account, account_seed, auth_info).await
     }
@@ -194,6 +237,40 @@ impl Store for WebStore {
         self.get_account_auth(account_id).await
     }
 
+    async fn get_spending_policy(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<SpendingPolicy>, StoreError> {
+        self.get_spending_policy(account_id).await
+    }
+
+    async fn upsert_spending_policy(
+        &self,
+        account_id: AccountId,
+        policy: &SpendingPolicy,
+    ) -> Result<(), StoreError> {
+        self.upsert_spending_policy(account_id, policy).await
+    }
+
+    async fn remove_spending_policy(&self, account_id: AccountId) -> Result<(), StoreError> {
+        self.remove_spending_policy(account_id).await
+    }
+
+    async fn get_foreign_account_code(
+        &self,
+        account_ids: Vec<AccountId>,
+    ) -> Result<BTreeMap<AccountId, AccountCode>, StoreError> {
+        self.get_foreign_account_code(account_ids).await
+    }
+
+    async fn upsert_foreign_account_code(
+        &self,
+        account_id: AccountId,
+        code: AccountCode,
+    ) -> Result<(), StoreError> {
+        self.upsert_foreign_account_code(account_id, code).await
+    }
+
     async fn get_unspent_input_note_nullifiers(&self) -> Result<Vec<Nullifier>, StoreError> {
         self.get_unspent_input_note_nullifiers().await
     }
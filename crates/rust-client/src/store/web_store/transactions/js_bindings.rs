@@ -31,4 +31,42 @@ extern "C" {
         block_num: String,
         committed: Option<String>,
     ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getUnsubmittedTransactions)]
+    pub fn idxdb_get_unsubmitted_transactions() -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getUnsubmittedTransaction)]
+    pub fn idxdb_get_unsubmitted_transaction(transaction_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = getRecurringPayments)]
+    pub fn idxdb_get_recurring_payments() -> js_sys::Promise;
+
+    // INSERTS
+    // ================================================================================================
+
+    #[wasm_bindgen(js_name = upsertUnsubmittedTransaction)]
+    pub fn idxdb_upsert_unsubmitted_transaction(
+        transaction_id: String,
+        account_id: String,
+        transaction_result: Vec<u8>,
+        proven_transaction: Option<Vec<u8>>,
+        submission_attempts: String,
+        last_attempt_block: Option<String>,
+    ) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = upsertRecurringPayment)]
+    pub fn idxdb_upsert_recurring_payment(
+        payment_id: u32,
+        account_id: String,
+        payment: Vec<u8>,
+    ) -> js_sys::Promise;
+
+    // DELETES
+    // ================================================================================================
+
+    #[wasm_bindgen(js_name = removeUnsubmittedTransaction)]
+    pub fn idxdb_remove_unsubmitted_transaction(transaction_id: String) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = removeRecurringPayment)]
+    pub fn idxdb_remove_recurring_payment(payment_id: u32) -> js_sys::Promise;
 }
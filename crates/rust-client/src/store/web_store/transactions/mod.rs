@@ -2,17 +2,20 @@ use alloc::{string::ToString, vec::Vec};
 
 use miden_objects::{
     accounts::AccountId,
-    transaction::{OutputNotes, TransactionScript},
+    transaction::{OutputNotes, ProvenTransaction, TransactionId, TransactionScript},
     Digest,
 };
-use miden_tx::utils::Deserializable;
+use miden_tx::utils::{Deserializable, Serializable};
 use serde_wasm_bindgen::from_value;
 use wasm_bindgen_futures::*;
 
 use super::{notes::utils::apply_note_updates_tx, WebStore};
 use crate::{
     store::{StoreError, TransactionFilter},
-    transactions::{TransactionRecord, TransactionStatus, TransactionStoreUpdate},
+    transactions::{
+        RecurringPayment, TransactionRecord, TransactionResult, TransactionStatus,
+        TransactionStoreUpdate, UnsubmittedTransaction,
+    },
 };
 
 mod js_bindings;
@@ -95,6 +98,8 @@ impl WebStore {
     ) -> Result<(), StoreError> {
         // Transaction Data
         insert_proven_transaction_data(tx_update.executed_transaction()).await?;
+        self.remove_unsubmitted_transaction(tx_update.executed_transaction().id())
+            .await?;
 
         // Account Data
         update_account(tx_update.updated_account()).await.unwrap();
@@ -108,4 +113,107 @@ impl WebStore {
 
         Ok(())
     }
+
+    pub async fn get_unsubmitted_transactions(
+        &self,
+    ) -> Result<Vec<UnsubmittedTransaction>, StoreError> {
+        let promise = idxdb_get_unsubmitted_transactions();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let transactions_idxdb: Vec<UnsubmittedTransactionIdxdbObject> =
+            from_value(js_value).unwrap();
+
+        transactions_idxdb.into_iter().map(parse_unsubmitted_transaction).collect()
+    }
+
+    pub async fn get_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<UnsubmittedTransaction>, StoreError> {
+        let promise = idxdb_get_unsubmitted_transaction(transaction_id.to_string());
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let transaction_idxdb: Option<UnsubmittedTransactionIdxdbObject> =
+            from_value(js_value).unwrap();
+
+        transaction_idxdb.map(parse_unsubmitted_transaction).transpose()
+    }
+
+    pub async fn upsert_unsubmitted_transaction(
+        &self,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_upsert_unsubmitted_transaction(
+            transaction.id().to_string(),
+            transaction.account_id().to_string(),
+            transaction.tx_result().to_bytes(),
+            transaction.proven_transaction().map(|proven| proven.to_bytes()),
+            transaction.submission_attempts().to_string(),
+            transaction.last_attempt_block().map(|block| block.to_string()),
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub async fn remove_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_remove_unsubmitted_transaction(transaction_id.to_string());
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub async fn get_recurring_payments(&self) -> Result<Vec<RecurringPayment>, StoreError> {
+        let promise = idxdb_get_recurring_payments();
+        let js_value = JsFuture::from(promise).await.unwrap();
+        let payments_idxdb: Vec<RecurringPaymentIdxdbObject> = from_value(js_value).unwrap();
+
+        payments_idxdb
+            .into_iter()
+            .map(|payment_idxdb| Ok(RecurringPayment::read_from_bytes(&payment_idxdb.payment)?))
+            .collect()
+    }
+
+    pub async fn upsert_recurring_payment(
+        &self,
+        payment: &RecurringPayment,
+    ) -> Result<(), StoreError> {
+        let promise = idxdb_upsert_recurring_payment(
+            payment.id(),
+            payment.payment_data().account_id().to_string(),
+            payment.to_bytes(),
+        );
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+
+    pub async fn remove_recurring_payment(&self, payment_id: u32) -> Result<(), StoreError> {
+        let promise = idxdb_remove_recurring_payment(payment_id);
+        JsFuture::from(promise).await.unwrap();
+
+        Ok(())
+    }
+}
+
+/// Parses an unsubmitted transaction from its IndexedDB representation.
+fn parse_unsubmitted_transaction(
+    tx_idxdb: UnsubmittedTransactionIdxdbObject,
+) -> Result<UnsubmittedTransaction, StoreError> {
+    let tx_result = TransactionResult::read_from_bytes(&tx_idxdb.transaction_result)?;
+    let proven_transaction = tx_idxdb
+        .proven_transaction
+        .map(|proven| ProvenTransaction::read_from_bytes(&proven))
+        .transpose()?;
+
+    let submission_attempts = tx_idxdb.submission_attempts.parse::<u32>().unwrap();
+    let last_attempt_block = tx_idxdb.last_attempt_block.map(|block| block.parse::<u32>().unwrap());
+
+    Ok(UnsubmittedTransaction::from_parts(
+        tx_result,
+        proven_transaction,
+        submission_attempts,
+        last_attempt_block,
+    ))
 }
@@ -21,6 +21,22 @@ pub struct TransactionIdxdbObject {
     pub commit_height: Option<String>, // usually Option<u32>
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct UnsubmittedTransactionIdxdbObject {
+    #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
+    pub transaction_result: Vec<u8>,
+    #[serde(deserialize_with = "base64_to_vec_u8_optional", default)]
+    pub proven_transaction: Option<Vec<u8>>,
+    pub submission_attempts: String,        // usually u32
+    pub last_attempt_block: Option<String>, // usually Option<u32>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RecurringPaymentIdxdbObject {
+    #[serde(deserialize_with = "base64_to_vec_u8_required", default)]
+    pub payment: Vec<u8>,
+}
+
 fn base64_to_vec_u8_required<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
     D: Deserializer<'de>,
@@ -17,6 +17,7 @@ use tracing::info;
 use crate::{
     notes::NoteUpdates,
     rpc::{
+        verification::{verify_block_header, verify_note_inclusion, verify_nullifier_updates},
         AccountDetails, CommittedNote, NoteDetails, NullifierUpdate, RpcError, TransactionUpdate,
     },
     store::{
@@ -153,6 +154,7 @@ impl<R: FeltRng> Client<R> {
     /// Returns the block number the client has been synced to.
     pub async fn sync_state(&mut self) -> Result<SyncSummary, ClientError> {
         self.ensure_genesis_in_place().await?;
+        let starting_block_num = self.store.get_sync_height().await?;
         let mut total_sync_summary = SyncSummary::new_empty(0);
         loop {
             let response = self.sync_state_once().await?;
@@ -165,6 +167,11 @@ impl<R: FeltRng> Client<R> {
         }
         self.update_mmr_data().await?;
 
+        // Foreign account data is reused until the client syncs to a new block
+        if total_sync_summary.block_num != starting_block_num {
+            self.foreign_account_cache.clear();
+        }
+
         Ok(total_sync_summary)
     }
 
@@ -205,6 +212,31 @@ impl<R: FeltRng> Client<R> {
             return Ok(SyncStatus::SyncedToLastBlock(SyncSummary::new_empty(current_block_num)));
         }
 
+        // Build PartialMmr with current data and apply updates
+        let (new_peaks, new_authentication_nodes) = {
+            let current_partial_mmr = self.build_current_partial_mmr(false).await?;
+
+            let (current_block, has_relevant_notes) =
+                self.store.get_block_header_by_num(current_block_num).await?;
+
+            apply_mmr_changes(
+                current_partial_mmr,
+                response.mmr_delta,
+                current_block,
+                has_relevant_notes,
+            )?
+        };
+
+        // The updated MMR authenticates the received block header, which is then used to verify
+        // the rest of the response
+        verify_block_header(&response.block_header, &new_peaks)?;
+        verify_nullifier_updates(
+            &response.nullifiers,
+            &nullifiers_tags,
+            current_block_num,
+            &response.block_header,
+        )?;
+
         let (committed_note_updates, tags_to_remove) = self
             .committed_note_updates(response.note_inclusions, &response.block_header)
             .await?;
@@ -229,21 +261,6 @@ impl<R: FeltRng> Client<R> {
         self.validate_local_account_hashes(&response.account_hash_updates, &offchain_accounts)
             .await?;
 
-        // Build PartialMmr with current data and apply updates
-        let (new_peaks, new_authentication_nodes) = {
-            let current_partial_mmr = self.build_current_partial_mmr(false).await?;
-
-            let (current_block, has_relevant_notes) =
-                self.store.get_block_header_by_num(current_block_num).await?;
-
-            apply_mmr_changes(
-                current_partial_mmr,
-                response.mmr_delta,
-                current_block,
-                has_relevant_notes,
-            )?
-        };
-
         // Store summary to return later
         let sync_summary = SyncSummary::new(
             response.block_header.block_num(),
@@ -317,6 +334,14 @@ impl<R: FeltRng> Client<R> {
         let mut removed_tags = vec![];
 
         for committed_note in committed_notes {
+            verify_note_inclusion(
+                *committed_note.note_id(),
+                &committed_note.metadata(),
+                committed_note.note_index(),
+                committed_note.merkle_path(),
+                block_header,
+            )?;
+
             let inclusion_proof = NoteInclusionProof::new(
                 block_header.block_num(),
                 committed_note.note_index(),
@@ -496,6 +521,13 @@ impl<R: FeltRng> Client<R> {
                 },
                 NoteDetails::Public(note, inclusion_proof) => {
                     info!("Retrieved details for Note ID {}.", note.id());
+                    verify_note_inclusion(
+                        note.id(),
+                        note.metadata(),
+                        inclusion_proof.note_index,
+                        &inclusion_proof.merkle_path,
+                        block_header,
+                    )?;
                     let inclusion_proof = NoteInclusionProof::new(
                         block_header.block_num(),
                         inclusion_proof.note_index,
@@ -1,8 +1,8 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, string::ToString, sync::Arc, vec::Vec};
 
 // TESTS
 // ================================================================================================
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{transaction::TransactionKernel, AuthScheme};
 use miden_objects::{
     accounts::{
         account_id::testing::{
@@ -10,21 +10,42 @@ use miden_objects::{
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
         },
-        Account, AccountCode, AccountHeader, AccountId, AccountStorageMode, AuthSecretKey,
+        Account, AccountCode, AccountDelta, AccountDeltaError, AccountHeader, AccountId,
+        AccountStorageMode, AccountType, AuthSecretKey,
     },
-    assets::{FungibleAsset, TokenSymbol},
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails, TokenSymbol},
     crypto::dsa::rpo_falcon512::SecretKey,
-    notes::{NoteFile, NoteTag},
-    Felt, FieldElement, Word,
+    notes::{NoteFile, NoteId, NoteTag},
+    Digest, Felt, FieldElement, Word,
+};
+use miden_tx::{
+    utils::{Deserializable, Serializable},
+    TransactionProverError,
 };
-use miden_tx::utils::{Deserializable, Serializable};
 
 use crate::{
     accounts::AccountTemplate,
-    mock::create_test_client,
-    rpc::NodeRpcClient,
-    store::{InputNoteRecord, NoteFilter, Store},
-    transactions::TransactionRequest,
+    auth::{
+        AirGappedAuthenticator, SigningRequest, SigningResponse, TransactionAuthenticator,
+        REQUEST_FILE_EXTENSION, SIGNATURE_FILE_EXTENSION,
+    },
+    mock::{create_test_client, create_test_client_with_authenticator},
+    rpc::{
+        fetch_notes_in_chunks,
+        verification::{verify_block_header, verify_note_inclusion_proof},
+        InstrumentedRpcClient, NodeRpcClient, NodeRpcClientEndpoint, NoteDetails,
+        NoteInclusionDetails, RpcCallOutcome, RpcError, RpcMetrics,
+    },
+    shared::SharedClient,
+    store::{InputNoteRecord, NoteFilter, Store, TransactionFilter},
+    transactions::{
+        DebugDump, FallbackTransactionProver, LocalTransactionProver, PaymentOutcome,
+        PaymentSchedule, PaymentTransactionData, ProvenTransaction, ProverHealth, ProvingOptions,
+        ProvingProgress, SpendingLimit, SpendingPolicy, SpendingPolicyViolation,
+        TransactionDebugOutput, TransactionPhase, TransactionProver, TransactionRequest,
+        TransactionRequestError, TransactionStatus, TransactionWitness,
+    },
+    ClientError,
 };
 
 #[tokio::test]
@@ -407,6 +428,43 @@ async fn test_mint_transaction() {
     assert!(transaction.executed_transaction().account_delta().nonce().is_some());
 }
 
+#[tokio::test]
+async fn test_mint_non_fungible_asset() {
+    // generate test client with a random store name
+    let (mut client, _rpc_api) = create_test_client().await;
+
+    // Faucet account generation
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::NonFungibleFaucet {
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    assert_eq!(faucet.account_type(), AccountType::NonFungibleFaucet);
+
+    client.sync_state().await.unwrap();
+
+    let asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet.id(), vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap();
+    let transaction_request = TransactionRequest::mint_non_fungible_asset(
+        asset,
+        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+
+    assert!(transaction.executed_transaction().account_delta().nonce().is_some());
+    let created_note = transaction.created_notes().get_note(0);
+    assert!(created_note
+        .assets()
+        .is_some_and(|assets| assets.iter().eq([&Asset::NonFungible(asset)])));
+}
+
 #[tokio::test]
 async fn test_get_output_notes() {
     // generate test client with a random store name
@@ -444,6 +502,258 @@ async fn test_get_output_notes() {
     assert!(!client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_swap_orders_only_track_swap_notes() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    client.submit_transaction(transaction).await.unwrap();
+
+    // The minted P2ID note is an output note, but not a SWAP order
+    let output_notes = client.get_output_notes(NoteFilter::All).await.unwrap();
+    assert_eq!(output_notes.len(), 1);
+    assert!(client.get_swap_orders().await.unwrap().is_empty());
+
+    let note_id = output_notes[0].id();
+    assert!(matches!(
+        client.cancel_swap(note_id).await,
+        Err(ClientError::SwapOrderNotFound(id)) if id == note_id
+    ));
+}
+
+#[tokio::test]
+async fn test_transaction_stages() {
+    let (mut client, _rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let transaction_id = client.save_transaction(transaction).await.unwrap();
+
+    // The stored transaction is not applied to the local state until it's submitted
+    let unsubmitted = client.get_unsubmitted_transaction(transaction_id).await.unwrap();
+    assert_eq!(unsubmitted.status(), TransactionStatus::Executed);
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+    assert!(client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+
+    let witness = client.export_transaction_witness(transaction_id).await.unwrap();
+    assert!(TransactionWitness::read_from_bytes(&witness.to_bytes()).is_ok());
+
+    let proven_transaction = client.prove_stored_transaction(transaction_id).await.unwrap();
+    assert_eq!(proven_transaction.id(), transaction_id);
+    let unsubmitted = client.get_unsubmitted_transaction(transaction_id).await.unwrap();
+    assert_eq!(unsubmitted.status(), TransactionStatus::Proven);
+
+    // Importing the proof again replaces the stored one
+    client.import_proven_transaction(proven_transaction.clone()).await.unwrap();
+
+    client.submit_stored_transaction(transaction_id).await.unwrap();
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+
+    let transactions = client.get_transactions(TransactionFilter::All).await.unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].transaction_status, TransactionStatus::Pending);
+    assert!(!client.get_output_notes(NoteFilter::All).await.unwrap().is_empty());
+
+    // Proofs can't be imported for transactions that are not stored
+    assert!(matches!(
+        client.import_proven_transaction(proven_transaction).await,
+        Err(ClientError::UnsubmittedTransactionNotFound(id)) if id == transaction_id
+    ));
+}
+
+#[tokio::test]
+async fn test_queued_transaction_resubmission() {
+    let (mut client, rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let transaction_id = transaction.executed_transaction().id();
+
+    // Submitting while the node is unreachable queues the proven transaction
+    rpc_api
+        .submission_unavailable
+        .store(true, core::sync::atomic::Ordering::Relaxed);
+    assert!(matches!(
+        client.submit_transaction(transaction).await,
+        Err(ClientError::RpcError(RpcError::ConnectionError(_)))
+    ));
+
+    let queued = client.get_unsubmitted_transaction(transaction_id).await.unwrap();
+    assert_eq!(queued.status(), TransactionStatus::Queued);
+    assert_eq!(queued.submission_attempts(), 1);
+    let sync_height = client.get_sync_height().await.unwrap();
+    assert_eq!(queued.next_attempt_block(), Some(sync_height + 1));
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+
+    // The transaction isn't resubmitted until the chain moves past the backoff
+    rpc_api
+        .submission_unavailable
+        .store(false, core::sync::atomic::Ordering::Relaxed);
+    assert!(client.resubmit_queued_transactions().await.unwrap().is_empty());
+
+    client.submit_stored_transaction(transaction_id).await.unwrap();
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+    assert_eq!(client.get_transactions(TransactionFilter::All).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_timed_out_submission_is_applied_once_committed() {
+    let (mut client, rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let transaction_id = transaction.executed_transaction().id();
+
+    // The node accepts the transaction but the response times out, so it stays queued
+    rpc_api.submission_timed_out.store(true, core::sync::atomic::Ordering::Relaxed);
+    assert!(matches!(
+        client.submit_transaction(transaction).await,
+        Err(ClientError::RpcError(RpcError::ConnectionError(_)))
+    ));
+
+    let queued = client.get_unsubmitted_transaction(transaction_id).await.unwrap();
+    assert_eq!(queued.status(), TransactionStatus::Queued);
+    assert!(client.get_transactions(TransactionFilter::All).await.unwrap().is_empty());
+
+    // The node rejects the resubmission as a duplicate, and its account state shows the
+    // transaction was committed, so it's applied instead of discarded
+    rpc_api.submission_timed_out.store(false, core::sync::atomic::Ordering::Relaxed);
+    client.submit_stored_transaction(transaction_id).await.unwrap();
+
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+    let transactions = client.get_transactions(TransactionFilter::All).await.unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].id, transaction_id);
+}
+
+#[tokio::test]
+async fn test_expired_transaction_is_kept_while_node_is_unreachable() {
+    let (mut client, mut rpc_api) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap()
+    .with_expiration_delta(1)
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let transaction_id = transaction.executed_transaction().id();
+
+    // The node accepts the transaction but the response times out, so it stays queued
+    rpc_api.submission_timed_out.store(true, core::sync::atomic::Ordering::Relaxed);
+    assert!(client.submit_transaction(transaction).await.is_err());
+
+    // The chain moves past the expiration block while the node's account state can't be
+    // retrieved
+    rpc_api.seal_block(vec![], vec![]);
+    client.rpc_api = Box::new(rpc_api.clone());
+    rpc_api
+        .account_updates_unavailable
+        .store(true, core::sync::atomic::Ordering::Relaxed);
+    client.sync_state().await.unwrap();
+
+    // The transaction may have been committed, so it isn't discarded
+    assert!(client.resubmit_queued_transactions().await.unwrap().is_empty());
+    let queued = client.get_unsubmitted_transaction(transaction_id).await.unwrap();
+    assert_eq!(queued.status(), TransactionStatus::Queued);
+
+    // Once the node is reachable, its state shows the transaction was committed
+    rpc_api
+        .account_updates_unavailable
+        .store(false, core::sync::atomic::Ordering::Relaxed);
+    assert_eq!(client.resubmit_queued_transactions().await.unwrap(), vec![transaction_id]);
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+    assert_eq!(client.get_transactions(TransactionFilter::All).await.unwrap().len(), 1);
+}
+
 #[tokio::test]
 async fn test_import_note_validation() {
     // generate test client
@@ -507,3 +817,1049 @@ async fn test_transaction_request_expiration() {
 
     assert_eq!(tx_outputs.expiration_block_num, current_height + 5);
 }
+
+#[tokio::test]
+async fn test_transaction_chain() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    // The faucet mints twice, and the wallet consumes the note created by the second mint before
+    // any of the transactions is committed
+    let first_mint = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5).unwrap(),
+        wallet.id(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let second_mint = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 10).unwrap(),
+        wallet.id(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let minted_note_id = second_mint.expected_output_notes().next().unwrap().id();
+    let consume = TransactionRequest::consume_notes(vec![minted_note_id]);
+
+    let tx_results = client
+        .new_transaction_chain(vec![
+            (faucet.id(), first_mint),
+            (faucet.id(), second_mint),
+            (wallet.id(), consume),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(tx_results.len(), 3);
+
+    // The second mint is executed against the faucet state left by the first one
+    assert_eq!(
+        tx_results[1].executed_transaction().initial_account().hash(),
+        tx_results[0].executed_transaction().final_account().hash()
+    );
+    assert!(tx_results[2].consumed_notes().iter().any(|note| note.id() == minted_note_id));
+
+    // Executing the chain doesn't store the chained note
+    assert!(client
+        .get_input_notes(NoteFilter::List(vec![minted_note_id]))
+        .await
+        .unwrap()
+        .is_empty());
+
+    client.submit_transaction_chain(tx_results).await.unwrap();
+
+    let (faucet, _) = client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(faucet.nonce().as_int(), 2);
+    let (wallet, _) = client.get_account(wallet.id()).await.unwrap();
+    assert_eq!(wallet.vault().get_balance(faucet.id()).unwrap(), 10);
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_prove_transactions() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mut tx_results = Vec::new();
+    for token_symbol in ["AAA", "BBB", "CCC"] {
+        let (faucet, _seed) = client
+            .new_account(AccountTemplate::FungibleFaucet {
+                token_symbol: token_symbol.try_into().unwrap(),
+                decimals: 3,
+                max_supply: 10000,
+                storage_mode: AccountStorageMode::Private,
+            })
+            .await
+            .unwrap();
+
+        let transaction_request = TransactionRequest::mint_fungible_asset(
+            FungibleAsset::new(faucet.id(), 5).unwrap(),
+            AccountId::from_hex("0x168187d729b31a84").unwrap(),
+            miden_objects::notes::NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+        tx_results.push(client.new_transaction(faucet.id(), transaction_request).await.unwrap());
+    }
+    let transaction_ids: Vec<_> = tx_results
+        .iter()
+        .map(|tx_result| tx_result.executed_transaction().id())
+        .collect();
+
+    let mut progress = Vec::new();
+    let results = client
+        .prove_transactions(tx_results, 2, |update| progress.push(update))
+        .await
+        .unwrap();
+
+    // Results are returned in the order the transactions were provided
+    assert_eq!(results.iter().map(|(id, _)| *id).collect::<Vec<_>>(), transaction_ids);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+
+    let proven = progress
+        .iter()
+        .filter_map(|update| match update {
+            ProvingProgress::Proven { completed, total, .. } => Some((*completed, *total)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(proven, vec![(1, 3), (2, 3), (3, 3)]);
+
+    // Transactions are submitted in order once all of them were proven
+    let submitted = progress
+        .iter()
+        .filter_map(|update| match update {
+            ProvingProgress::Submitted { transaction_id } => Some(*transaction_id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(submitted, transaction_ids);
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_prove_transactions_locally() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let mut tx_results = Vec::new();
+    for token_symbol in ["AAA", "BBB"] {
+        let (faucet, _seed) = client
+            .new_account(AccountTemplate::FungibleFaucet {
+                token_symbol: token_symbol.try_into().unwrap(),
+                decimals: 3,
+                max_supply: 10000,
+                storage_mode: AccountStorageMode::Private,
+            })
+            .await
+            .unwrap();
+
+        let transaction_request = TransactionRequest::mint_fungible_asset(
+            FungibleAsset::new(faucet.id(), 5).unwrap(),
+            AccountId::from_hex("0x168187d729b31a84").unwrap(),
+            miden_objects::notes::NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+        tx_results.push(client.new_transaction(faucet.id(), transaction_request).await.unwrap());
+    }
+    let transaction_ids: Vec<_> = tx_results
+        .iter()
+        .map(|tx_result| tx_result.executed_transaction().id())
+        .collect();
+
+    let mut proven = 0;
+    let results = client
+        .prove_transactions_locally(tx_results, 2, ProvingOptions::default(), |update| {
+            if matches!(update, ProvingProgress::Proven { .. }) {
+                proven += 1;
+            }
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(proven, 2);
+    assert_eq!(results.iter().map(|(id, _)| *id).collect::<Vec<_>>(), transaction_ids);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_prove_transactions_rolls_back_stored_batch() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let policy = SpendingPolicy::new()
+        .with_limit(faucet.id(), SpendingLimit { per_transaction: None, daily: Some(100) });
+    client.set_spending_policy(faucet.id(), policy).await.unwrap();
+
+    // Each transaction is allowed on its own, but not both of them on the same day
+    let mut tx_results = Vec::new();
+    for _ in 0..2 {
+        let transaction_request = TransactionRequest::mint_fungible_asset(
+            FungibleAsset::new(faucet.id(), 60).unwrap(),
+            AccountId::from_hex("0x168187d729b31a84").unwrap(),
+            miden_objects::notes::NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+        tx_results.push(client.new_transaction(faucet.id(), transaction_request).await.unwrap());
+    }
+
+    assert!(matches!(
+        client.prove_transactions(tx_results, 2, |_| {}).await,
+        Err(ClientError::SpendingPolicyViolation(
+            _,
+            SpendingPolicyViolation::DailyLimitExceeded { amount: 60, spent: 60, limit: 100, .. }
+        ))
+    ));
+
+    // The transaction stored before the failure is removed and its spending released
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+    let daily_spending = client.get_daily_spending(faucet.id()).await.unwrap();
+    assert_eq!(daily_spending.get(&faucet.id()).copied().unwrap_or_default(), 0);
+}
+
+#[tokio::test]
+async fn test_faucet_request_validation() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let target_id = AccountId::from_hex("0x168187d729b31a84").unwrap();
+
+    // Minting up to the maximum supply is allowed
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 10000).unwrap(),
+        target_id,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    client.validate_request(faucet.id(), &transaction_request).await.unwrap();
+
+    // Minting beyond the maximum supply is rejected
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 10001).unwrap(),
+        target_id,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    assert!(matches!(
+        client.new_transaction(faucet.id(), transaction_request).await,
+        Err(ClientError::TransactionRequestError(
+            TransactionRequestError::MaxSupplyExceeded {
+                requested: 10001,
+                issued: 0,
+                max_supply: 10000,
+                ..
+            }
+        ))
+    ));
+
+    // Distributing assets issued by another faucet is rejected
+    let foreign_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(foreign_faucet_id, 5).unwrap(),
+        target_id,
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    assert!(matches!(
+        client.validate_request(faucet.id(), &transaction_request).await,
+        Err(ClientError::TransactionRequestError(TransactionRequestError::ForeignFaucetAsset {
+            asset_faucet_id,
+            ..
+        })) if asset_faucet_id == foreign_faucet_id
+    ));
+}
+
+#[tokio::test]
+async fn test_rpc_response_verification() {
+    let (_, rpc_api) = create_test_client().await;
+
+    // Note inclusion proofs are checked against the note root of the block they reference
+    let note = rpc_api.get_note_at(1);
+    let inclusion_proof = note.proof().unwrap();
+    let block_num = inclusion_proof.location().block_num();
+    let block_header = rpc_api.blocks[block_num as usize].header();
+
+    verify_note_inclusion_proof(note.id(), note.note().metadata(), inclusion_proof, &block_header)
+        .unwrap();
+
+    let other_note = rpc_api.get_note_at(0);
+    assert!(verify_note_inclusion_proof(
+        other_note.id(),
+        note.note().metadata(),
+        inclusion_proof,
+        &block_header,
+    )
+    .is_err());
+
+    // Block headers are checked against the MMR of all previous blocks
+    let mmr_peaks = rpc_api.get_mmr().peaks(block_num as usize).unwrap();
+    verify_block_header(&block_header, &mmr_peaks).unwrap();
+
+    let previous_block_header = rpc_api.blocks[block_num as usize - 1].header();
+    assert!(verify_block_header(&previous_block_header, &mmr_peaks).is_err());
+}
+
+#[tokio::test]
+async fn test_fetch_notes_in_chunks() {
+    let (_, rpc_api) = create_test_client().await;
+    let template_note = rpc_api.get_note_at(0);
+    let metadata = *template_note.note().metadata();
+    let inclusion_proof = template_note.proof().unwrap().clone();
+    let note_details = |note_id: NoteId| {
+        let inclusion_details = NoteInclusionDetails::new(
+            inclusion_proof.location().block_num(),
+            inclusion_proof.location().node_index_in_block(),
+            inclusion_proof.note_path().clone(),
+        );
+        NoteDetails::Private(note_id, metadata, inclusion_details)
+    };
+
+    let note_ids: Vec<NoteId> =
+        (0..7u64).map(|i| NoteId::from(Digest::from([Felt::new(i); 4]))).collect();
+    let failing_note_id = note_ids[4];
+
+    // The node answers each chunk in reverse order, and fails the chunk with `failing_note_id`
+    let requested_chunks = std::sync::Mutex::new(Vec::new());
+    let fetch_chunk = |chunk: Vec<NoteId>| {
+        requested_chunks.lock().unwrap().push(chunk.clone());
+        let result: Result<Vec<NoteDetails>, RpcError> = if chunk.contains(&failing_note_id) {
+            Err(RpcError::ConnectionError("chunk failed".to_string()))
+        } else {
+            Ok(chunk.iter().rev().map(|note_id| note_details(*note_id)).collect())
+        };
+        async move { result }
+    };
+
+    // Duplicated IDs are requested once, in chunks of at most `chunk_size` IDs, and the notes
+    // follow the order of the request
+    let requested_ids = [note_ids[3], note_ids[0], note_ids[1], note_ids[3], note_ids[2]];
+    let notes = fetch_notes_in_chunks(&requested_ids, 2, 2, &fetch_chunk).await.unwrap();
+    assert_eq!(
+        notes.iter().map(NoteDetails::id).collect::<Vec<_>>(),
+        vec![note_ids[3], note_ids[0], note_ids[1], note_ids[2]]
+    );
+    assert_eq!(
+        *requested_chunks.lock().unwrap(),
+        vec![vec![note_ids[3], note_ids[0]], vec![note_ids[1], note_ids[2]]]
+    );
+
+    // Every ID of a failed chunk is reported, and only those
+    requested_chunks.lock().unwrap().clear();
+    let requested_ids = [note_ids[0], note_ids[1], note_ids[4], note_ids[5], note_ids[6]];
+    match fetch_notes_in_chunks(&requested_ids, 2, 1, &fetch_chunk).await {
+        Err(RpcError::NotesByIdChunkError(failed_note_ids, _)) => {
+            assert_eq!(failed_note_ids, vec![note_ids[4], note_ids[5]])
+        },
+        _ => panic!("Expected a NotesByIdChunkError"),
+    }
+    assert_eq!(requested_chunks.lock().unwrap().len(), 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_shared_client() {
+    let shared_client =
+        SharedClient::new(|| async { Ok(create_test_client().await.0) }).await.unwrap();
+    assert_eq!(shared_client.get_sync_height().await.unwrap(), 0);
+
+    // Handles can be moved into tasks spawned on other threads
+    let tasks: Vec<_> = (0..4)
+        .map(|_| {
+            let shared_client = shared_client.clone();
+            tokio::spawn(async move { shared_client.sync_state().await.map(|_| ()) })
+        })
+        .collect();
+
+    for task in tasks {
+        task.await.unwrap().unwrap();
+    }
+
+    let sync_height = shared_client.get_sync_height().await.unwrap();
+    assert!(sync_height > 0);
+
+    let account_count = shared_client
+        .with_client(|client| {
+            Box::pin(async move { client.get_account_headers().await.map(|headers| headers.len()) })
+        })
+        .await
+        .unwrap();
+    assert_eq!(account_count, 0);
+}
+
+#[tokio::test]
+async fn test_instrumented_rpc_client() {
+    #[derive(Default)]
+    struct RecordingMetrics {
+        calls: std::sync::Mutex<Vec<(NodeRpcClientEndpoint, usize, bool)>>,
+    }
+
+    impl RpcMetrics for RecordingMetrics {
+        fn record(
+            &self,
+            endpoint: NodeRpcClientEndpoint,
+            request_size: usize,
+            _duration: core::time::Duration,
+            outcome: RpcCallOutcome<'_>,
+        ) {
+            let success = matches!(outcome, RpcCallOutcome::Success);
+            self.calls.lock().unwrap().push((endpoint, request_size, success));
+        }
+    }
+
+    let (_, rpc_api) = create_test_client().await;
+    let metrics = Arc::new(RecordingMetrics::default());
+    let mut rpc_api = InstrumentedRpcClient::new(rpc_api).with_metrics(metrics.clone());
+
+    rpc_api.get_block_header_by_number(None, false).await.unwrap();
+
+    let note_ids = [rpc_api.inner().get_note_at(0).id(), rpc_api.inner().get_note_at(1).id()];
+    rpc_api.get_notes_by_id(&note_ids).await.unwrap();
+
+    assert_eq!(
+        *metrics.calls.lock().unwrap(),
+        vec![
+            (NodeRpcClientEndpoint::GetBlockHeaderByNumber, 1, true),
+            (NodeRpcClientEndpoint::GetNotesById, 2, true),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_transaction_summary() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let current_height = client.get_sync_height().await.unwrap();
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let asset = FungibleAsset::new(faucet.id(), 5u64).unwrap();
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        asset,
+        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap()
+    .with_expiration_delta(5)
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let summary = transaction.summary();
+
+    assert_eq!(summary.transaction_id(), transaction.executed_transaction().id());
+    assert_eq!(summary.account_id(), faucet.id());
+    assert_eq!(summary.expiration_block_num(), current_height + 5);
+    assert!(summary.consumed_notes().is_empty());
+    assert!(summary.final_nonce().is_some());
+
+    let created_notes = summary.created_notes();
+    assert_eq!(created_notes.len(), 1);
+    assert_eq!(created_notes[0].note_type, miden_objects::notes::NoteType::Private);
+    assert_eq!(created_notes[0].assets, Some(vec![asset.into()]));
+}
+
+#[tokio::test]
+async fn test_transaction_execution_stats() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let stats = transaction.stats();
+
+    // The mint runs a transaction script and consumes no notes
+    assert!(stats.prologue_cycles() > 0);
+    assert!(stats.tx_script_cycles() > 0);
+    assert!(stats.epilogue_cycles() > 0);
+    assert!(stats.note_execution_cycles().is_empty());
+
+    assert_eq!(
+        stats.total_cycles(),
+        stats.prologue_cycles()
+            + stats.notes_processing_cycles()
+            + stats.tx_script_cycles()
+            + stats.epilogue_cycles()
+    );
+    assert!(stats.trace_length().is_power_of_two());
+    assert!(stats.trace_length() >= stats.total_cycles());
+}
+
+#[tokio::test]
+async fn test_transaction_debug_output() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let script = "
+        use.miden::contracts::auth::basic->auth_tx
+        begin
+            push.3.2.1
+            debug.stack.3
+            drop drop drop
+            call.auth_tx::auth_tx_rpo_falcon512
+        end";
+    let tx_script = client.compile_tx_script(vec![], script).unwrap();
+    let transaction_request = TransactionRequest::new().with_custom_script(tx_script).unwrap();
+
+    let mut debug_output = TransactionDebugOutput::default();
+    client
+        .new_transaction_with_debug_output(faucet.id(), transaction_request, &mut debug_output)
+        .await
+        .unwrap();
+
+    assert_eq!(debug_output.error(), None);
+    assert_eq!(debug_output.entries().len(), 1);
+
+    let entry = &debug_output.entries()[0];
+    assert_eq!(entry.phase, TransactionPhase::TransactionScript);
+    assert_eq!(entry.dump, DebugDump::Stack(vec![Felt::new(1), Felt::new(2), Felt::new(3)]));
+
+    // A failing script still leaves the dumps taken before the failure, along with the error
+    let script = "
+        begin
+            push.7
+            debug.stack.1
+            assert
+        end";
+    let tx_script = client.compile_tx_script(vec![], script).unwrap();
+    let transaction_request = TransactionRequest::new().with_custom_script(tx_script).unwrap();
+
+    let mut debug_output = TransactionDebugOutput::default();
+    assert!(client
+        .new_transaction_with_debug_output(faucet.id(), transaction_request, &mut debug_output)
+        .await
+        .is_err());
+
+    assert!(debug_output.error().is_some());
+    assert_eq!(debug_output.entries().len(), 1);
+    assert_eq!(debug_output.entries()[0].dump, DebugDump::Stack(vec![Felt::new(7)]));
+}
+
+/// Signs the first signing request exported to `dir` with `secret_key` on another thread, as the
+/// offline machine of an air-gapped setup would.
+fn spawn_air_gapped_signer(
+    dir: std::path::PathBuf,
+    secret_key: AuthSecretKey,
+) -> std::thread::JoinHandle<SigningResponse> {
+    std::thread::spawn(move || loop {
+        let request_path = std::fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|path| path.extension() == Some(std::ffi::OsStr::new(REQUEST_FILE_EXTENSION)));
+
+        // The request may still be being written, in which case it's read again later
+        let request = request_path
+            .as_ref()
+            .and_then(|path| SigningRequest::read_from_bytes(&std::fs::read(path).ok()?).ok());
+
+        if let (Some(path), Some(request)) = (request_path, request) {
+            let response = request.sign(&secret_key, &mut rand::thread_rng()).unwrap();
+
+            // The signature is moved into place once written, so it's never read partially
+            let tmp_path = path.with_extension("tmp");
+            std::fs::write(&tmp_path, response.to_bytes()).unwrap();
+            std::fs::rename(tmp_path, path.with_extension(SIGNATURE_FILE_EXTENSION)).unwrap();
+            return response;
+        }
+
+        std::thread::sleep(core::time::Duration::from_millis(50));
+    })
+}
+
+#[test]
+fn test_air_gapped_authenticator() {
+    let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+
+    let secret_key = SecretKey::new();
+    let pub_key = Word::from(secret_key.public_key());
+    let message = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let account_delta = AccountDelta::default();
+    let request = SigningRequest::new(pub_key, message, account_delta.clone());
+
+    // Without a signature, the execution fails once the timeout elapses and the request is removed
+    let authenticator = AirGappedAuthenticator::new(&dir, core::time::Duration::from_millis(100));
+    assert!(authenticator.get_signature(pub_key, message, &account_delta).is_err());
+    assert!(!authenticator.request_path(&request).exists());
+
+    // Signing with a different key fails
+    let other_key = AuthSecretKey::RpoFalcon512(SecretKey::new());
+    assert!(request.sign(&other_key, &mut rand::thread_rng()).is_err());
+
+    // A signature provided while the authenticator waits completes the execution
+    let authenticator = AirGappedAuthenticator::new(&dir, core::time::Duration::from_secs(30));
+    let signer = spawn_air_gapped_signer(dir.clone(), AuthSecretKey::RpoFalcon512(secret_key));
+    let signature = authenticator.get_signature(pub_key, message, &account_delta).unwrap();
+
+    let response = signer.join().unwrap();
+    assert_eq!(signature, response.signature());
+    assert_eq!(
+        SigningResponse::read_from_bytes(&response.to_bytes()).unwrap().request_id(),
+        request.id()
+    );
+    assert!(!authenticator.request_path(&request).exists());
+
+    // A signature file that can't be parsed fails the execution instead of being waited for
+    std::fs::write(authenticator.signature_path(&request), [1u8, 2, 3]).unwrap();
+    assert!(authenticator.get_signature(pub_key, message, &account_delta).is_err());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_transaction_without_stored_secret_key() {
+    let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    let authenticator = AirGappedAuthenticator::new(&dir, core::time::Duration::from_secs(60));
+    let (mut client, _rpc_api) =
+        create_test_client_with_authenticator(Some(Arc::new(authenticator))).await;
+    client.sync_state().await.unwrap();
+
+    // The faucet's key is only known to the signer
+    let secret_key = SecretKey::new();
+    let (faucet, seed) = miden_lib::accounts::faucets::create_basic_fungible_faucet(
+        [3u8; 32],
+        TokenSymbol::new("TST").unwrap(),
+        3,
+        Felt::new(10000),
+        AccountStorageMode::Private,
+        AuthScheme::RpoFalcon512 { pub_key: secret_key.public_key() },
+    )
+    .unwrap();
+    client.import_account_without_auth(&faucet, Some(seed)).await.unwrap();
+    assert!(client.get_account_auth(faucet.id()).await.is_err());
+
+    let signer = spawn_air_gapped_signer(dir.clone(), AuthSecretKey::RpoFalcon512(secret_key));
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x0123456789abcdef").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    signer.join().unwrap();
+
+    client.submit_transaction(transaction).await.unwrap();
+    let (faucet, _) = client.get_account(faucet.id()).await.unwrap();
+    assert_eq!(faucet.nonce(), Felt::ONE);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// A prover that is always down.
+struct UnreachableProver;
+
+#[async_trait::async_trait(?Send)]
+impl TransactionProver for UnreachableProver {
+    async fn prove(
+        &self,
+        _tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        Err(TransactionProverError::InternalError("connection refused".to_string()))
+    }
+}
+
+/// A prover that rejects every transaction as invalid.
+struct RejectingProver;
+
+#[async_trait::async_trait(?Send)]
+impl TransactionProver for RejectingProver {
+    async fn prove(
+        &self,
+        _tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        Err(TransactionProverError::InvalidAccountDelta(
+            AccountDeltaError::InconsistentNonceUpdate("nonce not incremented".to_string()),
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_fallback_transaction_prover() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let witness: TransactionWitness = transaction.executed_transaction().clone().into();
+
+    let tx_prover = FallbackTransactionProver::new()
+        .with_failure_threshold(1)
+        .with_cooldown(1)
+        .with_prover("remote", Arc::new(UnreachableProver), None)
+        .with_prover("local", Arc::new(LocalTransactionProver::default()), None);
+
+    // The remote prover fails and the local prover proves the transaction
+    let proven_transaction = tx_prover.prove(witness.clone()).await.unwrap();
+    assert_eq!(proven_transaction.id(), transaction.executed_transaction().id());
+    assert_eq!(
+        tx_prover.health(),
+        vec![
+            ProverHealth {
+                name: "remote".to_string(),
+                consecutive_failures: 1,
+                healthy: false
+            },
+            ProverHealth {
+                name: "local".to_string(),
+                consecutive_failures: 0,
+                healthy: true
+            },
+        ]
+    );
+
+    // The unhealthy prover is skipped during its cooldown, and tried again afterwards
+    tx_prover.prove(witness.clone()).await.unwrap();
+    assert_eq!(tx_prover.health()[0].consecutive_failures, 1);
+    tx_prover.prove(witness.clone()).await.unwrap();
+    assert_eq!(tx_prover.health()[0].consecutive_failures, 2);
+
+    // If every prover fails, the errors of all of them are returned
+    let tx_prover = FallbackTransactionProver::new()
+        .with_prover("first", Arc::new(UnreachableProver), None)
+        .with_prover("second", Arc::new(UnreachableProver), None);
+    let err = tx_prover.prove(witness.clone()).await.unwrap_err();
+    assert!(err.to_string().contains("first: "));
+    assert!(err.to_string().contains("second: "));
+
+    // Errors caused by the transaction are returned without trying the next prover, and don't
+    // count against the health of the prover
+    let tx_prover = FallbackTransactionProver::new()
+        .with_prover("remote", Arc::new(RejectingProver), None)
+        .with_prover("local", Arc::new(LocalTransactionProver::default()), None);
+    assert!(matches!(
+        tx_prover.prove(witness).await,
+        Err(TransactionProverError::InvalidAccountDelta(_))
+    ));
+    assert_eq!(tx_prover.health()[0].consecutive_failures, 0);
+}
+
+#[tokio::test]
+async fn test_submit_transaction_with_prover() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+
+    let transaction_request = TransactionRequest::mint_fungible_asset(
+        FungibleAsset::new(faucet.id(), 5u64).unwrap(),
+        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+        miden_objects::notes::NoteType::Private,
+        client.rng(),
+    )
+    .unwrap();
+    let transaction = client.new_transaction(faucet.id(), transaction_request).await.unwrap();
+    let transaction_id = transaction.executed_transaction().id();
+
+    // The prover passed to the call is used instead of the client's prover
+    assert!(matches!(
+        client
+            .submit_transaction_with_prover(transaction, Arc::new(UnreachableProver))
+            .await,
+        Err(ClientError::TransactionProvingError(_))
+    ));
+    let stored = client.get_unsubmitted_transaction(transaction_id).await.unwrap();
+    assert!(stored.proven_transaction().is_none());
+
+    client
+        .submit_stored_transaction_with_prover(
+            transaction_id,
+            Arc::new(LocalTransactionProver::default()),
+        )
+        .await
+        .unwrap();
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+    assert_eq!(client.get_transactions(TransactionFilter::All).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_spending_policy() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+
+    let (faucet, _seed) = client
+        .new_account(AccountTemplate::FungibleFaucet {
+            token_symbol: "TST".try_into().unwrap(),
+            decimals: 3,
+            max_supply: 10000,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let allowed_id = AccountId::from_hex("0x168187d729b31a84").unwrap();
+    let other_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    let policy = SpendingPolicy::new()
+        .with_limit(
+            faucet.id(),
+            SpendingLimit {
+                per_transaction: Some(100),
+                daily: Some(150),
+            },
+        )
+        .with_allowed_recipient(allowed_id)
+        .with_blocked_note_type(miden_objects::notes::NoteType::Public);
+    client.set_spending_policy(faucet.id(), policy.clone()).await.unwrap();
+    assert_eq!(client.get_spending_policy(faucet.id()).await.unwrap(), Some(policy));
+
+    let mut mint_request = |amount, target_id, note_type| {
+        TransactionRequest::mint_fungible_asset(
+            FungibleAsset::new(faucet.id(), amount).unwrap(),
+            target_id,
+            note_type,
+            client.rng(),
+        )
+        .unwrap()
+    };
+    let private = miden_objects::notes::NoteType::Private;
+    let public = miden_objects::notes::NoteType::Public;
+
+    let over_limit = mint_request(101, allowed_id, private);
+    let not_allowed = mint_request(10, other_id, private);
+    let blocked_type = mint_request(10, allowed_id, public);
+    let allowed = mint_request(100, allowed_id, private);
+    let over_daily_limit = mint_request(60, allowed_id, private);
+    let reserved = mint_request(50, allowed_id, private);
+
+    // Violations are reported before the transaction is executed
+    assert!(matches!(
+        client.new_transaction(faucet.id(), over_limit).await,
+        Err(ClientError::SpendingPolicyViolation(
+            account_id,
+            SpendingPolicyViolation::TransactionLimitExceeded { amount: 101, limit: 100, .. }
+        )) if account_id == faucet.id()
+    ));
+    assert!(matches!(
+        client.validate_request(faucet.id(), &not_allowed).await,
+        Err(ClientError::SpendingPolicyViolation(
+            _,
+            SpendingPolicyViolation::RecipientNotAllowed(recipient)
+        )) if recipient == other_id
+    ));
+    assert!(matches!(
+        client.validate_request(faucet.id(), &blocked_type).await,
+        Err(ClientError::SpendingPolicyViolation(
+            _,
+            SpendingPolicyViolation::NoteTypeBlocked { .. }
+        ))
+    ));
+
+    // Applied transactions count towards the daily limit
+    let tx_result = client.new_transaction(faucet.id(), allowed).await.unwrap();
+    client.testing_apply_transaction(tx_result).await.unwrap();
+    assert_eq!(
+        client.get_daily_spending(faucet.id()).await.unwrap().get(&faucet.id()),
+        Some(&100)
+    );
+
+    assert!(matches!(
+        client.validate_request(faucet.id(), &over_daily_limit).await,
+        Err(ClientError::SpendingPolicyViolation(
+            _,
+            SpendingPolicyViolation::DailyLimitExceeded { amount: 60, spent: 100, limit: 150, .. }
+        ))
+    ));
+
+    // Stored transactions count towards the daily limit until they're discarded
+    let tx_result = client.new_transaction(faucet.id(), reserved).await.unwrap();
+    let transaction_id = client.save_transaction(tx_result.clone()).await.unwrap();
+    assert_eq!(
+        client.get_daily_spending(faucet.id()).await.unwrap().get(&faucet.id()),
+        Some(&150)
+    );
+    client.discard_stored_transaction(transaction_id).await.unwrap();
+    assert_eq!(
+        client.get_daily_spending(faucet.id()).await.unwrap().get(&faucet.id()),
+        Some(&100)
+    );
+
+    // The notes created by the transaction are checked again when it's stored
+    let stricter_policy = SpendingPolicy::new()
+        .with_limit(faucet.id(), SpendingLimit { per_transaction: Some(40), daily: None });
+    client.set_spending_policy(faucet.id(), stricter_policy).await.unwrap();
+    assert!(matches!(
+        client.save_transaction(tx_result).await,
+        Err(ClientError::SpendingPolicyViolation(
+            _,
+            SpendingPolicyViolation::TransactionLimitExceeded { amount: 50, limit: 40, .. }
+        ))
+    ));
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+
+    // Removing the policy lifts the restrictions
+    client.remove_spending_policy(faucet.id()).await.unwrap();
+    client.validate_request(faucet.id(), &over_daily_limit).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_recurring_payments() {
+    let (mut client, _) = create_test_client().await;
+    client.sync_state().await.unwrap();
+    let sync_height = client.get_sync_height().await.unwrap();
+
+    let (wallet, _seed) = client
+        .new_account(AccountTemplate::BasicWallet {
+            mutable_code: false,
+            storage_mode: AccountStorageMode::Private,
+        })
+        .await
+        .unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let payment_data = PaymentTransactionData::new(
+        vec![FungibleAsset::new(faucet_id, 10).unwrap().into()],
+        wallet.id(),
+        AccountId::from_hex("0x168187d729b31a84").unwrap(),
+    );
+
+    let due_payment = client
+        .schedule_payment(
+            payment_data.clone(),
+            miden_objects::notes::NoteType::Private,
+            PaymentSchedule::every(5, sync_height),
+        )
+        .await
+        .unwrap();
+    let future_payment = client
+        .schedule_payment(
+            payment_data,
+            miden_objects::notes::NoteType::Private,
+            PaymentSchedule::once_at(sync_height + 10),
+        )
+        .await
+        .unwrap();
+    assert_ne!(due_payment.id(), future_payment.id());
+    assert!(due_payment.is_due(sync_height));
+    assert!(!future_payment.is_due(sync_height));
+
+    // Only the due payment is attempted. The wallet has no funds, so the attempt fails and is
+    // recorded, and the payment is retried after a backoff
+    let executions = client.run_due_payments().await.unwrap();
+    assert_eq!(executions.len(), 1);
+    let (payment_id, execution) = &executions[0];
+    assert_eq!(*payment_id, due_payment.id());
+    assert_eq!(execution.block_num, sync_height);
+    assert!(matches!(execution.outcome, PaymentOutcome::Failed(_)));
+
+    let payments = client.get_recurring_payments().await.unwrap();
+    assert_eq!(payments.len(), 2);
+    let stored_payment = payments.iter().find(|payment| payment.id() == due_payment.id()).unwrap();
+    assert_eq!(stored_payment.history(), &[execution.clone()]);
+    assert_eq!(stored_payment.next_block(), Some(sync_height));
+    assert_eq!(stored_payment.next_retry_block(), Some(sync_height + 1));
+    assert!(stored_payment.in_flight_transaction().is_none());
+    assert!(!stored_payment.is_due(sync_height));
+    assert!(stored_payment.is_due(sync_height + 1));
+    assert!(client.get_unsubmitted_transactions().await.unwrap().is_empty());
+
+    // The failed payment isn't retried until the backoff elapses
+    assert!(client.run_due_payments().await.unwrap().is_empty());
+
+    // Cancelled payments are removed from the store
+    client.cancel_recurring_payment(due_payment.id()).await.unwrap();
+    assert_eq!(client.get_recurring_payments().await.unwrap().len(), 1);
+    assert!(matches!(
+        client.cancel_recurring_payment(due_payment.id()).await,
+        Err(ClientError::RecurringPaymentNotFound(payment_id)) if payment_id == due_payment.id()
+    ));
+}
@@ -0,0 +1,228 @@
+//! Provides APIs to prove many transactions and submit them in order.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use futures::{stream, Stream, StreamExt};
+use miden_objects::transaction::{ProvenTransaction, TransactionId, TransactionWitness};
+use miden_tx::TransactionProverError;
+use tracing::warn;
+
+use super::{TransactionResult, UnsubmittedTransaction};
+use crate::{Client, ClientError, FeltRng};
+
+// PROVING PROGRESS
+// ================================================================================================
+
+/// An update on the progress of [Client::prove_transactions].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProvingProgress {
+    /// The transaction was proven. `completed` transactions out of `total` finished proving.
+    Proven {
+        transaction_id: TransactionId,
+        completed: usize,
+        total: usize,
+    },
+    /// The transaction could not be proven. `completed` transactions out of `total` finished
+    /// proving.
+    Failed {
+        transaction_id: TransactionId,
+        completed: usize,
+        total: usize,
+    },
+    /// The transaction was submitted to the network and applied to the local state.
+    Submitted { transaction_id: TransactionId },
+}
+
+impl<R: FeltRng> Client<R> {
+    // BATCH PROVING
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves the transactions with the client's prover, with up to `workers` proofs in progress
+    /// at the same time, and then submits the proven transactions in the order they were
+    /// provided.
+    ///
+    /// The transactions are stored before proving starts. A transaction that fails to be proven or
+    /// submitted doesn't stop the others, and its error is returned alongside its ID, in the order
+    /// the transactions were provided. Transactions that failed to be proven stay stored and can
+    /// be resumed with [Client::submit_stored_transaction]. `on_progress` is called every time a
+    /// transaction is proven, fails to be proven, or is submitted.
+    ///
+    /// Proofs only overlap when the prover awaits work done elsewhere, as is the case for remote
+    /// provers, where `workers` bounds the number of requests in flight. The local prover runs
+    /// each proof to completion on the calling task, so to prove transactions locally in parallel
+    /// use `Client::prove_transactions_locally` instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transactions can't be stored, or if one of them isn't allowed by
+    /// the spending policy of its account. In that case none of them is left stored.
+    pub async fn prove_transactions(
+        &mut self,
+        tx_results: Vec<TransactionResult>,
+        workers: usize,
+        on_progress: impl FnMut(ProvingProgress),
+    ) -> Result<Vec<(TransactionId, Result<(), ClientError>)>, ClientError> {
+        let transactions = self.store_batch(tx_results).await?;
+
+        let tx_prover = self.tx_prover.clone();
+        let proofs = stream::iter(batch_witnesses(&transactions))
+            .map(move |(index, witness)| {
+                let tx_prover = tx_prover.clone();
+                async move { (index, tx_prover.prove(witness).await) }
+            })
+            .buffer_unordered(workers.max(1));
+
+        self.submit_batch(transactions, proofs, on_progress).await
+    }
+
+    /// Proves the transactions with a local prover on up to `workers` threads, and then submits
+    /// the proven transactions in the order they were provided.
+    ///
+    /// Every thread proves one transaction at a time with its own
+    /// [LocalTransactionProver](crate::transactions::LocalTransactionProver) built from
+    /// `proving_options`, regardless of the client's prover. Transactions are stored, and
+    /// outcomes and progress are reported, as in [Client::prove_transactions].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transactions can't be stored, or if one of them isn't allowed by
+    /// the spending policy of its account. In that case none of them is left stored.
+    #[cfg(feature = "concurrent")]
+    pub async fn prove_transactions_locally(
+        &mut self,
+        tx_results: Vec<TransactionResult>,
+        workers: usize,
+        proving_options: miden_tx::ProvingOptions,
+        on_progress: impl FnMut(ProvingProgress),
+    ) -> Result<Vec<(TransactionId, Result<(), ClientError>)>, ClientError> {
+        use alloc::{collections::VecDeque, sync::Arc};
+        use std::sync::Mutex;
+
+        use futures::channel::mpsc;
+        use miden_tx::{LocalTransactionProver, TransactionProver};
+
+        let transactions = self.store_batch(tx_results).await?;
+
+        let queue = Arc::new(Mutex::new(VecDeque::from(batch_witnesses(&transactions))));
+        let (sender, receiver) = mpsc::unbounded();
+        for _ in 0..workers.clamp(1, transactions.len().max(1)) {
+            let queue = queue.clone();
+            let sender = sender.clone();
+            let proving_options = proving_options.clone();
+
+            std::thread::spawn(move || {
+                let tx_prover = LocalTransactionProver::new(proving_options);
+                loop {
+                    let next = queue.lock().expect("queue lock should not be poisoned").pop_front();
+                    let Some((index, witness)) = next else {
+                        break;
+                    };
+
+                    let result = futures::executor::block_on(tx_prover.prove(witness));
+                    // The receiver is only dropped if the batch was cancelled
+                    if sender.unbounded_send((index, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        // The stream of proofs ends once every thread is done
+        drop(sender);
+
+        self.submit_batch(transactions, receiver, on_progress).await
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Stores the transactions of a batch and reserves the spending of each of them. If one of
+    /// them can't be stored, the ones stored before it are removed and their spending released.
+    async fn store_batch(
+        &self,
+        tx_results: Vec<TransactionResult>,
+    ) -> Result<Vec<UnsubmittedTransaction>, ClientError> {
+        let mut transactions = Vec::with_capacity(tx_results.len());
+        for tx_result in tx_results {
+            let stored = match self.reserve_spending(&tx_result).await {
+                Ok(()) => {
+                    let transaction = UnsubmittedTransaction::new(tx_result);
+                    let stored = self.store.upsert_unsubmitted_transaction(&transaction).await;
+                    transactions.push(transaction);
+                    stored.map_err(ClientError::from)
+                },
+                Err(err) => Err(err),
+            };
+
+            if let Err(err) = stored {
+                for transaction in &transactions {
+                    if let Err(err) = self.remove_stored_transaction(transaction).await {
+                        warn!("Failed to remove stored transaction {}: {err}", transaction.id());
+                    }
+                }
+                return Err(err);
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Stores the proofs of a batch as they are received, reporting the progress, and then
+    /// submits the proven transactions in order. `proofs` yields the outcome of proving each
+    /// transaction along with its index in `transactions`.
+    async fn submit_batch(
+        &mut self,
+        transactions: Vec<UnsubmittedTransaction>,
+        proofs: impl Stream<Item = (usize, Result<ProvenTransaction, TransactionProverError>)>,
+        mut on_progress: impl FnMut(ProvingProgress),
+    ) -> Result<Vec<(TransactionId, Result<(), ClientError>)>, ClientError> {
+        let total = transactions.len();
+        let mut outcomes: BTreeMap<usize, Result<(), ClientError>> = BTreeMap::new();
+
+        let mut proofs = core::pin::pin!(proofs);
+        while let Some((index, result)) = proofs.next().await {
+            let transaction_id = transactions[index].id();
+            let completed = outcomes.len() + 1;
+
+            match result {
+                Ok(proven_transaction) => {
+                    let transaction =
+                        transactions[index].clone().with_proven_transaction(proven_transaction);
+                    self.store.upsert_unsubmitted_transaction(&transaction).await?;
+                    outcomes.insert(index, Ok(()));
+                    on_progress(ProvingProgress::Proven { transaction_id, completed, total });
+                },
+                Err(err) => {
+                    outcomes.insert(index, Err(err.into()));
+                    on_progress(ProvingProgress::Failed { transaction_id, completed, total });
+                },
+            }
+        }
+
+        let mut results = Vec::with_capacity(total);
+        for (transaction, proving_outcome) in transactions.iter().zip(outcomes.into_values()) {
+            let transaction_id = transaction.id();
+            let outcome = match proving_outcome {
+                Ok(()) => self.submit_stored_transaction(transaction_id).await,
+                Err(err) => Err(err),
+            };
+
+            if outcome.is_ok() {
+                on_progress(ProvingProgress::Submitted { transaction_id });
+            }
+            results.push((transaction_id, outcome));
+        }
+
+        Ok(results)
+    }
+}
+
+/// Returns the witnesses of the transactions of a batch, along with their index in the batch.
+fn batch_witnesses(transactions: &[UnsubmittedTransaction]) -> Vec<(usize, TransactionWitness)> {
+    transactions
+        .iter()
+        .enumerate()
+        .map(|(index, transaction)| {
+            (index, transaction.tx_result().executed_transaction().clone().into())
+        })
+        .collect()
+}
@@ -0,0 +1,102 @@
+//! Provides APIs to execute a sequence of transactions where each transaction builds on the
+//! state left by the previous ones, and to submit them in order.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    notes::{Note, NoteId},
+};
+
+use super::{notes_from_output, TransactionRequest, TransactionResult};
+use crate::{store::InputNoteRecord, Client, ClientError, FeltRng};
+
+impl<R: FeltRng> Client<R> {
+    // TRANSACTION CHAINS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the transaction requests back to back, each against its account, without changing
+    /// the local database. Unauthenticated input notes are only stored once the chain is
+    /// submitted.
+    ///
+    /// Each transaction is executed on top of the previous transactions of the chain:
+    /// - An account used by a previous step is executed against the state it was left in by that
+    ///   step, instead of its stored state.
+    /// - Input notes of a request that were created by a previous step are consumed as
+    ///   unauthenticated notes, so they don't need to be committed first. Such notes can be
+    ///   referenced by their ID, for example with [TransactionRequest::consume_notes].
+    ///
+    /// The returned results should be submitted with [Client::submit_transaction_chain], which
+    /// allows the whole chain to be included in a single block.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first step that fails to execute. No step is applied in that case.
+    pub async fn new_transaction_chain(
+        &mut self,
+        transaction_requests: Vec<(AccountId, TransactionRequest)>,
+    ) -> Result<Vec<TransactionResult>, ClientError> {
+        let mut pending_accounts: BTreeMap<AccountId, Account> = BTreeMap::new();
+        let mut chained_notes: BTreeMap<NoteId, Note> = BTreeMap::new();
+        let mut tx_results = Vec::with_capacity(transaction_requests.len());
+
+        for (account_id, transaction_request) in transaction_requests {
+            let transaction_request = transaction_request.with_chained_input_notes(&chained_notes);
+            let mut account = match pending_accounts.remove(&account_id) {
+                Some(account) => account,
+                None => self.get_account(account_id).await?.0,
+            };
+
+            let tx_result = self
+                .execute_transaction_request(
+                    account_id,
+                    transaction_request,
+                    Some(account.clone()),
+                    None,
+                )
+                .await?;
+
+            account.apply_delta(tx_result.account_delta())?;
+            pending_accounts.insert(account_id, account);
+
+            chained_notes.extend(
+                notes_from_output(tx_result.created_notes()).map(|note| (note.id(), note.clone())),
+            );
+            tx_results.push(tx_result);
+        }
+
+        Ok(tx_results)
+    }
+
+    /// Proves and submits the transactions of a chain created with
+    /// [Client::new_transaction_chain], in order, applying each one to the local state once it's
+    /// submitted.
+    ///
+    /// Every transaction, along with the unauthenticated notes it consumes, is stored before the
+    /// first one is submitted. If a submission fails, the remaining transactions stay stored, and
+    /// can be resumed in order with [Client::submit_stored_transaction] or discarded with
+    /// [Client::discard_stored_transaction].
+    pub async fn submit_transaction_chain(
+        &mut self,
+        tx_results: Vec<TransactionResult>,
+    ) -> Result<(), ClientError> {
+        let unauthenticated_input_notes: Vec<InputNoteRecord> = tx_results
+            .iter()
+            .flat_map(|tx_result| tx_result.consumed_notes().iter())
+            .filter(|input_note| input_note.proof().is_none())
+            .map(|input_note| input_note.note().clone().into())
+            .collect();
+        self.store.upsert_input_notes(&unauthenticated_input_notes).await?;
+
+        let mut transaction_ids = Vec::with_capacity(tx_results.len());
+        for tx_result in tx_results {
+            transaction_ids.push(self.save_transaction(tx_result).await?);
+        }
+
+        for transaction_id in transaction_ids {
+            self.submit_stored_transaction(transaction_id).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+//! Captures the output of the `debug` instructions run by a transaction, so it can be inspected
+//! instead of being printed to the standard output by the VM.
+
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use miden_lib::transaction::{TransactionKernel, TransactionTrace};
+use miden_objects::{
+    accounts::AccountCode,
+    notes::NoteId,
+    transaction::{TransactionArgs, TransactionInputs},
+    vm::AdviceInputs,
+    Digest, Felt, Word,
+};
+use miden_processor::{
+    DebugOptions, ExecutionError, ExecutionOptions, Host, HostResponse, MastForest,
+    MemAdviceProvider, ProcessState,
+};
+use miden_tx::{TransactionHost, TransactionMastStore};
+
+// TRANSACTION DEBUG OUTPUT
+// ================================================================================================
+
+/// The output of the `debug` instructions run by a transaction, in the order they were run.
+///
+/// Each entry records the phase of the transaction it was taken in, so the dumps of each input
+/// note can be told apart.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionDebugOutput {
+    entries: Vec<DebugEntry>,
+    error: Option<String>,
+}
+
+impl TransactionDebugOutput {
+    /// Returns the captured dumps, in the order they were taken.
+    pub fn entries(&self) -> &[DebugEntry] {
+        &self.entries
+    }
+
+    /// Returns the dumps taken while executing the script of the specified input note.
+    pub fn note_entries(&self, note_id: NoteId) -> impl Iterator<Item = &DebugEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.phase == TransactionPhase::Note(note_id))
+    }
+
+    /// Returns the error that stopped the execution, if the transaction failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns a debug output without dumps, for a transaction that couldn't be executed.
+    pub(crate) fn failed(error: String) -> Self {
+        Self { entries: vec![], error: Some(error) }
+    }
+
+    /// Executes the transaction kernel in debug mode and records the dumps of its `debug`
+    /// instructions.
+    ///
+    /// The execution doesn't use an authenticator: signatures have to be part of
+    /// `advice_witness`, as they are in the witness of an executed transaction. Otherwise, the
+    /// execution stops with an error when the signature is requested, which still leaves the
+    /// dumps taken up to that point.
+    pub(crate) fn capture(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        advice_witness: Option<AdviceInputs>,
+        foreign_account_codes: &[AccountCode],
+    ) -> Self {
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(tx_inputs, tx_args, advice_witness);
+
+        let mast_store = Arc::new(TransactionMastStore::new());
+        mast_store.load_transaction_code(tx_inputs, tx_args);
+        foreign_account_codes
+            .iter()
+            .for_each(|code| mast_store.insert(code.mast().clone()));
+
+        let account_code_commitments: BTreeSet<Digest> =
+            foreign_account_codes.iter().map(AccountCode::commitment).collect();
+
+        let tx_host = match TransactionHost::new(
+            tx_inputs.account().into(),
+            MemAdviceProvider::from(advice_inputs),
+            mast_store,
+            None,
+            account_code_commitments,
+        ) {
+            Ok(tx_host) => tx_host,
+            Err(err) => return Self::failed(err.to_string()),
+        };
+
+        let note_ids = tx_inputs.input_notes().iter().map(|note| note.id()).collect();
+        let mut host = DebugCaptureHost::new(tx_host, note_ids);
+
+        let result = miden_processor::execute(
+            &TransactionKernel::main(),
+            stack_inputs,
+            &mut host,
+            ExecutionOptions::default().with_tracing().with_debugging(),
+        );
+
+        Self {
+            entries: host.entries,
+            error: result.err().map(|err| err.to_string()),
+        }
+    }
+}
+
+// DEBUG ENTRY
+// ================================================================================================
+
+/// A dump taken by a `debug` instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugEntry {
+    /// Clock cycle at which the dump was taken.
+    pub clk: u32,
+    /// Execution context in which the dump was taken.
+    pub context: u32,
+    /// Phase of the transaction in which the dump was taken.
+    pub phase: TransactionPhase,
+    /// Contents of the dump.
+    pub dump: DebugDump,
+}
+
+/// Phase of a transaction execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionPhase {
+    Prologue,
+    /// Processing of the input notes, outside of their scripts.
+    NotesProcessing,
+    /// Execution of the script of an input note.
+    Note(NoteId),
+    TransactionScript,
+    Epilogue,
+}
+
+/// Contents of a dump taken by a `debug` instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DebugDump {
+    /// Operand stack items, starting from the top.
+    Stack(Vec<Felt>),
+    /// Memory words with their addresses. Addresses that were never written contain zeros.
+    Memory(Vec<(u64, Word)>),
+}
+
+// DEBUG CAPTURE HOST
+// ================================================================================================
+
+/// Host that records the dumps of `debug` instructions instead of printing them, and handles
+/// everything else with the wrapped host.
+///
+/// The phase of each dump is tracked through the traces emitted by the transaction kernel.
+struct DebugCaptureHost<H: Host> {
+    inner: H,
+    note_ids: Vec<NoteId>,
+    executed_notes: usize,
+    phase: TransactionPhase,
+    entries: Vec<DebugEntry>,
+}
+
+impl<H: Host> DebugCaptureHost<H> {
+    fn new(inner: H, note_ids: Vec<NoteId>) -> Self {
+        Self {
+            inner,
+            note_ids,
+            executed_notes: 0,
+            phase: TransactionPhase::Prologue,
+            entries: vec![],
+        }
+    }
+
+    fn update_phase(&mut self, trace_id: u32) {
+        self.phase = match TransactionTrace::try_from(trace_id) {
+            Ok(TransactionTrace::PrologueStart) => TransactionPhase::Prologue,
+            Ok(TransactionTrace::NotesProcessingStart | TransactionTrace::NoteExecutionEnd) => {
+                TransactionPhase::NotesProcessing
+            },
+            Ok(TransactionTrace::NoteExecutionStart) => {
+                let Some(note_id) = self.note_ids.get(self.executed_notes) else {
+                    return;
+                };
+                self.executed_notes += 1;
+                TransactionPhase::Note(*note_id)
+            },
+            Ok(TransactionTrace::TxScriptProcessingStart) => TransactionPhase::TransactionScript,
+            Ok(TransactionTrace::EpilogueStart) => TransactionPhase::Epilogue,
+            _ => return,
+        };
+    }
+}
+
+impl<H: Host> Host for DebugCaptureHost<H> {
+    type AdviceProvider = H::AdviceProvider;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        self.inner.advice_provider()
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        self.inner.advice_provider_mut()
+    }
+
+    fn get_mast_forest(&self, node_digest: &Digest) -> Option<Arc<MastForest>> {
+        self.inner.get_mast_forest(node_digest)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.inner.on_event(process, event_id)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.update_phase(trace_id);
+        self.inner.on_trace(process, trace_id)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        let dump = match *options {
+            DebugOptions::StackAll => DebugDump::Stack(process.get_stack_state()),
+            DebugOptions::StackTop(count) => {
+                DebugDump::Stack(process.get_stack_state().into_iter().take(count.into()).collect())
+            },
+            DebugOptions::MemAll => DebugDump::Memory(process.get_mem_state(process.ctx())),
+            DebugOptions::MemInterval(start, end) => DebugDump::Memory(
+                (start..=end)
+                    .map(|addr| {
+                        let word = process.get_mem_value(process.ctx(), addr).unwrap_or_default();
+                        (addr.into(), word)
+                    })
+                    .collect(),
+            ),
+            DebugOptions::LocalInterval(start, end, num_locals) => {
+                // Locals are stored below the frame pointer
+                let first_local = process.fmp() - u64::from(num_locals);
+                DebugDump::Memory(
+                    (start..=end)
+                        .map(|index| {
+                            let addr = first_local + u64::from(index);
+                            let word = u32::try_from(addr)
+                                .ok()
+                                .and_then(|addr| process.get_mem_value(process.ctx(), addr))
+                                .unwrap_or_default();
+                            (addr, word)
+                        })
+                        .collect(),
+                )
+            },
+        };
+
+        self.entries.push(DebugEntry {
+            clk: process.clk().into(),
+            context: process.ctx().into(),
+            phase: self.phase,
+            dump,
+        });
+
+        Ok(HostResponse::None)
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        self.inner.on_assert_failed(process, err_code)
+    }
+}
@@ -4,6 +4,7 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
+    sync::Arc,
     vec::Vec,
 };
 use core::fmt::{self};
@@ -12,7 +13,6 @@ pub use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     accounts::{
         Account, AccountCode, AccountDelta, AccountHeader, AccountId, AccountStorageHeader,
-        AccountType,
     },
     assets::{Asset, NonFungibleAsset},
     crypto::merkle::MerklePath,
@@ -21,16 +21,21 @@ use miden_objects::{
     vm::AdviceInputs,
     AssetError, Digest, Felt, Word, ZERO,
 };
+use miden_tx::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    DataStore, TransactionExecutor,
+};
 pub use miden_tx::{LocalTransactionProver, ProvingOptions, TransactionProver};
-use script_builder::{AccountCapabilities, AccountInterface};
+use script_builder::AccountCapabilities;
 use tracing::info;
 
 use super::{Client, FeltRng};
 use crate::{
     notes::{NoteScreener, NoteUpdates},
+    rpc::{verification::verify_account_proof, RpcError},
     store::{
-        input_note_states::ExpectedNoteState, InputNoteRecord, InputNoteState, NoteFilter,
-        OutputNoteRecord, TransactionFilter,
+        data_store::ClientDataStore, input_note_states::ExpectedNoteState, InputNoteRecord,
+        InputNoteState, NoteFilter, OutputNoteRecord, TransactionFilter,
     },
     sync::NoteTagRecord,
     ClientError,
@@ -42,13 +47,51 @@ pub use request::{
     TransactionRequestError, TransactionScriptTemplate,
 };
 
+mod batch;
+pub use batch::ProvingProgress;
+
+mod chain;
+
+mod payments;
+pub use payments::{
+    PaymentExecution, PaymentOutcome, PaymentSchedule, RecurringPayment, MAX_PAYMENT_HISTORY,
+};
+
+mod pipeline;
+pub use pipeline::UnsubmittedTransaction;
+
+#[cfg(feature = "futures-timer")]
+mod prover;
+#[cfg(feature = "futures-timer")]
+pub use prover::{
+    FallbackTransactionProver, ProverHealth, DEFAULT_COOLDOWN, DEFAULT_FAILURE_THRESHOLD,
+};
+
+mod policy;
+pub use policy::{SpendingLimit, SpendingPolicy, SpendingPolicyViolation};
+
 mod script_builder;
 pub use miden_objects::transaction::{
     ExecutedTransaction, InputNote, OutputNote, OutputNotes, ProvenTransaction, TransactionId,
-    TransactionScript,
+    TransactionScript, TransactionWitness,
 };
 pub use miden_tx::{DataStoreError, TransactionExecutorError};
-pub use script_builder::TransactionScriptBuilderError;
+pub use script_builder::{AccountInterface, BasicAccountInterface, TransactionScriptBuilderError};
+
+mod debug;
+pub use debug::{DebugDump, DebugEntry, TransactionDebugOutput, TransactionPhase};
+
+mod stats;
+pub use stats::ExecutionStats;
+
+mod summary;
+pub use summary::{
+    AssetChange, ConsumedNoteSummary, CreatedNoteSummary, StorageSlotChange, TransactionSummary,
+};
+
+/// Index of the storage slot reserved for faucet data. Fungible faucets keep their issued supply
+/// in it.
+const FAUCET_DATA_SLOT: u8 = 0;
 
 // TRANSACTION RESULT
 // --------------------------------------------------------------------------------------------
@@ -136,6 +179,16 @@ impl TransactionResult {
     pub fn consumed_notes(&self) -> &InputNotes<InputNote> {
         self.transaction.tx_inputs().input_notes()
     }
+
+    /// Returns a [TransactionSummary] describing the effects of the transaction.
+    pub fn summary(&self) -> TransactionSummary {
+        TransactionSummary::from(&self.transaction)
+    }
+
+    /// Returns the number of VM cycles spent in each phase of the transaction execution.
+    pub fn stats(&self) -> ExecutionStats {
+        ExecutionStats::from(self.transaction.measurements())
+    }
 }
 
 impl From<TransactionResult> for ExecutedTransaction {
@@ -144,6 +197,22 @@ impl From<TransactionResult> for ExecutedTransaction {
     }
 }
 
+impl Serializable for TransactionResult {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.transaction.write_into(target);
+        self.relevant_notes.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionResult {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let transaction = ExecutedTransaction::read_from(source)?;
+        let relevant_notes = Vec::<InputNoteRecord>::read_from(source)?;
+
+        Ok(Self { transaction, relevant_notes })
+    }
+}
+
 // TRANSACTION RECORD
 // --------------------------------------------------------------------------------------------
 
@@ -194,6 +263,12 @@ impl TransactionRecord {
 /// Represents the status of a transaction
 #[derive(Debug, Clone, PartialEq)]
 pub enum TransactionStatus {
+    /// Transaction has been executed and stored locally, but not yet proven
+    Executed,
+    /// Transaction has been proven and stored locally, but not yet submitted
+    Proven,
+    /// Transaction has been proven, but its submission failed and will be retried
+    Queued,
     /// Transaction has been submitted but not yet committed
     Pending,
     /// Transaction has been committed and included at the specified block number
@@ -205,6 +280,9 @@ pub enum TransactionStatus {
 impl fmt::Display for TransactionStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TransactionStatus::Executed => write!(f, "Executed"),
+            TransactionStatus::Proven => write!(f, "Proven"),
+            TransactionStatus::Queued => write!(f, "Queued"),
             TransactionStatus::Pending => write!(f, "Pending"),
             TransactionStatus::Committed(block_number) => {
                 write!(f, "Committed (Block: {})", block_number)
@@ -306,9 +384,49 @@ impl<R: FeltRng> Client<R> {
         &mut self,
         account_id: AccountId,
         transaction_request: TransactionRequest,
+    ) -> Result<TransactionResult, ClientError> {
+        self.execute_transaction_request(account_id, transaction_request, None, None)
+            .await
+    }
+
+    /// Same as [Client::new_transaction], but the output of the `debug` instructions run by the
+    /// transaction is captured into `debug_output`, grouped by input note, instead of being
+    /// printed.
+    ///
+    /// The dumps are captured by executing the transaction a second time, so `debug_output` is
+    /// filled in even if the execution fails, along with the execution error. The client doesn't
+    /// need to be in debug mode.
+    pub async fn new_transaction_with_debug_output(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+        debug_output: &mut TransactionDebugOutput,
+    ) -> Result<TransactionResult, ClientError> {
+        self.execute_transaction_request(account_id, transaction_request, None, Some(debug_output))
+            .await
+    }
+
+    /// Executes the transaction specified by the request against the specified account.
+    ///
+    /// If `pending_account` is set, the transaction is part of a chain: it's executed against that
+    /// state of the account instead of the stored one, and its unauthenticated input notes are
+    /// only provided to the executor instead of being stored.
+    ///
+    /// If `debug_output` is set, the output of the `debug` instructions run by the transaction is
+    /// captured into it.
+    pub(crate) async fn execute_transaction_request(
+        &mut self,
+        account_id: AccountId,
+        transaction_request: TransactionRequest,
+        pending_account: Option<Account>,
+        debug_output: Option<&mut TransactionDebugOutput>,
     ) -> Result<TransactionResult, ClientError> {
         // Validates the transaction request before executing
-        self.validate_request(account_id, &transaction_request).await?;
+        let account = match &pending_account {
+            Some(account) => account.clone(),
+            None => self.get_account(account_id).await?.0,
+        };
+        self.validate_account_request(&transaction_request, &account).await?;
 
         // Ensure authenticated notes have their inclusion proofs (a.k.a they're in a committed
         // state). TODO: we should consider refactoring this in a way we can handle this in
@@ -329,7 +447,8 @@ impl<R: FeltRng> Client<R> {
             }
         }
 
-        // If tx request contains unauthenticated_input_notes we should insert them
+        // If tx request contains unauthenticated_input_notes we should insert them, unless the
+        // transaction is part of a chain, which doesn't change the store until it's submitted
         let unauthenticated_input_notes = transaction_request
             .unauthenticated_input_notes()
             .iter()
@@ -337,7 +456,9 @@ impl<R: FeltRng> Client<R> {
             .map(|note| note.into())
             .collect::<Vec<_>>();
 
-        self.store.upsert_input_notes(&unauthenticated_input_notes).await?;
+        if pending_account.is_none() {
+            self.store.upsert_input_notes(&unauthenticated_input_notes).await?;
+        }
 
         let note_ids = transaction_request.get_input_note_ids();
 
@@ -358,9 +479,27 @@ impl<R: FeltRng> Client<R> {
             .into_transaction_args(tx_script)
             .with_advice_inputs(foreign_data_advice_inputs);
 
+        // Transactions on top of a pending account state need an executor whose data store
+        // provides that state
+        let pending_executor;
+        let tx_executor = match &pending_account {
+            Some(account) => {
+                let data_store = ClientDataStore::new(self.store.clone())
+                    .with_pending_account(account.clone())
+                    .with_pending_notes(unauthenticated_input_notes.clone());
+                pending_executor = TransactionExecutor::new(
+                    Arc::new(data_store) as Arc<dyn DataStore>,
+                    Some(self.authenticator.clone()),
+                )
+                .with_debug_mode(self.in_debug_mode);
+                &pending_executor
+            },
+            None => &self.tx_executor,
+        };
+
         foreign_account_codes
             .iter()
-            .for_each(|code| self.tx_executor.load_account_code(code));
+            .for_each(|code| tx_executor.load_account_code(code));
 
         let block_num = if let Some(block_num) = fpi_block_num {
             block_num
@@ -369,10 +508,52 @@ impl<R: FeltRng> Client<R> {
         };
 
         // Execute the transaction and get the witness
-        let executed_transaction = self
-            .tx_executor
-            .execute_transaction(account_id, block_num, &note_ids, tx_args)
-            .await?;
+        let executed_transaction = match debug_output {
+            Some(debug_output) => {
+                let result = tx_executor
+                    .execute_transaction(account_id, block_num, &note_ids, tx_args.clone())
+                    .await;
+
+                // The witness of an executed transaction contains its signatures, so it can be
+                // executed again without the authenticator. A failed execution is executed again
+                // from its inputs instead
+                *debug_output = match &result {
+                    Ok(executed_transaction) => TransactionDebugOutput::capture(
+                        executed_transaction.tx_inputs(),
+                        executed_transaction.tx_args(),
+                        Some(executed_transaction.advice_witness().clone()),
+                        &foreign_account_codes,
+                    ),
+                    Err(_) => {
+                        let mut data_store = ClientDataStore::new(self.store.clone());
+                        if let Some(account) = pending_account {
+                            data_store = data_store
+                                .with_pending_account(account)
+                                .with_pending_notes(unauthenticated_input_notes);
+                        }
+                        match data_store
+                            .get_transaction_inputs(account_id, block_num, &note_ids)
+                            .await
+                        {
+                            Ok(tx_inputs) => TransactionDebugOutput::capture(
+                                &tx_inputs,
+                                &tx_args,
+                                None,
+                                &foreign_account_codes,
+                            ),
+                            Err(err) => TransactionDebugOutput::failed(err.to_string()),
+                        }
+                    },
+                };
+
+                result?
+            },
+            None => {
+                tx_executor
+                    .execute_transaction(account_id, block_num, &note_ids, tx_args)
+                    .await?
+            },
+        };
 
         // Check that the expected output notes matches the transaction outcome.
         // We compare authentication hashes where possible since that involves note IDs + metadata
@@ -400,23 +581,39 @@ impl<R: FeltRng> Client<R> {
 
     /// Proves the specified transaction, submits it to the network, and saves the transaction into
     /// the local database for tracking.
+    ///
+    /// The transaction is stored before proving, and its proof is stored before submission. If
+    /// either stage fails, the transaction can be resumed with
+    /// [Client::submit_stored_transaction]. If the node can't be reached, the transaction is
+    /// queued and resubmitted automatically on later syncs.
     pub async fn submit_transaction(
         &mut self,
         tx_result: TransactionResult,
     ) -> Result<(), ClientError> {
-        let proven_transaction = self.prove_transaction(&tx_result).await?;
-        self.submit_proven_transaction(proven_transaction).await?;
-        self.apply_transaction(tx_result).await
+        self.submit_transaction_with_prover(tx_result, self.tx_prover.clone()).await
+    }
+
+    /// Same as [Client::submit_transaction], but the transaction is proven with `tx_prover`
+    /// instead of the prover the client was created with. This allows, for example, to prove
+    /// small transactions locally and large ones remotely.
+    pub async fn submit_transaction_with_prover(
+        &mut self,
+        tx_result: TransactionResult,
+        tx_prover: Arc<dyn TransactionProver>,
+    ) -> Result<(), ClientError> {
+        let transaction_id = self.save_transaction(tx_result).await?;
+        self.submit_stored_transaction_with_prover(transaction_id, tx_prover).await
     }
 
     async fn prove_transaction(
         &mut self,
         tx_result: &TransactionResult,
+        tx_prover: Arc<dyn TransactionProver>,
     ) -> Result<ProvenTransaction, ClientError> {
         info!("Proving transaction...");
 
         let proven_transaction =
-            self.tx_prover.prove(tx_result.executed_transaction().clone().into()).await?;
+            tx_prover.prove(tx_result.executed_transaction().clone().into()).await?;
 
         info!("Transaction proven.");
 
@@ -483,6 +680,10 @@ impl<R: FeltRng> Client<R> {
             }
         }
 
+        // Spending is recorded before the transaction is stored, so a failure can only make the
+        // daily limits stricter
+        self.record_spending(&tx_result).await?;
+
         let tx_update = TransactionStoreUpdate::new(
             tx_result.into(),
             account,
@@ -510,6 +711,18 @@ impl<R: FeltRng> Client<R> {
             .map_err(ClientError::TransactionScriptError)
     }
 
+    // ACCOUNT INTERFACES
+    // --------------------------------------------------------------------------------------------
+
+    /// Registers an [AccountInterface], so that it's detected on accounts whose code contains all
+    /// of its procedures and used to build the scripts of their transactions.
+    ///
+    /// Interfaces are preferred in reverse order of registration when choosing how to send notes,
+    /// so a registered interface takes precedence over the [BasicAccountInterface]s.
+    pub fn register_account_interface(&mut self, interface: Arc<dyn AccountInterface>) {
+        self.account_interfaces.insert(0, interface);
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -633,23 +846,93 @@ impl<R: FeltRng> Client<R> {
         Ok(())
     }
 
+    /// Validates a transaction request executed by a faucet.
+    ///
+    /// A faucet can only distribute assets it issues, and a basic fungible faucet can't issue more
+    /// tokens than its maximum supply.
+    fn validate_faucet_request(
+        &self,
+        transaction_request: &TransactionRequest,
+        account: &Account,
+    ) -> Result<(), ClientError> {
+        let (fungible_balance_map, non_fungible_set) =
+            self.get_outgoing_assets(transaction_request);
+
+        let foreign_faucet_id = fungible_balance_map
+            .keys()
+            .copied()
+            .chain(non_fungible_set.iter().map(|asset| asset.faucet_id()))
+            .find(|faucet_id| *faucet_id != account.id());
+        if let Some(asset_faucet_id) = foreign_faucet_id {
+            return Err(TransactionRequestError::ForeignFaucetAsset {
+                faucet_id: account.id(),
+                asset_faucet_id,
+            }
+            .into());
+        }
+
+        let requested = fungible_balance_map.get(&account.id()).copied().unwrap_or(0);
+        if requested == 0 {
+            return Ok(());
+        }
+
+        // Faucets with custom code may store their supply elsewhere, so the supply is only
+        // checked for faucets that expose the basic fungible faucet interface
+        let Some(metadata_slot) =
+            BasicAccountInterface::BasicFungibleFaucet.storage_offset(account.code())
+        else {
+            return Ok(());
+        };
+
+        // The reserved faucet slot holds `[0, 0, 0, issued_supply]`, and the basic fungible
+        // faucet metadata is `[max_supply, decimals, token_symbol, 0]`
+        let issued = Word::from(account.storage().get_item(FAUCET_DATA_SLOT)?)[3].as_int();
+        let max_supply = Word::from(account.storage().get_item(metadata_slot)?)[0].as_int();
+
+        if issued.saturating_add(requested) > max_supply {
+            return Err(TransactionRequestError::MaxSupplyExceeded {
+                faucet_id: account.id(),
+                requested,
+                issued,
+                max_supply,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     /// Validates that the specified transaction request can be executed by the specified account.
     ///
     /// This function checks that the account has enough balance to cover the outgoing assets. This
     /// does't guarantee that the transaction will succeed, but it's useful to avoid submitting
     /// transactions that are guaranteed to fail.
+    ///
+    /// If the account has a [SpendingPolicy], the request is also checked against it, returning a
+    /// [ClientError::SpendingPolicyViolation] if the policy doesn't allow it.
     pub async fn validate_request(
         &self,
         account_id: AccountId,
         transaction_request: &TransactionRequest,
     ) -> Result<(), ClientError> {
         let (account, _) = self.get_account(account_id).await?;
+        self.validate_account_request(transaction_request, &account).await
+    }
+
+    /// Validates that the specified transaction request can be executed by the specified state of
+    /// an account.
+    async fn validate_account_request(
+        &self,
+        transaction_request: &TransactionRequest,
+        account: &Account,
+    ) -> Result<(), ClientError> {
         if account.is_faucet() {
-            // TODO(SantiagoPittella): Add faucet validations.
-            Ok(())
+            self.validate_faucet_request(transaction_request, account)?;
         } else {
-            self.validate_basic_account_request(transaction_request, &account).await
+            self.validate_basic_account_request(transaction_request, account).await?;
         }
+
+        self.validate_spending_policy(transaction_request, account).await
     }
 
     /// Retrieves the account capabilities for the specified account.
@@ -658,30 +941,17 @@ impl<R: FeltRng> Client<R> {
         account_id: AccountId,
     ) -> Result<AccountCapabilities, ClientError> {
         let account = self.get_account(account_id).await?.0;
-        let account_auth = self.get_account_auth(account_id).await?;
-
-        // TODO: we should check if the account actually exposes the interfaces we're trying to use
-        let account_capabilities = match account.account_type() {
-            AccountType::FungibleFaucet => AccountInterface::BasicFungibleFaucet,
-            AccountType::NonFungibleFaucet => todo!("Non fungible faucet not supported yet"),
-            AccountType::RegularAccountImmutableCode | AccountType::RegularAccountUpdatableCode => {
-                AccountInterface::BasicWallet
-            },
-        };
 
-        Ok(AccountCapabilities {
-            account_id,
-            auth: account_auth,
-            interfaces: account_capabilities,
-        })
+        Ok(AccountCapabilities::from_account(&account, &self.account_interfaces))
     }
 
     /// Fetches foreign public account data as needed and returns advice inputs and account codes.
     /// Additionally, it returns the block number pertinent to the foreign data.
     ///
-    /// Account data is retrieved for the node's current chain tip, so we need to check whether we
-    /// currently have the corresponding block header data. Otherwise, we additionally need to
-    /// retrieve it.
+    /// The verified data of foreign accounts is cached until the next sync that moves the client's
+    /// sync height, so later transactions reuse it (along with the block it was retrieved for)
+    /// instead of querying the node again. The cache only lives in memory, so it's lost whenever
+    /// the client is dropped.
     async fn get_foreign_account_inputs(
         &mut self,
         account_ids: &BTreeSet<AccountId>,
@@ -693,45 +963,149 @@ impl<R: FeltRng> Client<R> {
             return Ok((AdviceInputs::default(), vec![], None));
         }
 
-        // Fetch account proofs
-        let (block_num, account_proofs) =
-            self.rpc_api.get_account_proofs(account_ids, &[], true).await?;
+        // Every foreign account must be proven against the same block, so the cached data is
+        // only used if all of them were retrieved for the same block
+        let cached_block_nums = account_ids
+            .iter()
+            .map(|account_id| {
+                self.foreign_account_cache.get(account_id).map(|inputs| inputs.block_num)
+            })
+            .collect::<Option<BTreeSet<_>>>();
 
-        for account_proof in account_proofs.into_iter() {
-            let account_header = account_proof.account_header().expect("RPC response should include this field becuase `include_headers` is on and no code commitments were sent");
-            let account_code = account_proof.account_code().expect("RPC response should include this field becuase `include_headers` is on and no code commitments were sent");
-            let storage_header = account_proof.storage_header().expect("RPC response should include this field becuase `include_headers` is on and no code commitments were sent");
+        let block_num = match cached_block_nums {
+            Some(block_nums) if block_nums.len() == 1 => {
+                let block_num = block_nums.into_iter().next().expect("there's one block number");
+                info!("Reusing foreign account data retrieved for block {block_num}");
+                block_num
+            },
+            _ => self.fetch_foreign_account_inputs(account_ids).await?,
+        };
 
-            account_codes.push(account_code.clone());
+        for account_id in account_ids {
+            let inputs = self.foreign_account_cache.get(account_id).ok_or_else(|| {
+                RpcError::ExpectedDataMissing(format!("Proof of foreign account {account_id}"))
+            })?;
 
-            let merkle_path = account_proof.merkle_proof();
+            account_codes.push(inputs.account_code.clone());
 
-            // Extend advice inputs using the extracted data
+            // Extend advice inputs using the cached data
             extend_advice_inputs_for_account(
                 &mut advice_inputs,
-                account_header,
-                account_code,
-                storage_header,
-                merkle_path,
+                &inputs.account_header,
+                &inputs.account_code,
+                &inputs.storage_header,
+                &inputs.merkle_path,
             )?;
         }
 
+        Ok((advice_inputs, account_codes, Some(block_num)))
+    }
+
+    /// Retrieves and verifies the data of the foreign accounts from the node, caches it and
+    /// returns the block number it was retrieved for.
+    ///
+    /// Account data is retrieved for the node's current chain tip, so we need to check whether we
+    /// currently have the corresponding block header data. Otherwise, we additionally need to
+    /// retrieve it. The commitments of the account codes cached in the store are sent along with
+    /// the request, so the node only returns the codes that changed.
+    async fn fetch_foreign_account_inputs(
+        &mut self,
+        account_ids: &BTreeSet<AccountId>,
+    ) -> Result<u32, ClientError> {
+        let cached_codes = self
+            .store
+            .get_foreign_account_code(account_ids.iter().copied().collect())
+            .await?;
+        let known_codes = cached_codes
+            .values()
+            .map(|code| (code.commitment(), code.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let code_commitments = known_codes.keys().copied().collect::<Vec<_>>();
+
+        // Fetch account proofs
+        let (block_num, account_proofs) =
+            self.rpc_api.get_account_proofs(account_ids, &code_commitments, true).await?;
+
         // Optionally retrieve block header if we don't have it
-        if self.store.get_block_headers(&[block_num]).await?.is_empty() {
-            info!("Getting current block header data to execute transaction with foreign account requirements");
-            let summary = self.sync_state().await?;
-
-            if summary.block_num != block_num {
-                let mut current_partial_mmr = self.build_current_partial_mmr(true).await?;
-                self.get_and_store_authenticated_block(block_num, &mut current_partial_mmr)
-                    .await?;
+        let block_header = match self.store.get_block_headers(&[block_num]).await?.pop() {
+            Some((block_header, _)) => block_header,
+            None => {
+                info!("Getting current block header data to execute transaction with foreign account requirements");
+                let summary = self.sync_state().await?;
+
+                if summary.block_num != block_num {
+                    let mut current_partial_mmr = self.build_current_partial_mmr(true).await?;
+                    self.get_and_store_authenticated_block(block_num, &mut current_partial_mmr)
+                        .await?
+                } else {
+                    self.store.get_block_header_by_num(block_num).await?.0
+                }
+            },
+        };
+
+        let mut fetched_inputs = Vec::with_capacity(account_proofs.len());
+        for account_proof in account_proofs.into_iter() {
+            // The block header is authenticated, so the proof can be checked against its
+            // account root before using any of the account's data
+            verify_account_proof(&account_proof, &block_header)?;
+
+            let account_id = account_proof.account_id();
+            let account_header = account_proof
+                .account_header()
+                .expect("RPC response should include this field because `include_headers` is on")
+                .clone();
+            let storage_header = account_proof
+                .storage_header()
+                .expect("RPC response should include this field because `include_headers` is on")
+                .clone();
+
+            // The node only omits the code if its commitment is one of the known ones
+            let account_code = match account_proof.account_code() {
+                Some(account_code) => account_code.clone(),
+                None => known_codes.get(&account_header.code_commitment()).cloned().ok_or_else(
+                    || {
+                        RpcError::ExpectedDataMissing(format!(
+                            "Code of foreign account {account_id}"
+                        ))
+                    },
+                )?,
+            };
+
+            let cached_commitment = cached_codes.get(&account_id).map(AccountCode::commitment);
+            if cached_commitment != Some(account_code.commitment()) {
+                self.store.upsert_foreign_account_code(account_id, account_code.clone()).await?;
             }
+
+            fetched_inputs.push(ForeignAccountInputs {
+                block_num,
+                account_header,
+                account_code,
+                storage_header,
+                merkle_path: account_proof.merkle_proof().clone(),
+            });
         }
 
-        Ok((advice_inputs, account_codes, Some(block_num)))
+        for inputs in fetched_inputs {
+            self.foreign_account_cache.insert(inputs.account_header.id(), inputs);
+        }
+
+        Ok(block_num)
     }
 }
 
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// Verified data of a foreign account at a specific block, cached by the client to execute
+/// transactions against that block without querying the node again.
+pub(crate) struct ForeignAccountInputs {
+    block_num: u32,
+    account_header: AccountHeader,
+    account_code: AccountCode,
+    storage_header: AccountStorageHeader,
+    merkle_path: MerklePath,
+}
+
 // TESTING HELPERS
 // ================================================================================================
 
@@ -741,7 +1115,7 @@ impl<R: FeltRng> Client<R> {
         &mut self,
         tx_result: &TransactionResult,
     ) -> Result<ProvenTransaction, ClientError> {
-        self.prove_transaction(tx_result).await
+        self.prove_transaction(tx_result, self.tx_prover.clone()).await
     }
 
     pub async fn testing_submit_proven_transaction(
@@ -859,6 +1233,9 @@ pub fn notes_from_output(output_notes: &OutputNotes) -> impl Iterator<Item = &No
 
 #[cfg(test)]
 mod test {
+    use alloc::{boxed::Box, collections::BTreeSet, sync::Arc, vec::Vec};
+    use std::sync::Mutex;
+
     use miden_lib::{accounts::auth::RpoFalcon512, transaction::TransactionKernel};
     use miden_objects::{
         accounts::{
@@ -866,17 +1243,31 @@ mod test {
                 ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
                 ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
             },
-            AccountBuilder, AccountComponent, AccountData, StorageMap, StorageSlot,
+            AccountBuilder, AccountComponent, AccountData, AccountHeader, AccountStorageHeader,
+            StorageMap, StorageSlot, StorageSlotType,
         },
         assets::{Asset, FungibleAsset},
-        crypto::dsa::rpo_falcon512::SecretKey,
+        crypto::{
+            dsa::rpo_falcon512::SecretKey,
+            merkle::{MerklePath, NodeIndex},
+        },
         notes::NoteType,
         testing::account_component::BASIC_WALLET_CODE,
-        Felt, FieldElement, Word,
+        vm::AdviceInputs,
+        Digest, Felt, FieldElement, Word, ZERO,
     };
 
-    use super::{PaymentTransactionData, TransactionRequest};
-    use crate::mock::create_test_client;
+    use super::{
+        extend_advice_inputs_for_account, AccountInterface, BasicAccountInterface,
+        PaymentTransactionData, TransactionRequest, TransactionRequestError,
+        TransactionScriptBuilderError,
+    };
+    use crate::{
+        accounts::{AccountStorageMode, AccountTemplate},
+        mock::{create_test_client, create_test_client_with_rpc_api, MockRpcApi},
+        rpc::{InstrumentedRpcClient, NodeRpcClientEndpoint, RpcCallOutcome, RpcMetrics},
+        ClientError,
+    };
 
     #[tokio::test]
     async fn test_transaction_creates_two_notes() {
@@ -939,4 +1330,275 @@ mod test {
         // Prove and apply transaction
         client.testing_apply_transaction(tx_result.clone()).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_account_interfaces_are_inferred_from_code() {
+        let (mut client, _) = create_test_client().await;
+
+        let (wallet, _) = client
+            .new_account(AccountTemplate::BasicWallet {
+                mutable_code: false,
+                storage_mode: AccountStorageMode::Private,
+            })
+            .await
+            .unwrap();
+        let capabilities = client.get_account_capabilities(wallet.id()).await.unwrap();
+        assert_eq!(capabilities.interfaces.len(), 1);
+        assert_eq!(
+            capabilities.interfaces[0].procedure_roots(),
+            BasicAccountInterface::BasicWallet.procedure_roots()
+        );
+
+        // An account with a custom component and no wallet procedures can't send notes
+        let custom_component = AccountComponent::compile(
+            "
+            export.get_item_0
+                push.0
+                exec.::miden::account::get_item
+                swapw dropw
+            end
+            ",
+            TransactionKernel::assembler(),
+            vec![StorageSlot::Value(Word::default())],
+        )
+        .unwrap()
+        .with_supports_all_types();
+
+        let asset: Asset =
+            FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN.try_into().unwrap(), 10)
+                .unwrap()
+                .into();
+
+        let secret_key = SecretKey::new();
+        let (account, _) = AccountBuilder::new()
+            .init_seed([1u8; 32])
+            .nonce(Felt::ONE)
+            .with_component(custom_component)
+            .with_component(RpoFalcon512::new(secret_key.public_key()))
+            .with_assets([asset])
+            .build()
+            .unwrap();
+
+        client
+            .import_account(AccountData::new(
+                account.clone(),
+                None,
+                miden_objects::accounts::AuthSecretKey::RpoFalcon512(secret_key),
+            ))
+            .await
+            .unwrap();
+        client.sync_state().await.unwrap();
+        assert!(client
+            .get_account_capabilities(account.id())
+            .await
+            .unwrap()
+            .interfaces
+            .is_empty());
+
+        let tx_request = TransactionRequest::pay_to_id(
+            PaymentTransactionData::new(
+                vec![asset],
+                account.id(),
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
+            ),
+            None,
+            NoteType::Private,
+            client.rng(),
+        )
+        .unwrap();
+
+        let result = client.new_transaction(account.id(), tx_request).await;
+        assert!(matches!(
+            result,
+            Err(ClientError::TransactionRequestError(
+                TransactionRequestError::TransactionScriptBuilderError(
+                    TransactionScriptBuilderError::AccountCannotSendNotes(account_id)
+                )
+            )) if account_id == account.id()
+        ));
+    }
+
+    /// An interface made up of the procedures of a custom component, which can't send notes.
+    struct GetItemInterface(Vec<Digest>);
+
+    impl AccountInterface for GetItemInterface {
+        fn procedure_roots(&self) -> Vec<Digest> {
+            self.0.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_account_interfaces_are_detected() {
+        let (mut client, _) = create_test_client().await;
+
+        let custom_component = AccountComponent::compile(
+            "
+            export.get_item_0
+                push.0
+                exec.::miden::account::get_item
+                swapw dropw
+            end
+            ",
+            TransactionKernel::assembler(),
+            vec![StorageSlot::Value(Word::default())],
+        )
+        .unwrap()
+        .with_supports_all_types();
+        let procedure_roots = custom_component.mast_forest().procedure_digests().collect();
+
+        let secret_key = SecretKey::new();
+        let (account, _) = AccountBuilder::new()
+            .init_seed([2u8; 32])
+            .nonce(Felt::ONE)
+            .with_component(custom_component)
+            .with_component(RpoFalcon512::new(secret_key.public_key()))
+            .build()
+            .unwrap();
+        client
+            .import_account(AccountData::new(
+                account.clone(),
+                None,
+                miden_objects::accounts::AuthSecretKey::RpoFalcon512(secret_key),
+            ))
+            .await
+            .unwrap();
+        assert!(client
+            .get_account_capabilities(account.id())
+            .await
+            .unwrap()
+            .interfaces
+            .is_empty());
+
+        client.register_account_interface(Arc::new(GetItemInterface(procedure_roots)));
+
+        let capabilities = client.get_account_capabilities(account.id()).await.unwrap();
+        assert_eq!(capabilities.interfaces.len(), 1);
+        assert!(!capabilities.interfaces[0].can_send_notes());
+        assert_eq!(capabilities.interfaces[0].storage_offset(account.code()), Some(0));
+    }
+
+    #[test]
+    fn test_foreign_account_data_is_added_to_advice_inputs() {
+        let value: Word = [Felt::new(2); 4];
+        let wallet_component = AccountComponent::compile(
+            BASIC_WALLET_CODE,
+            TransactionKernel::assembler(),
+            vec![StorageSlot::Value(value)],
+        )
+        .unwrap()
+        .with_supports_all_types();
+
+        let (account, _) = AccountBuilder::new()
+            .init_seed([2u8; 32])
+            .nonce(Felt::ONE)
+            .with_component(wallet_component)
+            .with_component(RpoFalcon512::new(SecretKey::new().public_key()))
+            .build()
+            .unwrap();
+
+        let account_id = account.id();
+        let account_header = AccountHeader::from(&account);
+        let storage_header = AccountStorageHeader::new(vec![(StorageSlotType::Value, value)]);
+        // Path of the account in the account tree, which has a depth of 64
+        let merkle_path = MerklePath::new(vec![Digest::default(); 64]);
+
+        let mut advice_inputs = AdviceInputs::default();
+        extend_advice_inputs_for_account(
+            &mut advice_inputs,
+            &account_header,
+            account.code(),
+            &storage_header,
+            &merkle_path,
+        )
+        .unwrap();
+
+        // Account data is mapped by the account ID, its storage and its code commitments
+        let foreign_id_root = Digest::from([account_id.into(), ZERO, ZERO, ZERO]);
+        let account_data = advice_inputs.mapped_values(&foreign_id_root).unwrap();
+        assert_eq!(&account_data[3], &account_header.nonce());
+        assert_eq!(&account_data[8..12], account_header.storage_commitment().as_elements());
+        assert_eq!(
+            &advice_inputs.mapped_values(&account_header.storage_commitment()).unwrap()[0..4],
+            value.as_slice()
+        );
+        assert_eq!(
+            advice_inputs.mapped_values(&account_header.code_commitment()),
+            Some(account.code().as_elements().as_slice())
+        );
+
+        // The account can be opened against the account root
+        let account_root =
+            merkle_path.compute_root(account_id.into(), account_header.hash()).unwrap();
+        let account_index = NodeIndex::new(64, account_id.into()).unwrap();
+        assert_eq!(
+            advice_inputs.merkle_store().get_node(account_root, account_index).unwrap(),
+            account_header.hash()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_foreign_account_data_is_reused() {
+        #[derive(Default)]
+        struct RecordingMetrics {
+            endpoints: Mutex<Vec<NodeRpcClientEndpoint>>,
+        }
+
+        impl RpcMetrics for RecordingMetrics {
+            fn record(
+                &self,
+                endpoint: NodeRpcClientEndpoint,
+                _request_size: usize,
+                _duration: core::time::Duration,
+                _outcome: RpcCallOutcome<'_>,
+            ) {
+                self.endpoints.lock().unwrap().push(endpoint);
+            }
+        }
+
+        let component = AccountComponent::compile(
+            BASIC_WALLET_CODE,
+            TransactionKernel::assembler(),
+            vec![StorageSlot::Value(Word::default())],
+        )
+        .unwrap()
+        .with_supports_all_types();
+
+        let (foreign_account, _) = AccountBuilder::new()
+            .init_seed([3u8; 32])
+            .nonce(Felt::ONE)
+            .storage_mode(AccountStorageMode::Public)
+            .with_component(component)
+            .with_component(RpoFalcon512::new(SecretKey::new().public_key()))
+            .build()
+            .unwrap();
+
+        let metrics = Arc::new(RecordingMetrics::default());
+        let rpc_api = MockRpcApi::with_public_accounts(vec![foreign_account.clone()]);
+        let mut client = create_test_client_with_rpc_api(Box::new(
+            InstrumentedRpcClient::new(rpc_api).with_metrics(metrics.clone()),
+        ))
+        .await;
+        client.sync_state().await.unwrap();
+
+        let proof_requests = || {
+            metrics
+                .endpoints
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|endpoint| **endpoint == NodeRpcClientEndpoint::GetAccountProofs)
+                .count()
+        };
+
+        let foreign_accounts = BTreeSet::from([foreign_account.id()]);
+        let (_, _, block_num) = client.get_foreign_account_inputs(&foreign_accounts).await.unwrap();
+        assert_eq!(proof_requests(), 1);
+
+        // A later transaction reads the same data, so the node isn't queried again
+        let (_, account_codes, cached_block_num) =
+            client.get_foreign_account_inputs(&foreign_accounts).await.unwrap();
+        assert_eq!(proof_requests(), 1);
+        assert_eq!(cached_block_num, block_num);
+        assert_eq!(account_codes, vec![foreign_account.code().clone()]);
+    }
 }
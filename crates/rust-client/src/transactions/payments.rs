@@ -0,0 +1,441 @@
+//! Provides recurring payments, which the client executes and submits once they are due.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    accounts::AccountId, assets::Asset, notes::NoteType, transaction::TransactionId,
+};
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use tracing::{info, warn};
+
+use super::{pipeline::MAX_BACKOFF_EXPONENT, PaymentTransactionData, TransactionRequest};
+use crate::{store::TransactionFilter, Client, ClientError, FeltRng};
+
+/// Maximum number of attempts kept in the history of a [RecurringPayment]. Older attempts are
+/// dropped once the history is full.
+pub const MAX_PAYMENT_HISTORY: usize = 100;
+
+// PAYMENT SCHEDULE
+// ================================================================================================
+
+/// The blocks at which a [RecurringPayment] is due.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PaymentSchedule {
+    start_block: u32,
+    interval: Option<u32>,
+}
+
+impl PaymentSchedule {
+    /// Returns a schedule for a single payment, due once the client syncs to `block_num`.
+    pub fn once_at(block_num: u32) -> Self {
+        Self { start_block: block_num, interval: None }
+    }
+
+    /// Returns a schedule for a payment due every `interval` blocks, starting at `start_block`.
+    /// An interval of 0 is treated as 1.
+    pub fn every(interval: u32, start_block: u32) -> Self {
+        Self {
+            start_block,
+            interval: Some(interval.max(1)),
+        }
+    }
+
+    /// Returns the block at which the first payment is due.
+    pub fn start_block(&self) -> u32 {
+        self.start_block
+    }
+
+    /// Returns the number of blocks between payments, or `None` for a single payment.
+    pub fn interval(&self) -> Option<u32> {
+        self.interval
+    }
+
+    /// Returns the first block after `block_num` at which a payment following the one due at
+    /// `due_block` is due, or `None` if there are no more payments.
+    fn next_due_block(&self, due_block: u32, block_num: u32) -> Option<u32> {
+        let interval = self.interval?;
+        let elapsed_intervals = block_num.saturating_sub(due_block) / interval + 1;
+
+        Some(due_block.saturating_add(elapsed_intervals.saturating_mul(interval)))
+    }
+}
+
+// RECURRING PAYMENT
+// ================================================================================================
+
+/// A payment the client makes on a [PaymentSchedule] by executing a P2ID transaction built with
+/// [TransactionRequest::pay_to_id].
+///
+/// Payments are run by [Client::run_due_payments]. If the client doesn't run them for several
+/// intervals, the missed payments are made only once, and the next payment is scheduled for the
+/// first interval after the current sync height.
+///
+/// Failed payments are retried with the same backoff as queued transactions: the `n`-th retry in
+/// a row is made `2^(n-1)` blocks after the previous attempt, up to 64 blocks.
+#[derive(Clone, Debug)]
+pub struct RecurringPayment {
+    id: u32,
+    payment_data: PaymentTransactionData,
+    note_type: NoteType,
+    schedule: PaymentSchedule,
+    next_block: Option<u32>,
+    /// Transaction of a payment that was stored but whose attempt wasn't recorded yet.
+    in_flight_transaction: Option<TransactionId>,
+    history: Vec<PaymentExecution>,
+}
+
+impl RecurringPayment {
+    /// Returns the ID the payment was stored with.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the sender, recipient and assets of the payment.
+    pub fn payment_data(&self) -> &PaymentTransactionData {
+        &self.payment_data
+    }
+
+    /// Returns the type of the notes created by the payment.
+    pub fn note_type(&self) -> NoteType {
+        self.note_type
+    }
+
+    /// Returns the schedule of the payment.
+    pub fn schedule(&self) -> PaymentSchedule {
+        self.schedule
+    }
+
+    /// Returns the block at which the next payment is due, or `None` if all the payments were
+    /// made.
+    pub fn next_block(&self) -> Option<u32> {
+        self.next_block
+    }
+
+    /// Returns whether a payment is due at the specified block, taking into account the backoff
+    /// after failed attempts.
+    pub fn is_due(&self, block_num: u32) -> bool {
+        self.next_block.is_some_and(|next_block| next_block <= block_num)
+            && self.next_retry_block().is_none_or(|retry_block| retry_block <= block_num)
+    }
+
+    /// Returns the block from which a failed payment can be retried, or `None` if the last
+    /// attempt didn't fail.
+    pub fn next_retry_block(&self) -> Option<u32> {
+        let failures = self
+            .history
+            .iter()
+            .rev()
+            .take_while(|execution| matches!(execution.outcome, PaymentOutcome::Failed(_)))
+            .count() as u32;
+        if failures == 0 {
+            return None;
+        }
+
+        let backoff = 1 << (failures - 1).min(MAX_BACKOFF_EXPONENT);
+        self.history.last().map(|execution| execution.block_num.saturating_add(backoff))
+    }
+
+    /// Returns the transaction of a payment that was stored, but whose outcome wasn't recorded
+    /// yet, for example because the client stopped while submitting it.
+    pub fn in_flight_transaction(&self) -> Option<TransactionId> {
+        self.in_flight_transaction
+    }
+
+    /// Returns the latest attempts to make the payment, in the order they were made. Only the
+    /// last [MAX_PAYMENT_HISTORY] attempts are kept.
+    pub fn history(&self) -> &[PaymentExecution] {
+        &self.history
+    }
+
+    /// Records an attempt to make the payment at the specified block. Successful payments move
+    /// the payment to its next due block, while failed ones are retried after a backoff.
+    fn record_execution(&mut self, block_num: u32, outcome: PaymentOutcome) -> PaymentExecution {
+        if let (PaymentOutcome::Submitted(_), Some(due_block)) = (&outcome, self.next_block) {
+            self.next_block = self.schedule.next_due_block(due_block, block_num);
+        }
+        self.in_flight_transaction = None;
+
+        let execution = PaymentExecution { block_num, outcome };
+        self.history.push(execution.clone());
+        if self.history.len() > MAX_PAYMENT_HISTORY {
+            let excess = self.history.len() - MAX_PAYMENT_HISTORY;
+            self.history.drain(..excess);
+        }
+        execution
+    }
+}
+
+impl Serializable for RecurringPayment {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.id.write_into(target);
+        self.payment_data.account_id().write_into(target);
+        self.payment_data.target_account_id().write_into(target);
+        self.payment_data.assets().write_into(target);
+        self.note_type.write_into(target);
+        self.schedule.start_block.write_into(target);
+        self.schedule.interval.write_into(target);
+        self.next_block.write_into(target);
+        self.in_flight_transaction.write_into(target);
+        self.history.write_into(target);
+    }
+}
+
+impl Deserializable for RecurringPayment {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = u32::read_from(source)?;
+        let sender_account_id = AccountId::read_from(source)?;
+        let target_account_id = AccountId::read_from(source)?;
+        let assets = Vec::<Asset>::read_from(source)?;
+        let note_type = NoteType::read_from(source)?;
+        let start_block = u32::read_from(source)?;
+        let interval = Option::<u32>::read_from(source)?;
+        let next_block = Option::<u32>::read_from(source)?;
+        let in_flight_transaction = Option::<TransactionId>::read_from(source)?;
+        let history = Vec::<PaymentExecution>::read_from(source)?;
+
+        Ok(Self {
+            id,
+            payment_data: PaymentTransactionData::new(assets, sender_account_id, target_account_id),
+            note_type,
+            schedule: PaymentSchedule { start_block, interval },
+            next_block,
+            in_flight_transaction,
+            history,
+        })
+    }
+}
+
+// PAYMENT EXECUTION
+// ================================================================================================
+
+/// An attempt to make a [RecurringPayment].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentExecution {
+    /// Sync height of the client when the payment was attempted.
+    pub block_num: u32,
+    /// Result of the attempt.
+    pub outcome: PaymentOutcome,
+}
+
+/// The result of a [PaymentExecution].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaymentOutcome {
+    /// The transaction of the payment was executed and submitted, or queued for resubmission
+    /// because the node couldn't be reached.
+    Submitted(TransactionId),
+    /// The transaction of the payment couldn't be executed or was rejected by the node.
+    Failed(String),
+}
+
+impl Serializable for PaymentExecution {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_num.write_into(target);
+        match &self.outcome {
+            PaymentOutcome::Submitted(transaction_id) => {
+                0u8.write_into(target);
+                transaction_id.write_into(target);
+            },
+            PaymentOutcome::Failed(err) => {
+                1u8.write_into(target);
+                err.as_bytes().to_vec().write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for PaymentExecution {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let block_num = u32::read_from(source)?;
+        let outcome = match u8::read_from(source)? {
+            0 => PaymentOutcome::Submitted(TransactionId::read_from(source)?),
+            1 => {
+                let err = String::from_utf8(Vec::<u8>::read_from(source)?)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+                PaymentOutcome::Failed(err)
+            },
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid payment outcome tag {tag}"
+                )))
+            },
+        };
+
+        Ok(Self { block_num, outcome })
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // RECURRING PAYMENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Stores a payment to be made on the specified schedule, and returns it with the ID it was
+    /// stored with. The payment is made by [Client::run_due_payments].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sender account is not tracked by the client.
+    pub async fn schedule_payment(
+        &self,
+        payment_data: PaymentTransactionData,
+        note_type: NoteType,
+        schedule: PaymentSchedule,
+    ) -> Result<RecurringPayment, ClientError> {
+        self.get_account_header_by_id(payment_data.account_id()).await?;
+
+        let id = self
+            .store
+            .get_recurring_payments()
+            .await?
+            .iter()
+            .map(|payment| payment.id + 1)
+            .max()
+            .unwrap_or(0);
+
+        let payment = RecurringPayment {
+            id,
+            payment_data,
+            note_type,
+            schedule,
+            next_block: Some(schedule.start_block),
+            in_flight_transaction: None,
+            history: Vec::new(),
+        };
+        self.store.upsert_recurring_payment(&payment).await?;
+
+        Ok(payment)
+    }
+
+    /// Retrieves the recurring payments stored in the client, including the ones that were
+    /// already completed.
+    pub async fn get_recurring_payments(&self) -> Result<Vec<RecurringPayment>, ClientError> {
+        self.store.get_recurring_payments().await.map_err(|err| err.into())
+    }
+
+    /// Removes a recurring payment, so no more payments are made.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::RecurringPaymentNotFound] if no payment is stored with the ID.
+    pub async fn cancel_recurring_payment(&self, payment_id: u32) -> Result<(), ClientError> {
+        if !self
+            .store
+            .get_recurring_payments()
+            .await?
+            .iter()
+            .any(|payment| payment.id == payment_id)
+        {
+            return Err(ClientError::RecurringPaymentNotFound(payment_id));
+        }
+
+        self.store.remove_recurring_payment(payment_id).await.map_err(|err| err.into())
+    }
+
+    /// Executes and submits the recurring payments that are due at the current sync height, and
+    /// records the attempts in their history. Returns the attempts made, along with the ID of
+    /// their payment.
+    ///
+    /// Failed payments are logged and don't make this function fail. They stay due, and are
+    /// retried once their backoff elapses. A payment whose transaction was stored by an earlier
+    /// run that didn't record its outcome isn't made again while that transaction is pending.
+    /// This function is meant to be called after [Client::sync_state].
+    pub async fn run_due_payments(&mut self) -> Result<Vec<(u32, PaymentExecution)>, ClientError> {
+        let sync_height = self.get_sync_height().await?;
+        let mut executions = vec![];
+
+        for mut payment in self.store.get_recurring_payments().await? {
+            if !payment.is_due(sync_height) {
+                continue;
+            }
+
+            let outcome = match self.make_payment(&mut payment).await {
+                Ok(transaction_id) => {
+                    info!("Recurring payment {} submitted in {transaction_id}.", payment.id);
+                    PaymentOutcome::Submitted(transaction_id)
+                },
+                Err(err) => {
+                    warn!("Recurring payment {} failed: {err}", payment.id);
+                    PaymentOutcome::Failed(err.to_string())
+                },
+            };
+
+            let execution = payment.record_execution(sync_height, outcome);
+            self.store.upsert_recurring_payment(&payment).await?;
+            executions.push((payment.id, execution));
+        }
+
+        Ok(executions)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes and submits the transaction of a payment, returning its ID. The transaction is
+    /// stored before it's submitted, so it's queued for resubmission if the node can't be
+    /// reached, and the payment is marked as in flight with it until its outcome is recorded.
+    ///
+    /// If the payment is already in flight, its transaction is submitted instead if it's still
+    /// stored, and returned if it was applied. A new transaction is only made if the previous
+    /// one was discarded.
+    async fn make_payment(
+        &mut self,
+        payment: &mut RecurringPayment,
+    ) -> Result<TransactionId, ClientError> {
+        if let Some(transaction_id) = payment.in_flight_transaction {
+            if self.store.get_unsubmitted_transaction(transaction_id).await?.is_some() {
+                return self.submit_payment_transaction(transaction_id).await;
+            }
+
+            let applied = self
+                .get_transactions(TransactionFilter::All)
+                .await?
+                .iter()
+                .any(|transaction| transaction.id == transaction_id);
+            if applied {
+                return Ok(transaction_id);
+            }
+        }
+
+        let transaction_request = TransactionRequest::pay_to_id(
+            payment.payment_data.clone(),
+            None,
+            payment.note_type,
+            self.rng(),
+        )?;
+        let tx_result = self
+            .new_transaction(payment.payment_data.account_id(), transaction_request)
+            .await?;
+        let transaction_id = self.save_transaction(tx_result).await?;
+
+        payment.in_flight_transaction = Some(transaction_id);
+        self.store.upsert_recurring_payment(payment).await?;
+
+        self.submit_payment_transaction(transaction_id).await
+    }
+
+    /// Submits the stored transaction of a payment, returning its ID if it was submitted or
+    /// queued for resubmission.
+    async fn submit_payment_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<TransactionId, ClientError> {
+        let transaction = self.get_unsubmitted_transaction(transaction_id).await?;
+        if transaction.is_queued() {
+            return Ok(transaction_id);
+        }
+
+        if let Err(err) = self.submit_stored_transaction(transaction_id).await {
+            let queued = self
+                .get_unsubmitted_transaction(transaction_id)
+                .await
+                .is_ok_and(|transaction| transaction.is_queued());
+            if !queued {
+                return Err(err);
+            }
+        }
+
+        Ok(transaction_id)
+    }
+}
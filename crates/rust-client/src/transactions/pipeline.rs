@@ -0,0 +1,472 @@
+//! Provides APIs to run the execute, prove and submit stages of a transaction separately,
+//! persisting the transaction between them.
+
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    transaction::{ProvenTransaction, TransactionId, TransactionWitness},
+    Digest,
+};
+use tracing::{info, warn};
+
+use super::{TransactionProver, TransactionRecord, TransactionResult, TransactionStatus};
+use crate::{
+    rpc::{AccountDetails, RpcError},
+    Client, ClientError, FeltRng,
+};
+
+/// Maximum exponent of the backoff between resubmission attempts. The `n`-th attempt is made
+/// `2^(n-1)` blocks after the previous one, up to `2^MAX_BACKOFF_EXPONENT` blocks.
+pub(super) const MAX_BACKOFF_EXPONENT: u32 = 6;
+
+// UNSUBMITTED TRANSACTION
+// ================================================================================================
+
+/// A transaction that was executed by the client and stored, but not yet submitted to the network.
+///
+/// The transaction may also hold its [ProvenTransaction] if it was already proven, either locally
+/// or externally from an exported [TransactionWitness]. Proven transactions whose submission
+/// failed without the node rejecting them are queued, and get resubmitted with
+/// [Client::resubmit_queued_transactions].
+#[derive(Clone, Debug)]
+pub struct UnsubmittedTransaction {
+    tx_result: TransactionResult,
+    proven_transaction: Option<ProvenTransaction>,
+    submission_attempts: u32,
+    last_attempt_block: Option<u32>,
+}
+
+impl UnsubmittedTransaction {
+    /// Returns a new [UnsubmittedTransaction] for an executed transaction that was not proven yet.
+    pub fn new(tx_result: TransactionResult) -> Self {
+        Self {
+            tx_result,
+            proven_transaction: None,
+            submission_attempts: 0,
+            last_attempt_block: None,
+        }
+    }
+
+    /// Returns an [UnsubmittedTransaction] built from its parts. The proven transaction is
+    /// expected to correspond to the executed transaction.
+    pub(crate) fn from_parts(
+        tx_result: TransactionResult,
+        proven_transaction: Option<ProvenTransaction>,
+        submission_attempts: u32,
+        last_attempt_block: Option<u32>,
+    ) -> Self {
+        Self {
+            tx_result,
+            proven_transaction,
+            submission_attempts,
+            last_attempt_block,
+        }
+    }
+
+    /// Returns the transaction with its proof set to `proven_transaction`.
+    pub(crate) fn with_proven_transaction(self, proven_transaction: ProvenTransaction) -> Self {
+        Self {
+            proven_transaction: Some(proven_transaction),
+            ..self
+        }
+    }
+
+    /// Returns the ID of the transaction.
+    pub fn id(&self) -> TransactionId {
+        self.tx_result.executed_transaction().id()
+    }
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.tx_result.executed_transaction().account_id()
+    }
+
+    /// Returns the result of the transaction execution.
+    pub fn tx_result(&self) -> &TransactionResult {
+        &self.tx_result
+    }
+
+    /// Returns the proven transaction, if the transaction was already proven.
+    pub fn proven_transaction(&self) -> Option<&ProvenTransaction> {
+        self.proven_transaction.as_ref()
+    }
+
+    /// Returns the number of failed attempts to submit the transaction.
+    pub fn submission_attempts(&self) -> u32 {
+        self.submission_attempts
+    }
+
+    /// Returns the sync height of the client when the last submission attempt failed.
+    pub fn last_attempt_block(&self) -> Option<u32> {
+        self.last_attempt_block
+    }
+
+    /// Returns whether the transaction is queued for resubmission.
+    pub fn is_queued(&self) -> bool {
+        self.proven_transaction.is_some() && self.submission_attempts > 0
+    }
+
+    /// Returns the block from which the transaction can be resubmitted, if it's queued.
+    pub fn next_attempt_block(&self) -> Option<u32> {
+        if !self.is_queued() {
+            return None;
+        }
+
+        let backoff = 1 << (self.submission_attempts - 1).min(MAX_BACKOFF_EXPONENT);
+        self.last_attempt_block.map(|block_num| block_num.saturating_add(backoff))
+    }
+
+    /// Returns the stage the transaction is in, which is either [TransactionStatus::Executed],
+    /// [TransactionStatus::Proven] or [TransactionStatus::Queued].
+    pub fn status(&self) -> TransactionStatus {
+        if self.is_queued() {
+            TransactionStatus::Queued
+        } else if self.proven_transaction.is_some() {
+            TransactionStatus::Proven
+        } else {
+            TransactionStatus::Executed
+        }
+    }
+}
+
+impl From<&UnsubmittedTransaction> for TransactionRecord {
+    fn from(transaction: &UnsubmittedTransaction) -> Self {
+        let executed_transaction = transaction.tx_result.executed_transaction();
+        let input_note_nullifiers: Vec<Digest> = executed_transaction
+            .input_notes()
+            .iter()
+            .map(|note| note.nullifier().inner())
+            .collect();
+
+        TransactionRecord::new(
+            transaction.id(),
+            transaction.account_id(),
+            executed_transaction.initial_account().hash(),
+            executed_transaction.final_account().hash(),
+            input_note_nullifiers,
+            executed_transaction.output_notes().clone(),
+            executed_transaction.tx_args().tx_script().cloned(),
+            transaction.tx_result.block_num(),
+            transaction.status(),
+        )
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // TRANSACTION STAGES
+    // --------------------------------------------------------------------------------------------
+
+    /// Retrieves the transactions that were executed but not yet submitted to the network.
+    pub async fn get_unsubmitted_transactions(
+        &self,
+    ) -> Result<Vec<UnsubmittedTransaction>, ClientError> {
+        self.store.get_unsubmitted_transactions().await.map_err(|err| err.into())
+    }
+
+    /// Retrieves the unsubmitted transaction with the specified ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::UnsubmittedTransactionNotFound] if no such transaction is stored.
+    pub async fn get_unsubmitted_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<UnsubmittedTransaction, ClientError> {
+        self.store
+            .get_unsubmitted_transaction(transaction_id)
+            .await?
+            .ok_or(ClientError::UnsubmittedTransactionNotFound(transaction_id))
+    }
+
+    /// Stores an executed transaction so that it can be proven and submitted later, possibly
+    /// after the process is restarted. Returns the ID of the stored transaction.
+    ///
+    /// The local state is not updated until the transaction is submitted. If the account has a
+    /// [SpendingPolicy](crate::transactions::SpendingPolicy), the notes created by the transaction
+    /// are checked against it, and the amounts they send count towards its daily limits until the
+    /// transaction is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::SpendingPolicyViolation] if the notes created by the transaction
+    /// aren't allowed by the spending policy of the account.
+    pub async fn save_transaction(
+        &self,
+        tx_result: TransactionResult,
+    ) -> Result<TransactionId, ClientError> {
+        self.reserve_spending(&tx_result).await?;
+        let transaction = UnsubmittedTransaction::new(tx_result);
+        self.store.upsert_unsubmitted_transaction(&transaction).await?;
+
+        Ok(transaction.id())
+    }
+
+    /// Proves a stored transaction with the client's prover and stores the resulting proof. If the
+    /// transaction was already proven, the stored proof is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::UnsubmittedTransactionNotFound] if no such transaction is stored.
+    pub async fn prove_stored_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<ProvenTransaction, ClientError> {
+        let transaction = self.get_unsubmitted_transaction(transaction_id).await?;
+        let transaction =
+            self.prove_unsubmitted_transaction(transaction, self.tx_prover.clone()).await?;
+
+        Ok(transaction.proven_transaction.expect("transaction should be proven"))
+    }
+
+    /// Returns the [TransactionWitness] of a stored transaction, which can be used to prove it
+    /// outside of the client. The resulting proof can be imported with
+    /// [Client::import_proven_transaction].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::UnsubmittedTransactionNotFound] if no such transaction is stored.
+    pub async fn export_transaction_witness(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<TransactionWitness, ClientError> {
+        let transaction = self.get_unsubmitted_transaction(transaction_id).await?;
+
+        Ok(transaction.tx_result.executed_transaction().clone().into())
+    }
+
+    /// Stores a proof generated outside of the client for a stored transaction. Any previously
+    /// stored proof is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::UnsubmittedTransactionNotFound] if the proven transaction does not
+    /// correspond to a stored transaction.
+    pub async fn import_proven_transaction(
+        &self,
+        proven_transaction: ProvenTransaction,
+    ) -> Result<TransactionId, ClientError> {
+        let transaction = self.get_unsubmitted_transaction(proven_transaction.id()).await?;
+        let transaction = UnsubmittedTransaction {
+            proven_transaction: Some(proven_transaction),
+            ..transaction
+        };
+        self.store.upsert_unsubmitted_transaction(&transaction).await?;
+
+        Ok(transaction.id())
+    }
+
+    /// Submits a stored transaction to the network and applies it to the local state. The
+    /// transaction is proven first if no proof was stored for it.
+    ///
+    /// If the submission fails without the node rejecting the transaction (for example, because
+    /// the node can't be reached), the transaction (and its proof) stays stored and is queued for
+    /// resubmission. If the node rejects the transaction, it's removed from the store, unless an
+    /// earlier submission may have reached the node: the rejection is then checked against the
+    /// node's state of the account, and the transaction is applied if it turns out to be
+    /// committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::UnsubmittedTransactionNotFound] if no such transaction is stored.
+    pub async fn submit_stored_transaction(
+        &mut self,
+        transaction_id: TransactionId,
+    ) -> Result<(), ClientError> {
+        self.submit_stored_transaction_with_prover(transaction_id, self.tx_prover.clone())
+            .await
+    }
+
+    /// Same as [Client::submit_stored_transaction], but the transaction is proven with
+    /// `tx_prover` instead of the prover the client was created with, if it wasn't proven yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ClientError::UnsubmittedTransactionNotFound] if no such transaction is stored.
+    pub async fn submit_stored_transaction_with_prover(
+        &mut self,
+        transaction_id: TransactionId,
+        tx_prover: Arc<dyn TransactionProver>,
+    ) -> Result<(), ClientError> {
+        let transaction = self.get_unsubmitted_transaction(transaction_id).await?;
+        let transaction = self.prove_unsubmitted_transaction(transaction, tx_prover).await?;
+        let proven_transaction =
+            transaction.proven_transaction.clone().expect("transaction should be proven");
+
+        if let Err(err) = self.submit_proven_transaction(proven_transaction).await {
+            let rejected = matches!(err, ClientError::RpcError(RpcError::TransactionRejected(_)));
+            if !rejected || !matches!(self.is_committed_on_node(&transaction).await, Ok(true)) {
+                self.record_failed_submission(transaction, &err).await?;
+                return Err(err);
+            }
+
+            info!(
+                "Transaction {} was already committed by an earlier submission.",
+                transaction.id()
+            );
+        }
+
+        self.apply_transaction(transaction.tx_result).await
+    }
+
+    /// Resubmits the queued transactions whose backoff has elapsed, and discards the ones that
+    /// expired. Returns the IDs of the transactions that were submitted.
+    ///
+    /// Failed resubmissions are logged and don't make this function fail. The client doesn't call
+    /// this function on its own, so it should be called periodically, for example after every
+    /// [Client::sync_state]. Expired transactions are only discarded if the node's state of their
+    /// account shows they weren't committed, and are applied otherwise. If that state can't be
+    /// retrieved, they're kept until a later call can check it.
+    pub async fn resubmit_queued_transactions(
+        &mut self,
+    ) -> Result<Vec<TransactionId>, ClientError> {
+        let sync_height = self.get_sync_height().await?;
+        let mut submitted_transactions = vec![];
+
+        for transaction in self.get_unsubmitted_transactions().await? {
+            if !transaction.is_queued() {
+                continue;
+            }
+
+            let transaction_id = transaction.id();
+            let expiration_block_num = transaction
+                .proven_transaction()
+                .expect("queued transactions are proven")
+                .expiration_block_num();
+
+            if expiration_block_num <= sync_height {
+                match self.is_committed_on_node(&transaction).await {
+                    Ok(true) => {
+                        info!("Queued transaction {transaction_id} was committed, applying it.");
+                        match self.apply_transaction(transaction.tx_result).await {
+                            Ok(()) => submitted_transactions.push(transaction_id),
+                            Err(err) => {
+                                warn!("Failed to apply transaction {transaction_id}: {err}")
+                            },
+                        }
+                    },
+                    Ok(false) => {
+                        info!("Queued transaction {transaction_id} expired, discarding it.");
+                        self.remove_stored_transaction(&transaction).await?;
+                    },
+                    Err(err) => warn!(
+                        "Failed to check whether expired transaction {transaction_id} was \
+                        committed, keeping it: {err}"
+                    ),
+                }
+                continue;
+            }
+
+            if transaction
+                .next_attempt_block()
+                .is_some_and(|block_num| block_num > sync_height)
+            {
+                continue;
+            }
+
+            match self.submit_stored_transaction(transaction_id).await {
+                Ok(()) => submitted_transactions.push(transaction_id),
+                Err(err) => warn!("Failed to resubmit transaction {transaction_id}: {err}"),
+            }
+        }
+
+        Ok(submitted_transactions)
+    }
+
+    /// Removes a stored transaction without submitting it.
+    pub async fn discard_stored_transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<(), ClientError> {
+        match self.store.get_unsubmitted_transaction(transaction_id).await? {
+            Some(transaction) => self.remove_stored_transaction(&transaction).await,
+            None => Ok(()),
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves the transaction and stores its proof, unless it was already proven.
+    async fn prove_unsubmitted_transaction(
+        &mut self,
+        transaction: UnsubmittedTransaction,
+        tx_prover: Arc<dyn TransactionProver>,
+    ) -> Result<UnsubmittedTransaction, ClientError> {
+        if transaction.proven_transaction.is_some() {
+            return Ok(transaction);
+        }
+
+        let proven_transaction = self.prove_transaction(&transaction.tx_result, tx_prover).await?;
+        let transaction = UnsubmittedTransaction {
+            proven_transaction: Some(proven_transaction),
+            ..transaction
+        };
+        self.store.upsert_unsubmitted_transaction(&transaction).await?;
+
+        Ok(transaction)
+    }
+
+    /// Removes a stored transaction and releases the spending reserved for it.
+    pub(super) async fn remove_stored_transaction(
+        &self,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<(), ClientError> {
+        let account_id = transaction.tx_result.executed_transaction().account_id();
+        self.release_spending(account_id, transaction.id()).await?;
+        self.store.remove_unsubmitted_transaction(transaction.id()).await?;
+
+        Ok(())
+    }
+
+    /// Removes the transaction if the node rejected its first submission, and queues it for
+    /// resubmission otherwise.
+    ///
+    /// Only a definite rejection discards the transaction: transient errors of the node may
+    /// succeed on a later attempt. A rejection after an earlier attempt isn't definite either, as
+    /// the earlier attempt may have timed out after reaching the node, and the transaction is
+    /// then only discarded once it expires.
+    async fn record_failed_submission(
+        &self,
+        transaction: UnsubmittedTransaction,
+        err: &ClientError,
+    ) -> Result<(), ClientError> {
+        let rejected = matches!(err, ClientError::RpcError(RpcError::TransactionRejected(_)));
+
+        if rejected && transaction.submission_attempts == 0 {
+            info!("Transaction {} was rejected, discarding it.", transaction.id());
+            self.remove_stored_transaction(&transaction).await?;
+        } else {
+            let transaction = UnsubmittedTransaction {
+                submission_attempts: transaction.submission_attempts + 1,
+                last_attempt_block: Some(self.get_sync_height().await?),
+                ..transaction
+            };
+            info!("Transaction {} queued for resubmission.", transaction.id());
+            self.store.upsert_unsubmitted_transaction(&transaction).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the node's committed state of the transaction's account is the state the
+    /// transaction results in, meaning that an earlier submission was accepted.
+    ///
+    /// Returns an error if the state can't be retrieved, as the transaction may have been
+    /// committed anyway.
+    async fn is_committed_on_node(
+        &mut self,
+        transaction: &UnsubmittedTransaction,
+    ) -> Result<bool, RpcError> {
+        if transaction.submission_attempts == 0 {
+            return Ok(false);
+        }
+
+        let final_hash = transaction.tx_result.executed_transaction().final_account().hash();
+        match self.rpc_api.get_account_update(transaction.account_id()).await? {
+            AccountDetails::Private(_, summary) | AccountDetails::Public(_, summary) => {
+                Ok(summary.hash == final_hash)
+            },
+        }
+    }
+}
@@ -0,0 +1,601 @@
+//! Provides spending policies, which restrict the transactions an account can execute through the
+//! client.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
+use core::fmt;
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    notes::{Note, NoteAssets, NoteId, NoteType},
+    transaction::{OutputNote, TransactionId},
+    Felt,
+};
+use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::{collect_assets, TransactionRequest, TransactionResult, TransactionScriptTemplate};
+use crate::{
+    notes::script_roots::{P2ID, P2IDR},
+    Client, ClientError, FeltRng,
+};
+
+/// Number of seconds in a day, used to group spending by day of chain time.
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+// SPENDING LIMIT
+// ================================================================================================
+
+/// Maximum amounts of a fungible asset an account can send.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SpendingLimit {
+    /// Maximum amount sent by a single transaction.
+    pub per_transaction: Option<u64>,
+    /// Maximum amount sent by all transactions applied during a day.
+    pub daily: Option<u64>,
+}
+
+impl Serializable for SpendingLimit {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.per_transaction.write_into(target);
+        self.daily.write_into(target);
+    }
+}
+
+impl Deserializable for SpendingLimit {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let per_transaction = Option::<u64>::read_from(source)?;
+        let daily = Option::<u64>::read_from(source)?;
+
+        Ok(Self { per_transaction, daily })
+    }
+}
+
+// SPENDING RESERVATION
+// ================================================================================================
+
+/// Amounts a stored transaction counts towards the daily limits, from the moment it's stored until
+/// it's either applied or discarded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SpendingReservation {
+    /// Day the amounts were added to the spending of.
+    day: u32,
+    /// Amounts sent by the transaction, by faucet.
+    amounts: BTreeMap<AccountId, u64>,
+}
+
+impl Serializable for SpendingReservation {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.day.write_into(target);
+        self.amounts.write_into(target);
+    }
+}
+
+impl Deserializable for SpendingReservation {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let day = u32::read_from(source)?;
+        let amounts = BTreeMap::<AccountId, u64>::read_from(source)?;
+
+        Ok(Self { day, amounts })
+    }
+}
+
+// SPENDING POLICY
+// ================================================================================================
+
+/// Restrictions on the notes created by an account's transactions, checked by
+/// [Client::validate_request] before the transactions are executed.
+///
+/// The policy is enforced by the client only. It applies to the notes declared in the transaction
+/// request, that is, its expected output notes and the notes it sends, and again to the notes the
+/// transaction actually created when it's stored for submission.
+///
+/// Days are measured in chain time, based on the timestamp of the last block the client synced
+/// to, so the daily limits apply to the transactions stored since the beginning of that day. A
+/// stored transaction counts towards the limits until it's discarded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpendingPolicy {
+    limits: BTreeMap<AccountId, SpendingLimit>,
+    allowed_recipients: BTreeSet<AccountId>,
+    blocked_note_types: Vec<NoteType>,
+    /// Day of the last recorded spending and the amounts spent on it, by faucet.
+    daily_spending: (u32, BTreeMap<AccountId, u64>),
+    /// Spending of the stored transactions that weren't applied yet, by transaction.
+    reservations: BTreeMap<TransactionId, SpendingReservation>,
+}
+
+impl SpendingPolicy {
+    /// Returns a new [SpendingPolicy] without restrictions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the limits for the assets issued by the faucet, replacing any previous limits.
+    pub fn with_limit(mut self, faucet_id: AccountId, limit: SpendingLimit) -> Self {
+        self.limits.insert(faucet_id, limit);
+        self
+    }
+
+    /// Allows notes to be sent to the account. Once a recipient is allowed, notes can only be
+    /// sent to allowed recipients, and notes whose recipient can't be determined are rejected.
+    pub fn with_allowed_recipient(mut self, account_id: AccountId) -> Self {
+        self.allowed_recipients.insert(account_id);
+        self
+    }
+
+    /// Rejects the transactions that create notes of the specified type.
+    pub fn with_blocked_note_type(mut self, note_type: NoteType) -> Self {
+        if !self.blocked_note_types.contains(&note_type) {
+            self.blocked_note_types.push(note_type);
+        }
+        self
+    }
+
+    /// Returns the spending limits, by faucet.
+    pub fn limits(&self) -> &BTreeMap<AccountId, SpendingLimit> {
+        &self.limits
+    }
+
+    /// Returns the accounts notes can be sent to. If empty, notes can be sent to any account.
+    pub fn allowed_recipients(&self) -> &BTreeSet<AccountId> {
+        &self.allowed_recipients
+    }
+
+    /// Returns the types of the notes that can't be created.
+    pub fn blocked_note_types(&self) -> &[NoteType] {
+        &self.blocked_note_types
+    }
+
+    /// Returns the amount of the faucet's asset sent on the specified day.
+    pub fn spent_on_day(&self, day: u32, faucet_id: AccountId) -> u64 {
+        let (spending_day, spent) = &self.daily_spending;
+        if *spending_day != day {
+            return 0;
+        }
+
+        spent.get(&faucet_id).copied().unwrap_or(0)
+    }
+
+    /// Returns the same policy with the spending of `other`, so replacing a policy doesn't reset
+    /// the amounts spent during the day.
+    fn with_spending_of(mut self, other: &SpendingPolicy) -> Self {
+        self.daily_spending = other.daily_spending.clone();
+        self.reservations = other.reservations.clone();
+        self
+    }
+
+    /// Adds the amounts sent by a stored transaction to the spending of the day, keeping track of
+    /// them so they can be released if the transaction is discarded.
+    fn reserve_spending(
+        &mut self,
+        transaction_id: TransactionId,
+        day: u32,
+        amounts: BTreeMap<AccountId, u64>,
+    ) {
+        self.record_spending(day, &amounts);
+        self.reservations.insert(transaction_id, SpendingReservation { day, amounts });
+    }
+
+    /// Removes the amounts reserved by a discarded transaction from the spending of the day they
+    /// were reserved on, if it's still the current one.
+    fn release_spending(&mut self, transaction_id: TransactionId) {
+        let Some(reservation) = self.reservations.remove(&transaction_id) else {
+            return;
+        };
+        if self.daily_spending.0 != reservation.day {
+            return;
+        }
+
+        for (faucet_id, amount) in reservation.amounts {
+            if let Some(spent) = self.daily_spending.1.get_mut(&faucet_id) {
+                *spent = spent.saturating_sub(amount);
+            }
+        }
+    }
+
+    /// Adds the amounts to the spending of the specified day.
+    fn record_spending(&mut self, day: u32, amounts: &BTreeMap<AccountId, u64>) {
+        if self.daily_spending.0 != day {
+            self.daily_spending = (day, BTreeMap::new());
+        }
+
+        for (faucet_id, amount) in amounts {
+            let spent = self.daily_spending.1.entry(*faucet_id).or_default();
+            *spent = spent.saturating_add(*amount);
+        }
+    }
+
+    /// Checks the notes created by a transaction against the policy.
+    fn check(
+        &self,
+        day: u32,
+        notes: &[OutgoingNote],
+        amounts: &BTreeMap<AccountId, u64>,
+    ) -> Result<(), SpendingPolicyViolation> {
+        for note in notes {
+            if self.blocked_note_types.contains(&note.note_type) {
+                return Err(SpendingPolicyViolation::NoteTypeBlocked {
+                    note_id: note.id,
+                    note_type: note.note_type,
+                });
+            }
+
+            if self.allowed_recipients.is_empty() {
+                continue;
+            }
+            match note.recipient {
+                Some(recipient) if self.allowed_recipients.contains(&recipient) => {},
+                Some(recipient) => {
+                    return Err(SpendingPolicyViolation::RecipientNotAllowed(recipient))
+                },
+                None => return Err(SpendingPolicyViolation::UnknownRecipient(note.id)),
+            }
+        }
+
+        for (faucet_id, amount) in amounts {
+            let Some(limit) = self.limits.get(faucet_id) else {
+                continue;
+            };
+
+            if let Some(per_transaction) = limit.per_transaction {
+                if *amount > per_transaction {
+                    return Err(SpendingPolicyViolation::TransactionLimitExceeded {
+                        faucet_id: *faucet_id,
+                        amount: *amount,
+                        limit: per_transaction,
+                    });
+                }
+            }
+
+            if let Some(daily) = limit.daily {
+                let spent = self.spent_on_day(day, *faucet_id);
+                if spent.saturating_add(*amount) > daily {
+                    return Err(SpendingPolicyViolation::DailyLimitExceeded {
+                        faucet_id: *faucet_id,
+                        amount: *amount,
+                        spent,
+                        limit: daily,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serializable for SpendingPolicy {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.limits.write_into(target);
+        self.allowed_recipients.write_into(target);
+        self.blocked_note_types.write_into(target);
+        self.daily_spending.0.write_into(target);
+        self.daily_spending.1.write_into(target);
+        self.reservations.write_into(target);
+    }
+}
+
+impl Deserializable for SpendingPolicy {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let limits = BTreeMap::<AccountId, SpendingLimit>::read_from(source)?;
+        let allowed_recipients = BTreeSet::<AccountId>::read_from(source)?;
+        let blocked_note_types = Vec::<NoteType>::read_from(source)?;
+        let spending_day = u32::read_from(source)?;
+        let spent = BTreeMap::<AccountId, u64>::read_from(source)?;
+        let reservations = BTreeMap::<TransactionId, SpendingReservation>::read_from(source)?;
+
+        Ok(Self {
+            limits,
+            allowed_recipients,
+            blocked_note_types,
+            daily_spending: (spending_day, spent),
+            reservations,
+        })
+    }
+}
+
+// SPENDING POLICY VIOLATION
+// ================================================================================================
+
+/// The reason a transaction request was rejected by a [SpendingPolicy].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpendingPolicyViolation {
+    /// The transaction sends more of the faucet's asset than allowed for a single transaction.
+    TransactionLimitExceeded {
+        faucet_id: AccountId,
+        amount: u64,
+        limit: u64,
+    },
+    /// The transaction would take the amount of the faucet's asset sent during the day over the
+    /// daily limit.
+    DailyLimitExceeded {
+        faucet_id: AccountId,
+        amount: u64,
+        spent: u64,
+        limit: u64,
+    },
+    /// The transaction sends a note to an account that isn't allowed.
+    RecipientNotAllowed(AccountId),
+    /// The transaction creates a note whose recipient can't be determined while only some
+    /// recipients are allowed.
+    UnknownRecipient(NoteId),
+    /// The transaction creates a note of a blocked type.
+    NoteTypeBlocked { note_id: NoteId, note_type: NoteType },
+}
+
+impl fmt::Display for SpendingPolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpendingPolicyViolation::TransactionLimitExceeded { faucet_id, amount, limit } => {
+                write!(
+                    f,
+                    "sending {amount} of faucet {faucet_id} exceeds the per-transaction limit of \
+                    {limit}"
+                )
+            },
+            SpendingPolicyViolation::DailyLimitExceeded { faucet_id, amount, spent, limit } => {
+                write!(
+                    f,
+                    "sending {amount} of faucet {faucet_id} exceeds the daily limit of {limit}, \
+                    as {spent} was already sent today"
+                )
+            },
+            SpendingPolicyViolation::RecipientNotAllowed(account_id) => {
+                write!(f, "account {account_id} is not an allowed recipient")
+            },
+            SpendingPolicyViolation::UnknownRecipient(note_id) => {
+                write!(f, "the recipient of note {note_id} can't be determined")
+            },
+            SpendingPolicyViolation::NoteTypeBlocked { note_id, note_type } => {
+                write!(f, "note {note_id} is {note_type:?}, which is a blocked note type")
+            },
+        }
+    }
+}
+
+impl<R: FeltRng> Client<R> {
+    // SPENDING POLICIES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the spending policy of the account, if it has one.
+    pub async fn get_spending_policy(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Option<SpendingPolicy>, ClientError> {
+        self.store
+            .get_spending_policy(account_id)
+            .await
+            .map_err(ClientError::StoreError)
+    }
+
+    /// Sets the spending policy of the account, replacing its current policy. The amounts the
+    /// account sent during the current day keep counting towards the new daily limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account is not tracked by the client.
+    pub async fn set_spending_policy(
+        &self,
+        account_id: AccountId,
+        policy: SpendingPolicy,
+    ) -> Result<(), ClientError> {
+        self.get_account_header_by_id(account_id).await?;
+
+        let policy = match self.store.get_spending_policy(account_id).await? {
+            Some(current_policy) => policy.with_spending_of(&current_policy),
+            None => policy,
+        };
+
+        self.store.upsert_spending_policy(account_id, &policy).await?;
+        Ok(())
+    }
+
+    /// Removes the spending policy of the account, if it has one.
+    pub async fn remove_spending_policy(&self, account_id: AccountId) -> Result<(), ClientError> {
+        self.store
+            .remove_spending_policy(account_id)
+            .await
+            .map_err(ClientError::StoreError)
+    }
+
+    /// Returns the amounts the account sent during the current day, by faucet, including the
+    /// amounts of stored transactions that weren't submitted yet.
+    pub async fn get_daily_spending(
+        &self,
+        account_id: AccountId,
+    ) -> Result<BTreeMap<AccountId, u64>, ClientError> {
+        let Some(policy) = self.store.get_spending_policy(account_id).await? else {
+            return Ok(BTreeMap::new());
+        };
+
+        let day = self.get_chain_day().await?;
+        let (spending_day, spent) = policy.daily_spending;
+
+        Ok(if spending_day == day { spent } else { BTreeMap::new() })
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks the transaction request against the spending policy of the account, if it has one.
+    pub(super) async fn validate_spending_policy(
+        &self,
+        transaction_request: &TransactionRequest,
+        account: &Account,
+    ) -> Result<(), ClientError> {
+        let Some(policy) = self.store.get_spending_policy(account.id()).await? else {
+            return Ok(());
+        };
+
+        let notes = outgoing_notes(transaction_request);
+        let (amounts, _) = collect_assets(
+            notes.iter().filter_map(|note| note.assets).flat_map(|assets| assets.iter()),
+        );
+        let day = self.get_chain_day().await?;
+
+        policy
+            .check(day, &notes, &amounts)
+            .map_err(|violation| ClientError::SpendingPolicyViolation(account.id(), violation))
+    }
+
+    /// Checks the notes created by an executed transaction against the spending policy of its
+    /// account, if it has one, and reserves the amounts they send until the transaction is
+    /// applied or discarded. Transactions that already have a reservation aren't checked again.
+    pub(super) async fn reserve_spending(
+        &self,
+        tx_result: &TransactionResult,
+    ) -> Result<(), ClientError> {
+        let account_id = tx_result.executed_transaction().account_id();
+        let transaction_id = tx_result.executed_transaction().id();
+        let Some(mut policy) = self.store.get_spending_policy(account_id).await? else {
+            return Ok(());
+        };
+        if policy.reservations.contains_key(&transaction_id) {
+            return Ok(());
+        }
+
+        let notes = created_notes(tx_result);
+        let (amounts, _) = collect_assets(
+            notes.iter().filter_map(|note| note.assets).flat_map(|assets| assets.iter()),
+        );
+        let day = self.get_chain_day().await?;
+
+        policy
+            .check(day, &notes, &amounts)
+            .map_err(|violation| ClientError::SpendingPolicyViolation(account_id, violation))?;
+        policy.reserve_spending(transaction_id, day, amounts);
+
+        self.store.upsert_spending_policy(account_id, &policy).await?;
+        Ok(())
+    }
+
+    /// Releases the spending reserved by a stored transaction that is being discarded.
+    pub(super) async fn release_spending(
+        &self,
+        account_id: AccountId,
+        transaction_id: TransactionId,
+    ) -> Result<(), ClientError> {
+        let Some(mut policy) = self.store.get_spending_policy(account_id).await? else {
+            return Ok(());
+        };
+        if !policy.reservations.contains_key(&transaction_id) {
+            return Ok(());
+        }
+
+        policy.release_spending(transaction_id);
+        self.store.upsert_spending_policy(account_id, &policy).await?;
+        Ok(())
+    }
+
+    /// Makes the spending of an applied transaction final. Transactions that were applied without
+    /// being stored first have their spending recorded instead.
+    pub(super) async fn record_spending(
+        &self,
+        tx_result: &TransactionResult,
+    ) -> Result<(), ClientError> {
+        let account_id = tx_result.executed_transaction().account_id();
+        let Some(mut policy) = self.store.get_spending_policy(account_id).await? else {
+            return Ok(());
+        };
+
+        let transaction_id = tx_result.executed_transaction().id();
+        if policy.reservations.remove(&transaction_id).is_none() {
+            let (amounts, _) = collect_assets(
+                tx_result
+                    .created_notes()
+                    .iter()
+                    .filter_map(|note| note.assets())
+                    .flat_map(|assets| assets.iter()),
+            );
+            policy.record_spending(self.get_chain_day().await?, &amounts);
+        }
+
+        self.store.upsert_spending_policy(account_id, &policy).await?;
+        Ok(())
+    }
+
+    /// Returns the current day in chain time, based on the last block the client synced to.
+    async fn get_chain_day(&self) -> Result<u32, ClientError> {
+        let sync_height = self.get_sync_height().await?;
+        let (block_header, _) = self.store.get_block_header_by_num(sync_height).await?;
+
+        Ok(block_header.timestamp() / SECONDS_PER_DAY)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// A note declared or created by a transaction, with the details a [SpendingPolicy] checks.
+struct OutgoingNote<'a> {
+    id: NoteId,
+    note_type: NoteType,
+    /// The assets of the note, unless only its header is known.
+    assets: Option<&'a NoteAssets>,
+    /// The account the note is meant for, if it can be determined from the note's script.
+    recipient: Option<AccountId>,
+}
+
+/// Returns the notes declared by the transaction request: its expected output notes and the
+/// notes it sends. The recipient of sent notes that are only partially known can't be determined.
+fn outgoing_notes(transaction_request: &TransactionRequest) -> Vec<OutgoingNote> {
+    let mut notes: BTreeMap<NoteId, OutgoingNote> = BTreeMap::new();
+    if let Some(TransactionScriptTemplate::SendNotes(own_notes)) =
+        transaction_request.script_template()
+    {
+        notes.extend(own_notes.iter().map(|note| {
+            let outgoing_note = OutgoingNote {
+                id: note.id(),
+                note_type: note.metadata().note_type(),
+                assets: Some(note.assets()),
+                recipient: None,
+            };
+            (note.id(), outgoing_note)
+        }));
+    }
+
+    // Full notes replace their partial counterparts, as their recipient is known
+    notes.extend(transaction_request.expected_output_notes().map(|note| {
+        let outgoing_note = OutgoingNote {
+            id: note.id(),
+            note_type: note.metadata().note_type(),
+            assets: Some(note.assets()),
+            recipient: note_recipient_account(note),
+        };
+        (note.id(), outgoing_note)
+    }));
+
+    notes.into_values().collect()
+}
+
+/// Returns the notes created by an executed transaction. The recipient of notes whose details
+/// aren't fully known can't be determined.
+fn created_notes(tx_result: &TransactionResult) -> Vec<OutgoingNote> {
+    tx_result
+        .created_notes()
+        .iter()
+        .map(|note| OutgoingNote {
+            id: note.id(),
+            note_type: note.metadata().note_type(),
+            assets: note.assets(),
+            recipient: match note {
+                OutputNote::Full(note) => note_recipient_account(note),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+/// Returns the account a P2ID or P2IDR note is meant for, or `None` for any other note.
+fn note_recipient_account(note: &Note) -> Option<AccountId> {
+    let script_root = note.script().hash().to_string();
+    if script_root != P2ID && script_root != P2IDR {
+        return None;
+    }
+
+    // The first input of both scripts is the ID of the target account
+    let target: Felt = *note.inputs().values().first()?;
+    AccountId::try_from(target).ok()
+}
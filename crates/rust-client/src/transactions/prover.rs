@@ -0,0 +1,239 @@
+//! Provides a [TransactionProver] that falls back through a prioritized list of provers.
+
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use futures::future::{select, Either};
+use futures_timer::Delay;
+use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
+use miden_tx::{TransactionProver, TransactionProverError};
+use tracing::warn;
+
+/// Default number of consecutive failures after which a prover is considered unhealthy.
+pub const DEFAULT_FAILURE_THRESHOLD: usize = 3;
+
+/// Default number of proofs an unhealthy prover is skipped for before it's tried again.
+pub const DEFAULT_COOLDOWN: usize = 10;
+
+// FALLBACK TRANSACTION PROVER
+// ================================================================================================
+
+/// A [TransactionProver] that tries a prioritized list of provers, moving on to the next one when
+/// a prover can't be reached or doesn't respond in time.
+///
+/// Errors caused by the transaction itself would happen with any prover, so they are returned
+/// right away and don't count against the health of the prover. Remote provers report transport
+/// failures as [TransactionProverError::InternalError], which is also how timeouts are reported,
+/// so only those errors move on to the next prover.
+///
+/// A typical setup lists one or more remote provers followed by a local prover, so transactions
+/// can still be proven while the remote provers are down.
+///
+/// Provers that fail [FallbackTransactionProver::with_failure_threshold] times in a row are
+/// considered unhealthy and are skipped for the next
+/// [FallbackTransactionProver::with_cooldown] proofs, after which they are tried again. If every
+/// prover is unhealthy, all of them are tried in order.
+pub struct FallbackTransactionProver {
+    provers: Vec<ProverEntry>,
+    failure_threshold: usize,
+    cooldown: usize,
+}
+
+/// A prover in the list of a [FallbackTransactionProver], along with its health.
+struct ProverEntry {
+    name: String,
+    prover: Arc<dyn TransactionProver>,
+    timeout: Option<Duration>,
+    consecutive_failures: AtomicUsize,
+    remaining_cooldown: AtomicUsize,
+}
+
+impl FallbackTransactionProver {
+    /// Returns a new [FallbackTransactionProver] without provers.
+    pub fn new() -> Self {
+        Self {
+            provers: Vec::new(),
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            cooldown: DEFAULT_COOLDOWN,
+        }
+    }
+
+    /// Adds a prover with the lowest priority so far. `name` identifies it in errors and health
+    /// reports. If `timeout` is set, proofs that take longer are abandoned and the next prover is
+    /// tried.
+    ///
+    /// The timeout can't interrupt provers that block the thread while proving, such as the local
+    /// prover, so it's meant for remote provers.
+    pub fn with_prover(
+        mut self,
+        name: impl Into<String>,
+        prover: Arc<dyn TransactionProver>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        self.provers.push(ProverEntry {
+            name: name.into(),
+            prover,
+            timeout,
+            consecutive_failures: AtomicUsize::new(0),
+            remaining_cooldown: AtomicUsize::new(0),
+        });
+        self
+    }
+
+    /// Sets the number of consecutive failures after which a prover is considered unhealthy.
+    /// Defaults to [DEFAULT_FAILURE_THRESHOLD].
+    pub fn with_failure_threshold(mut self, failure_threshold: usize) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self
+    }
+
+    /// Sets the number of proofs an unhealthy prover is skipped for before it's tried again.
+    /// Defaults to [DEFAULT_COOLDOWN].
+    pub fn with_cooldown(mut self, cooldown: usize) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Returns the health of every prover, in priority order.
+    pub fn health(&self) -> Vec<ProverHealth> {
+        self.provers
+            .iter()
+            .map(|entry| ProverHealth {
+                name: entry.name.clone(),
+                consecutive_failures: entry.consecutive_failures.load(Ordering::Relaxed),
+                healthy: entry.consecutive_failures.load(Ordering::Relaxed)
+                    < self.failure_threshold,
+            })
+            .collect()
+    }
+
+    /// Returns whether the prover should be tried for the current proof, consuming one proof of
+    /// its cooldown if it's unhealthy.
+    fn should_try(&self, entry: &ProverEntry) -> bool {
+        if entry.consecutive_failures.load(Ordering::Relaxed) < self.failure_threshold {
+            return true;
+        }
+
+        entry
+            .remaining_cooldown
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |remaining| {
+                remaining.checked_sub(1)
+            })
+            .is_err()
+    }
+
+    /// Proves the transaction with a single prover, updating its health if the prover is
+    /// unavailable.
+    async fn prove_with(
+        &self,
+        entry: &ProverEntry,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let proof = entry.prover.prove(tx_witness);
+        let result = match entry.timeout {
+            Some(timeout) => match select(Box::pin(proof), Delay::new(timeout)).await {
+                Either::Left((result, _)) => result,
+                Either::Right(_) => Err(TransactionProverError::InternalError(format!(
+                    "proof timed out after {} ms",
+                    timeout.as_millis()
+                ))),
+            },
+            None => proof.await,
+        };
+
+        match &result {
+            Ok(_) => entry.consecutive_failures.store(0, Ordering::Relaxed),
+            Err(err) if is_prover_unavailable(err) => {
+                warn!("Prover {} failed: {err}", entry.name);
+                let failures = entry.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+                if failures >= self.failure_threshold {
+                    entry.remaining_cooldown.store(self.cooldown, Ordering::Relaxed);
+                }
+            },
+            Err(_) => {},
+        }
+
+        result
+    }
+}
+
+impl Default for FallbackTransactionProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl TransactionProver for FallbackTransactionProver {
+    /// Proves the transaction with the first prover that succeeds, skipping unhealthy provers
+    /// unless all of them are.
+    ///
+    /// # Errors
+    ///
+    /// - Returns the error of a prover as is if it's caused by the transaction.
+    /// - Returns [TransactionProverError::InternalError] with the error of every prover that was
+    ///   tried if none of them can be reached.
+    async fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let mut candidates: Vec<&ProverEntry> =
+            self.provers.iter().filter(|entry| self.should_try(entry)).collect();
+        if candidates.is_empty() {
+            candidates = self.provers.iter().collect();
+        }
+
+        let mut errors = Vec::with_capacity(candidates.len());
+        for entry in candidates {
+            match self.prove_with(entry, tx_witness.clone()).await {
+                Ok(proven_transaction) => return Ok(proven_transaction),
+                Err(err) if is_prover_unavailable(&err) => {
+                    errors.push(format!("{}: {err}", entry.name))
+                },
+                Err(err) => return Err(err),
+            }
+        }
+
+        if errors.is_empty() {
+            return Err(TransactionProverError::InternalError(
+                "no transaction prover is configured".to_string(),
+            ));
+        }
+
+        Err(TransactionProverError::InternalError(format!(
+            "every transaction prover failed ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Returns whether the error means the prover couldn't produce a proof, as opposed to the
+/// transaction being invalid. Errors other than internal ones come from executing or checking the
+/// transaction, so they don't depend on the prover.
+fn is_prover_unavailable(err: &TransactionProverError) -> bool {
+    matches!(err, TransactionProverError::InternalError(_))
+}
+
+// PROVER HEALTH
+// ================================================================================================
+
+/// Health of a prover of a [FallbackTransactionProver].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProverHealth {
+    /// Name the prover was added with.
+    pub name: String,
+    /// Number of proofs that failed in a row with this prover.
+    pub consecutive_failures: usize,
+    /// Whether the prover is tried in priority order. Unhealthy provers are only tried once
+    /// their cooldown is over, or when every prover is unhealthy.
+    pub healthy: bool,
+}
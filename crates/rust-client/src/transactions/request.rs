@@ -11,7 +11,7 @@ use miden_lib::notes::{create_p2id_note, create_p2idr_note, create_swap_note};
 use miden_objects::{
     accounts::AccountId,
     assembly::AssemblyError,
-    assets::{Asset, FungibleAsset},
+    assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::{
         merkle::{InnerNodeInfo, MerkleStore},
         rand::FeltRng,
@@ -19,7 +19,7 @@ use miden_objects::{
     notes::{Note, NoteDetails, NoteExecutionMode, NoteId, NoteTag, NoteType, PartialNote},
     transaction::{OutputNote, TransactionArgs, TransactionScript},
     vm::AdviceMap,
-    Digest, Felt, FieldElement, NoteError, Word,
+    AssetError, Digest, Felt, FieldElement, NoteError, Word,
 };
 use miden_tx::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
@@ -295,6 +295,32 @@ impl TransactionRequest {
         TransactionRequest::new().with_own_output_notes(vec![OutputNote::Full(created_note)])
     }
 
+    /// Returns a new [TransactionRequest] for a transaction to mint a non-fungible asset. This
+    /// request must be executed against the non-fungible faucet account that issues the asset.
+    ///
+    /// - `asset` is the non-fungible asset to be minted.
+    /// - `target_id` is the account ID of the account to receive the minted asset.
+    /// - `note_type` determines the visibility of the note to be created.
+    /// - `rng` is the random number generator used to generate the serial number for the created
+    ///   note.
+    pub fn mint_non_fungible_asset(
+        asset: NonFungibleAsset,
+        target_id: AccountId,
+        note_type: NoteType,
+        rng: &mut impl FeltRng,
+    ) -> Result<Self, TransactionRequestError> {
+        let created_note = create_p2id_note(
+            asset.faucet_id(),
+            target_id,
+            vec![asset.into()],
+            note_type,
+            Felt::ZERO,
+            rng,
+        )?;
+
+        TransactionRequest::new().with_own_output_notes(vec![OutputNote::Full(created_note)])
+    }
+
     /// Returns a new [TransactionRequest] for a transaction to send a P2ID or P2IDR note. This
     /// request must be executed against the wallet sender account.
     ///
@@ -340,6 +366,79 @@ impl TransactionRequest {
         TransactionRequest::new().with_own_output_notes(vec![OutputNote::Full(created_note)])
     }
 
+    /// Returns a new [TransactionRequest] for a transaction that sends one P2ID note per payment.
+    /// This request must be executed against the wallet sender account.
+    ///
+    /// - `payments` is the list of payments to be made. Every payment must have the same sender
+    ///   account ID.
+    /// - `note_type` determines the visibility of the notes to be created.
+    /// - `rng` is the random number generator used to generate the serial numbers for the created
+    ///   notes.
+    ///
+    /// The assets of all payments are aggregated to check that the batch is consistent: fungible
+    /// amounts for the same faucet must not add up to more than the maximum fungible asset amount
+    /// and a non-fungible asset can't be sent more than once. Whether the sender holds enough
+    /// assets is checked against the account's vault when the request is executed.
+    pub fn pay_to_many(
+        payments: Vec<PaymentTransactionData>,
+        note_type: NoteType,
+        rng: &mut impl FeltRng,
+    ) -> Result<Self, TransactionRequestError> {
+        let sender_account_id = payments
+            .first()
+            .map(PaymentTransactionData::account_id)
+            .ok_or(TransactionRequestError::EmptyPaymentBatch)?;
+
+        let mut fungible_totals: BTreeMap<AccountId, FungibleAsset> = BTreeMap::new();
+        let mut non_fungible_assets = BTreeSet::new();
+        let mut created_notes = Vec::with_capacity(payments.len());
+
+        for payment in payments {
+            let PaymentTransactionData {
+                assets,
+                sender_account_id: payment_sender_id,
+                target_account_id,
+            } = payment;
+
+            if payment_sender_id != sender_account_id {
+                return Err(TransactionRequestError::InvalidSenderAccount(payment_sender_id));
+            }
+
+            for asset in assets.iter() {
+                match asset {
+                    Asset::Fungible(fungible_asset) => {
+                        let total = match fungible_totals.get(&fungible_asset.faucet_id()) {
+                            Some(total) => total
+                                .add(*fungible_asset)
+                                .map_err(TransactionRequestError::InvalidPaymentAmount)?,
+                            None => *fungible_asset,
+                        };
+                        fungible_totals.insert(fungible_asset.faucet_id(), total);
+                    },
+                    Asset::NonFungible(non_fungible_asset) => {
+                        if !non_fungible_assets.insert(*non_fungible_asset) {
+                            return Err(TransactionRequestError::DuplicateNonFungibleAsset(
+                                non_fungible_asset.faucet_id(),
+                            ));
+                        }
+                    },
+                }
+            }
+
+            let created_note = create_p2id_note(
+                sender_account_id,
+                target_account_id,
+                assets,
+                note_type,
+                Felt::ZERO,
+                rng,
+            )?;
+            created_notes.push(OutputNote::Full(created_note));
+        }
+
+        TransactionRequest::new().with_own_output_notes(created_notes)
+    }
+
     /// Returns a new [TransactionRequest] for a transaction to send a SWAP note. This request must
     /// be executed against the wallet sender account.
     ///
@@ -445,6 +544,18 @@ impl TransactionRequest {
         &self.foreign_account_ids
     }
 
+    /// Turns the authenticated input notes that are part of `notes` into unauthenticated input
+    /// notes. This allows consuming notes created by transactions that weren't committed yet.
+    pub(super) fn with_chained_input_notes(mut self, notes: &BTreeMap<NoteId, Note>) -> Self {
+        let chained_notes = self
+            .authenticated_input_note_ids()
+            .filter_map(|note_id| notes.get(&note_id).cloned())
+            .collect::<Vec<_>>();
+
+        self.unauthenticated_input_notes.extend(chained_notes);
+        self
+    }
+
     /// Converts the [TransactionRequest] into [TransactionArgs] in order to be executed by a Miden
     /// host.
     pub(super) fn into_transaction_args(self, tx_script: TransactionScript) -> TransactionArgs {
@@ -572,12 +683,25 @@ impl Default for TransactionRequest {
 /// Errors related to a [TransactionRequest]
 #[derive(Debug)]
 pub enum TransactionRequestError {
+    DuplicateNonFungibleAsset(AccountId),
+    EmptyPaymentBatch,
+    ForeignFaucetAsset {
+        faucet_id: AccountId,
+        asset_faucet_id: AccountId,
+    },
     InvalidForeignAccountId(AccountId),
     InputNoteNotAuthenticated,
     InputNotesMapMissingUnauthenticatedNotes,
     InvalidNoteVariant,
+    InvalidPaymentAmount(AssetError),
     InvalidSenderAccount(AccountId),
     InvalidTransactionScript(AssemblyError),
+    MaxSupplyExceeded {
+        faucet_id: AccountId,
+        requested: u64,
+        issued: u64,
+        max_supply: u64,
+    },
     NoInputNotes,
     ScriptTemplateError(String),
     NoteNotFound(String),
@@ -594,12 +718,17 @@ impl From<TransactionScriptBuilderError> for TransactionRequestError {
 impl fmt::Display for TransactionRequestError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::DuplicateNonFungibleAsset(faucet_id) => write!(f, "A non-fungible asset issued by faucet {faucet_id} is sent more than once"),
+            Self::EmptyPaymentBatch => write!(f, "A batch payment must contain at least one payment"),
+            Self::ForeignFaucetAsset { faucet_id, asset_faucet_id } => write!(f, "Faucet {faucet_id} can't distribute assets issued by faucet {asset_faucet_id}"),
             Self::InvalidForeignAccountId(acc_id) => write!(f, "Requested foreign account with ID {acc_id} is not public"),
             Self::InputNoteNotAuthenticated => write!(f, "Every authenticated note to be consumed should be committed and contain a valid inclusion proof"),
             Self::InputNotesMapMissingUnauthenticatedNotes => write!(f, "The input notes map should include keys for all provided unauthenticated input notes"),
             Self::InvalidNoteVariant => write!(f, "Own notes should be either full or partial, but not header"),
+            Self::InvalidPaymentAmount(err) => write!(f, "Invalid aggregate payment amount: {}", err),
             Self::InvalidSenderAccount(account_id) => write!(f, "Invalid sender account ID: {}", account_id),
             Self::InvalidTransactionScript(err) => write!(f, "Invalid transaction script: {}", err),
+            Self::MaxSupplyExceeded { faucet_id, requested, issued, max_supply } => write!(f, "Minting {requested} tokens exceeds the maximum supply of faucet {faucet_id}: {issued} of {max_supply} tokens were already issued"),
             Self::NoInputNotes => write!(f, "A transaction without output notes must have at least one input note"),
             Self::ScriptTemplateError(err) => write!(f, "Transaction script template error: {}", err),
             Self::NoteNotFound(err) => write!(f, "Note not found: {}", err),
@@ -734,7 +863,8 @@ mod tests {
     };
     use miden_tx::utils::{Deserializable, Serializable};
 
-    use super::TransactionRequest;
+    use super::{PaymentTransactionData, TransactionRequest, TransactionRequestError};
+    use crate::transactions::TransactionScriptTemplate;
 
     #[test]
     fn transaction_request_serialization() {
@@ -786,4 +916,59 @@ mod tests {
         let deserialized_tx_request = TransactionRequest::read_from_bytes(&buffer).unwrap();
         assert_eq!(tx_request, deserialized_tx_request);
     }
+
+    #[test]
+    fn pay_to_many_validation() {
+        let sender_id = AccountId::new_dummy([0u8; 32], AccountType::RegularAccountImmutableCode);
+        let other_sender_id =
+            AccountId::new_dummy([3u8; 32], AccountType::RegularAccountImmutableCode);
+        let faucet_id = AccountId::new_dummy([2u8; 32], AccountType::FungibleFaucet);
+        let mut rng = RpoRandomCoin::new(Default::default());
+
+        let payments: Vec<PaymentTransactionData> = (0..5u8)
+            .map(|i| {
+                let target_id =
+                    AccountId::new_dummy([10 + i; 32], AccountType::RegularAccountImmutableCode);
+                PaymentTransactionData::new(
+                    vec![FungibleAsset::new(faucet_id, 100).unwrap().into()],
+                    sender_id,
+                    target_id,
+                )
+            })
+            .collect();
+
+        let tx_request =
+            TransactionRequest::pay_to_many(payments.clone(), NoteType::Private, &mut rng).unwrap();
+        match tx_request.script_template() {
+            Some(TransactionScriptTemplate::SendNotes(notes)) => assert_eq!(notes.len(), 5),
+            _ => panic!("Expected a SendNotes script template"),
+        }
+
+        assert!(matches!(
+            TransactionRequest::pay_to_many(vec![], NoteType::Private, &mut rng),
+            Err(TransactionRequestError::EmptyPaymentBatch)
+        ));
+
+        let mut mixed_senders = payments.clone();
+        mixed_senders.push(PaymentTransactionData::new(
+            vec![FungibleAsset::new(faucet_id, 100).unwrap().into()],
+            other_sender_id,
+            sender_id,
+        ));
+        assert!(matches!(
+            TransactionRequest::pay_to_many(mixed_senders, NoteType::Private, &mut rng),
+            Err(TransactionRequestError::InvalidSenderAccount(account_id)) if account_id == other_sender_id
+        ));
+
+        let mut overflowing_payments = payments;
+        overflowing_payments.push(PaymentTransactionData::new(
+            vec![FungibleAsset::new(faucet_id, FungibleAsset::MAX_AMOUNT).unwrap().into()],
+            sender_id,
+            other_sender_id,
+        ));
+        assert!(matches!(
+            TransactionRequest::pay_to_many(overflowing_payments, NoteType::Private, &mut rng),
+            Err(TransactionRequestError::InvalidPaymentAmount(_))
+        ));
+    }
 }
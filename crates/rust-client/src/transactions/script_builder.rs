@@ -1,121 +1,311 @@
 use alloc::{
+    boxed::Box,
     string::{String, ToString},
+    sync::Arc,
     vec::Vec,
 };
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    accounts::{auth::RpoFalcon512, faucets::BasicFungibleFaucet, wallets::BasicWallet},
+    transaction::TransactionKernel,
+    AuthScheme,
+};
 use miden_objects::{
-    accounts::{AccountId, AuthSecretKey},
+    accounts::{Account, AccountCode, AccountComponent, AccountId, AccountType},
+    assets::TokenSymbol,
+    crypto::dsa::rpo_falcon512::PublicKey,
     notes::PartialNote,
     transaction::TransactionScript,
-    Felt, TransactionScriptError,
+    Digest, Felt, FieldElement, TransactionScriptError, Word,
 };
 use miden_tx::TransactionExecutorError;
+use once_cell::race::OnceBox;
 
 use super::prepare_word;
+use crate::accounts::{basic_non_fungible_faucet_component, non_fungible_faucet_distribute_root};
 
 // ACCOUNT CAPABILITIES
 // ============================================================================================
 pub(crate) struct AccountCapabilities {
     pub account_id: AccountId,
-    pub auth: AuthSecretKey,
-    pub interfaces: AccountInterface,
+    /// The authentication scheme of the account, or `None` if it doesn't use a known scheme.
+    pub auth: Option<AuthScheme>,
+    pub interfaces: Vec<Arc<dyn AccountInterface>>,
 }
 
-pub(crate) enum AccountInterface {
-    /// The account exposes procedures of the basic wallet.
-    BasicWallet,
-    /// The account is a fungible faucet and exposes procedures of the basic fungible faucet.
-    BasicFungibleFaucet,
+impl AccountCapabilities {
+    /// Returns the capabilities of `account`, made up of the `interfaces` whose procedures are all
+    /// part of the account's code. The order of `interfaces` is kept.
+    ///
+    /// The authentication scheme is read from the account's authentication component, so the
+    /// account's secret key doesn't need to be known.
+    pub fn from_account(account: &Account, interfaces: &[Arc<dyn AccountInterface>]) -> Self {
+        let interfaces = interfaces
+            .iter()
+            .filter(|interface| interface.is_exposed_by(account.code()))
+            .cloned()
+            .collect();
+
+        Self {
+            account_id: account.id(),
+            auth: auth_scheme(account),
+            interfaces,
+        }
+    }
+
+    /// Returns the first interface exposed by the account that can be used to send notes, if any.
+    fn send_note_interface(&self) -> Option<&dyn AccountInterface> {
+        self.interfaces
+            .iter()
+            .find(|interface| interface.can_send_notes())
+            .map(|interface| interface.as_ref())
+    }
 }
 
-impl AccountInterface {
-    /// Returns the script body that sends notes to the recipients.
+// ACCOUNT INTERFACE
+// ============================================================================================
+
+/// An interface exposed by an account, made up of the procedures of an account component.
+///
+/// The client detects the interfaces of an account by looking for all of their procedures in the
+/// account's code, and uses them to build the scripts of the transactions the account executes.
+/// Besides the [BasicAccountInterface]s, custom interfaces can be added to a client with
+/// [Client::register_account_interface](crate::Client::register_account_interface).
+pub trait AccountInterface: Send + Sync {
+    /// Returns the MAST roots of the procedures that make up the interface.
+    fn procedure_roots(&self) -> Vec<Digest>;
+
+    /// Returns the `use` statements needed by the code returned by
+    /// [AccountInterface::send_note_procedure].
+    fn script_includes(&self) -> String {
+        String::new()
+    }
+
+    /// Returns `true` if the interface exposes a procedure to create notes.
+    fn can_send_notes(&self) -> bool {
+        false
+    }
+
+    /// Returns the script code that creates `partial_note` from account `account_id`.
     ///
-    /// Errors:
-    /// - [TransactionScriptBuilderError::InvalidSenderAccount] if the sender of the note is not the
-    ///   account for which the script is being built.
-    /// - [TransactionScriptBuilderError::InvalidAssetAmount] if the note does not contain exactly
-    ///   one asset.
-    /// - [TransactionScriptBuilderError::InvalidAsset] if a faucet tries to distribute an asset
-    ///   with a different faucet ID.
+    /// The code runs with `[tag, aux, note_type, execution_hint, RECIPIENT]` at the top of the
+    /// stack, and has to remove those elements from the stack once the note is created. It's only
+    /// called if [AccountInterface::can_send_notes] returns `true`.
     fn send_note_procedure(
         &self,
         account_id: AccountId,
-        notes: &[PartialNote],
+        _partial_note: &PartialNote,
     ) -> Result<String, TransactionScriptBuilderError> {
-        let mut body = String::new();
+        Err(TransactionScriptBuilderError::AccountCannotSendNotes(account_id))
+    }
 
-        for partial_note in notes.iter() {
-            if partial_note.metadata().sender() != account_id {
-                return Err(TransactionScriptBuilderError::InvalidSenderAccount(
-                    partial_note.metadata().sender(),
-                ));
-            }
+    /// Returns `true` if all the procedures of the interface are part of `account_code`.
+    fn is_exposed_by(&self, account_code: &AccountCode) -> bool {
+        self.procedure_roots().into_iter().all(|root| account_code.has_procedure(root))
+    }
 
-            let asset = partial_note.assets().iter().next().expect("There should be an asset");
+    /// Returns the index of the first storage slot of the interface's component in an account with
+    /// `account_code`, or `None` if the account doesn't expose the interface.
+    fn storage_offset(&self, account_code: &AccountCode) -> Option<u8> {
+        let procedure_roots = self.procedure_roots();
 
-            body.push_str(&format!(
-                "
-                    push.{recipient}
-                    push.{execution_hint}
-                    push.{note_type}
-                    push.{aux}
-                    push.{tag}
-                    ",
-                recipient = prepare_word(&partial_note.recipient_digest()),
-                note_type = Felt::from(partial_note.metadata().note_type()),
-                execution_hint = Felt::from(partial_note.metadata().execution_hint()),
-                aux = partial_note.metadata().aux(),
-                tag = Felt::from(partial_note.metadata().tag()),
-            ));
+        account_code
+            .procedures()
+            .iter()
+            .find(|procedure| procedure_roots.contains(procedure.mast_root()))
+            .map(|procedure| procedure.storage_offset())
+    }
+}
 
-            match self {
-                AccountInterface::BasicFungibleFaucet => {
-                    if asset.faucet_id() != account_id {
-                        return Err(TransactionScriptBuilderError::InvalidAsset(asset.faucet_id()));
-                    }
+/// The interfaces of the account components provided by `miden-lib` and the client, which every
+/// client can detect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BasicAccountInterface {
+    /// The account exposes procedures of the basic wallet.
+    BasicWallet,
+    /// The account is a fungible faucet and exposes procedures of the basic fungible faucet.
+    BasicFungibleFaucet,
+    /// The account is a non-fungible faucet and exposes the procedures of
+    /// [basic_non_fungible_faucet_component](crate::accounts::basic_non_fungible_faucet_component).
+    BasicNonFungibleFaucet,
+}
 
-                    body.push_str(&format!(
-                        "
-                        push.{amount}
-                        call.faucet::distribute dropw dropw drop
-                        ",
-                        amount = asset.unwrap_fungible().amount()
-                    ));
-                },
-                AccountInterface::BasicWallet => {
-                    body.push_str(
-                        "
-                        call.wallet::create_note",
-                    );
-
-                    for asset in partial_note.assets().iter() {
-                        body.push_str(&format!(
-                            "
-                        push.{asset}
-                        call.wallet::move_asset_to_note dropw
-                        ",
-                            asset = prepare_word(&asset.into())
-                        ))
-                    }
-
-                    body.push_str("dropw dropw dropw drop");
-                },
-            }
+impl BasicAccountInterface {
+    /// Every basic interface, in order of preference for sending notes. Faucets come first so that
+    /// minting transactions distribute new assets.
+    pub const ALL: [BasicAccountInterface; 3] = [
+        BasicAccountInterface::BasicFungibleFaucet,
+        BasicAccountInterface::BasicNonFungibleFaucet,
+        BasicAccountInterface::BasicWallet,
+    ];
+
+    /// Returns the account component that defines the interface, along with an account type the
+    /// component supports.
+    fn component(&self) -> (AccountComponent, AccountType) {
+        match self {
+            BasicAccountInterface::BasicWallet => {
+                (BasicWallet.into(), AccountType::RegularAccountUpdatableCode)
+            },
+            BasicAccountInterface::BasicFungibleFaucet => {
+                // The token metadata is only stored in the faucet's storage, so it doesn't affect
+                // the procedure roots
+                let faucet = BasicFungibleFaucet::new(
+                    TokenSymbol::new("TKN").expect("token symbol should be valid"),
+                    0,
+                    Felt::ZERO,
+                )
+                .expect("basic fungible faucet parameters should be valid");
+                (faucet.into(), AccountType::FungibleFaucet)
+            },
+            BasicAccountInterface::BasicNonFungibleFaucet => {
+                (basic_non_fungible_faucet_component(), AccountType::NonFungibleFaucet)
+            },
         }
+    }
+}
 
-        Ok(body)
+impl AccountInterface for BasicAccountInterface {
+    /// Returns the MAST roots of the procedures of the interface's component, which is only
+    /// compiled the first time the roots are requested.
+    fn procedure_roots(&self) -> Vec<Digest> {
+        static BASIC_WALLET_ROOTS: OnceBox<Vec<Digest>> = OnceBox::new();
+        static BASIC_FUNGIBLE_FAUCET_ROOTS: OnceBox<Vec<Digest>> = OnceBox::new();
+        static BASIC_NON_FUNGIBLE_FAUCET_ROOTS: OnceBox<Vec<Digest>> = OnceBox::new();
+
+        let roots = match self {
+            BasicAccountInterface::BasicWallet => &BASIC_WALLET_ROOTS,
+            BasicAccountInterface::BasicFungibleFaucet => &BASIC_FUNGIBLE_FAUCET_ROOTS,
+            BasicAccountInterface::BasicNonFungibleFaucet => &BASIC_NON_FUNGIBLE_FAUCET_ROOTS,
+        };
+
+        roots
+            .get_or_init(|| {
+                let (component, account_type) = self.component();
+                Box::new(component_procedure_roots(component, account_type))
+            })
+            .clone()
     }
 
-    fn script_includes(&self) -> &str {
+    fn script_includes(&self) -> String {
         match self {
-            AccountInterface::BasicWallet => "use.miden::contracts::wallets::basic->wallet\n",
-            AccountInterface::BasicFungibleFaucet => {
-                "use.miden::contracts::faucets::basic_fungible->faucet\n"
+            BasicAccountInterface::BasicWallet => {
+                "use.miden::contracts::wallets::basic->wallet\n".to_string()
             },
+            BasicAccountInterface::BasicFungibleFaucet => {
+                "use.miden::contracts::faucets::basic_fungible->faucet\n".to_string()
+            },
+            // The non-fungible faucet procedures are called by their MAST root
+            BasicAccountInterface::BasicNonFungibleFaucet => String::new(),
+        }
+    }
+
+    fn can_send_notes(&self) -> bool {
+        match self {
+            BasicAccountInterface::BasicWallet
+            | BasicAccountInterface::BasicFungibleFaucet
+            | BasicAccountInterface::BasicNonFungibleFaucet => true,
         }
     }
+
+    /// Errors:
+    /// - [TransactionScriptBuilderError::InvalidAsset] if a faucet tries to distribute an asset
+    ///   with a different faucet ID, or a non-fungible faucet tries to distribute a fungible asset.
+    fn send_note_procedure(
+        &self,
+        account_id: AccountId,
+        partial_note: &PartialNote,
+    ) -> Result<String, TransactionScriptBuilderError> {
+        let asset = partial_note.assets().iter().next().expect("There should be an asset");
+
+        let body = match self {
+            BasicAccountInterface::BasicFungibleFaucet => {
+                if asset.faucet_id() != account_id {
+                    return Err(TransactionScriptBuilderError::InvalidAsset(asset.faucet_id()));
+                }
+
+                format!(
+                    "
+                    push.{amount}
+                    call.faucet::distribute dropw dropw drop
+                    ",
+                    amount = asset.unwrap_fungible().amount()
+                )
+            },
+            BasicAccountInterface::BasicNonFungibleFaucet => {
+                if asset.faucet_id() != account_id || asset.is_fungible() {
+                    return Err(TransactionScriptBuilderError::InvalidAsset(asset.faucet_id()));
+                }
+
+                format!(
+                    "
+                    push.{asset}
+                    call.{distribute_root} dropw dropw dropw
+                    ",
+                    asset = prepare_word(&asset.into()),
+                    distribute_root = non_fungible_faucet_distribute_root(),
+                )
+            },
+            BasicAccountInterface::BasicWallet => {
+                let mut body = String::from(
+                    "
+                    call.wallet::create_note",
+                );
+
+                for asset in partial_note.assets().iter() {
+                    body.push_str(&format!(
+                        "
+                    push.{asset}
+                    call.wallet::move_asset_to_note dropw
+                    ",
+                        asset = prepare_word(&asset.into())
+                    ))
+                }
+
+                body.push_str("dropw dropw dropw drop");
+                body
+            },
+        };
+
+        Ok(body)
+    }
+}
+
+// HELPERS
+// ============================================================================================
+
+/// Returns the MAST roots of the procedures of `component` in an account of type `account_type`.
+fn component_procedure_roots(
+    component: AccountComponent,
+    account_type: AccountType,
+) -> Vec<Digest> {
+    AccountCode::from_components(&[component], account_type)
+        .expect("code of a known account component should be valid")
+        .procedures()
+        .iter()
+        .map(|procedure| *procedure.mast_root())
+        .collect()
+}
+
+/// Returns the authentication scheme of `account`, with the public key stored by its
+/// authentication component, or `None` if the account doesn't have a known authentication
+/// component.
+fn auth_scheme(account: &Account) -> Option<AuthScheme> {
+    static RPO_FALCON_512_ROOTS: OnceBox<Vec<Digest>> = OnceBox::new();
+
+    // The public key is only stored in the component's storage, so it doesn't affect the roots
+    let roots = RPO_FALCON_512_ROOTS.get_or_init(|| {
+        let component = RpoFalcon512::new(PublicKey::new(Word::default())).into();
+        Box::new(component_procedure_roots(component, AccountType::RegularAccountUpdatableCode))
+    });
+
+    let procedure = account
+        .code()
+        .procedures()
+        .iter()
+        .find(|procedure| roots.contains(procedure.mast_root()))?;
+    let pub_key = account.storage().get_item(procedure.storage_offset()).ok()?;
+
+    Some(AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key.into()) })
 }
 
 // TRANSACTION SCRIPT BUILDER
@@ -140,14 +330,58 @@ impl TransactionScriptBuilder {
         &self,
         output_notes: &[PartialNote],
     ) -> Result<TransactionScript, TransactionScriptBuilderError> {
-        let send_note_procedure = self
+        let account_id = self.account_capabilities.account_id;
+        let send_note_interface = self
             .account_capabilities
-            .interfaces
-            .send_note_procedure(self.account_capabilities.account_id, output_notes)?;
+            .send_note_interface()
+            .ok_or(TransactionScriptBuilderError::AccountCannotSendNotes(account_id))?;
+        let send_note_procedure =
+            Self::send_note_procedure(send_note_interface, account_id, output_notes)?;
 
         self.build_script_with_sections(vec![send_note_procedure])
     }
 
+    /// Returns the script body that sends notes to the recipients through `interface`.
+    ///
+    /// Errors:
+    /// - [TransactionScriptBuilderError::InvalidSenderAccount] if the sender of the note is not the
+    ///   account for which the script is being built.
+    /// - Any error returned by [AccountInterface::send_note_procedure].
+    fn send_note_procedure(
+        interface: &dyn AccountInterface,
+        account_id: AccountId,
+        notes: &[PartialNote],
+    ) -> Result<String, TransactionScriptBuilderError> {
+        let mut body = String::new();
+
+        for partial_note in notes.iter() {
+            if partial_note.metadata().sender() != account_id {
+                return Err(TransactionScriptBuilderError::InvalidSenderAccount(
+                    partial_note.metadata().sender(),
+                ));
+            }
+
+            body.push_str(&format!(
+                "
+                    push.{recipient}
+                    push.{execution_hint}
+                    push.{note_type}
+                    push.{aux}
+                    push.{tag}
+                    ",
+                recipient = prepare_word(&partial_note.recipient_digest()),
+                note_type = Felt::from(partial_note.metadata().note_type()),
+                execution_hint = Felt::from(partial_note.metadata().execution_hint()),
+                aux = partial_note.metadata().aux(),
+                tag = Felt::from(partial_note.metadata().tag()),
+            ));
+
+            body.push_str(&interface.send_note_procedure(account_id, partial_note)?);
+        }
+
+        Ok(body)
+    }
+
     /// Builds a simple authentication script for the transaction that doesn't send any notes.
     pub fn build_auth_script(&self) -> Result<TransactionScript, TransactionScriptBuilderError> {
         self.build_script_with_sections(vec![])
@@ -162,6 +396,12 @@ impl TransactionScriptBuilder {
         &self,
         sections: Vec<String>,
     ) -> Result<TransactionScript, TransactionScriptBuilderError> {
+        if self.account_capabilities.auth.is_none() {
+            return Err(TransactionScriptBuilderError::UnknownAuthScheme(
+                self.account_capabilities.account_id,
+            ));
+        }
+
         let script = format!(
             "{} begin {} {} {} end",
             self.script_includes(),
@@ -180,12 +420,15 @@ impl TransactionScriptBuilder {
     fn script_includes(&self) -> String {
         let mut includes = String::new();
 
-        includes.push_str(self.account_capabilities.interfaces.script_includes());
+        for interface in self.account_capabilities.interfaces.iter() {
+            includes.push_str(&interface.script_includes());
+        }
 
         match self.account_capabilities.auth {
-            AuthSecretKey::RpoFalcon512(_) => {
+            Some(AuthScheme::RpoFalcon512 { .. }) => {
                 includes.push_str("use.miden::contracts::auth::basic->auth_tx\n");
             },
+            None => {},
         }
 
         if self.expiration_delta.is_some() {
@@ -198,7 +441,10 @@ impl TransactionScriptBuilder {
     /// Returns a string with the authentication procedure call for the script.
     fn script_authentication(&self) -> String {
         match self.account_capabilities.auth {
-            AuthSecretKey::RpoFalcon512(_) => "call.auth_tx::auth_tx_rpo_falcon512\n".to_string(),
+            Some(AuthScheme::RpoFalcon512 { .. }) => {
+                "call.auth_tx::auth_tx_rpo_falcon512\n".to_string()
+            },
+            None => String::new(),
         }
     }
 
@@ -218,15 +464,24 @@ impl TransactionScriptBuilder {
 /// Errors related to building a transaction script.
 #[derive(Debug)]
 pub enum TransactionScriptBuilderError {
+    AccountCannotSendNotes(AccountId),
     InvalidAsset(AccountId),
     InvalidTransactionScript(TransactionScriptError),
     InvalidSenderAccount(AccountId),
     TransactionExecutorError(TransactionExecutorError),
+    UnknownAuthScheme(AccountId),
 }
 
 impl core::fmt::Display for TransactionScriptBuilderError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            TransactionScriptBuilderError::AccountCannotSendNotes(account_id) => {
+                write!(
+                    f,
+                    "Account {} can't send notes: it doesn't expose an interface that can send notes",
+                    account_id
+                )
+            },
             TransactionScriptBuilderError::InvalidAsset(account_id) => {
                 write!(f, "Invalid asset: {}", account_id)
             },
@@ -239,6 +494,9 @@ impl core::fmt::Display for TransactionScriptBuilderError {
             TransactionScriptBuilderError::TransactionExecutorError(err) => {
                 write!(f, "Transaction executor error: {}", err)
             },
+            TransactionScriptBuilderError::UnknownAuthScheme(account_id) => {
+                write!(f, "Account {} doesn't use a known authentication scheme", account_id)
+            },
         }
     }
 }
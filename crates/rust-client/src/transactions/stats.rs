@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use miden_objects::notes::NoteId;
+use miden_tx::TransactionMeasurements;
+
+// EXECUTION STATS
+// ================================================================================================
+
+/// The number of VM cycles spent in each phase of a transaction execution.
+///
+/// Proving time grows with the length of the execution trace, which is the number of cycles
+/// padded to the next power of two. Cycles spent in the VM chiplets (for example, hashing) are not
+/// included, so [ExecutionStats::trace_length] is a lower bound of the actual trace length.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionStats {
+    prologue_cycles: usize,
+    notes_processing_cycles: usize,
+    note_execution_cycles: Vec<(NoteId, usize)>,
+    tx_script_cycles: usize,
+    epilogue_cycles: usize,
+}
+
+impl ExecutionStats {
+    /// Returns the number of cycles spent in the transaction prologue.
+    pub fn prologue_cycles(&self) -> usize {
+        self.prologue_cycles
+    }
+
+    /// Returns the number of cycles spent processing input notes, including the execution of
+    /// their scripts.
+    pub fn notes_processing_cycles(&self) -> usize {
+        self.notes_processing_cycles
+    }
+
+    /// Returns the number of cycles spent executing the script of each input note.
+    pub fn note_execution_cycles(&self) -> &[(NoteId, usize)] {
+        &self.note_execution_cycles
+    }
+
+    /// Returns the number of cycles spent executing the transaction script.
+    pub fn tx_script_cycles(&self) -> usize {
+        self.tx_script_cycles
+    }
+
+    /// Returns the number of cycles spent in the transaction epilogue.
+    pub fn epilogue_cycles(&self) -> usize {
+        self.epilogue_cycles
+    }
+
+    /// Returns the total number of cycles spent in the transaction.
+    pub fn total_cycles(&self) -> usize {
+        self.prologue_cycles
+            + self.notes_processing_cycles
+            + self.tx_script_cycles
+            + self.epilogue_cycles
+    }
+
+    /// Returns the estimated length of the execution trace that has to be proven.
+    pub fn trace_length(&self) -> usize {
+        self.total_cycles().next_power_of_two()
+    }
+}
+
+impl From<&TransactionMeasurements> for ExecutionStats {
+    fn from(measurements: &TransactionMeasurements) -> Self {
+        Self {
+            prologue_cycles: measurements.prologue,
+            notes_processing_cycles: measurements.notes_processing,
+            note_execution_cycles: measurements.note_execution.clone(),
+            tx_script_cycles: measurements.tx_script_processing,
+            epilogue_cycles: measurements.epilogue,
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use miden_objects::{
+    accounts::{delta::NonFungibleDeltaAction, AccountId},
+    assets::{Asset, NonFungibleAsset},
+    notes::{NoteId, NoteTag, NoteType},
+    transaction::{ExecutedTransaction, TransactionId},
+    Digest, Felt, Word,
+};
+
+use super::OutputNote;
+
+// TRANSACTION SUMMARY
+// ================================================================================================
+
+/// A human-readable overview of the effects of an [ExecutedTransaction].
+///
+/// It describes the changes the transaction makes to the executing account (vault, storage and
+/// nonce), the notes it consumes and creates, and the block after which it expires. It is meant to
+/// be shown to users before a transaction is proven and submitted.
+#[derive(Clone, Debug)]
+pub struct TransactionSummary {
+    transaction_id: TransactionId,
+    account_id: AccountId,
+    block_num: u32,
+    expiration_block_num: u32,
+    initial_nonce: Felt,
+    final_nonce: Option<Felt>,
+    asset_changes: Vec<AssetChange>,
+    storage_changes: Vec<StorageSlotChange>,
+    consumed_notes: Vec<ConsumedNoteSummary>,
+    created_notes: Vec<CreatedNoteSummary>,
+}
+
+impl TransactionSummary {
+    /// Returns the ID of the summarized transaction.
+    pub fn transaction_id(&self) -> TransactionId {
+        self.transaction_id
+    }
+
+    /// Returns the ID of the account executing the transaction.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the number of the block against which the transaction was executed.
+    pub fn block_num(&self) -> u32 {
+        self.block_num
+    }
+
+    /// Returns the number of the last block in which the transaction can be included.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.expiration_block_num
+    }
+
+    /// Returns the nonce of the account before the transaction.
+    pub fn initial_nonce(&self) -> Felt {
+        self.initial_nonce
+    }
+
+    /// Returns the nonce of the account after the transaction, or `None` if the transaction does
+    /// not change it.
+    pub fn final_nonce(&self) -> Option<Felt> {
+        self.final_nonce
+    }
+
+    /// Returns the changes to the account's vault, with fungible assets grouped by faucet.
+    pub fn asset_changes(&self) -> &[AssetChange] {
+        &self.asset_changes
+    }
+
+    /// Returns the account storage slots updated by the transaction.
+    pub fn storage_changes(&self) -> &[StorageSlotChange] {
+        &self.storage_changes
+    }
+
+    /// Returns the notes consumed by the transaction.
+    pub fn consumed_notes(&self) -> &[ConsumedNoteSummary] {
+        &self.consumed_notes
+    }
+
+    /// Returns the notes created by the transaction.
+    pub fn created_notes(&self) -> &[CreatedNoteSummary] {
+        &self.created_notes
+    }
+
+    /// Returns `true` if the transaction does not change the account's vault, storage or nonce.
+    pub fn is_account_unchanged(&self) -> bool {
+        self.asset_changes.is_empty()
+            && self.storage_changes.is_empty()
+            && self.final_nonce.is_none()
+    }
+}
+
+impl From<&ExecutedTransaction> for TransactionSummary {
+    fn from(transaction: &ExecutedTransaction) -> Self {
+        let account_delta = transaction.account_delta();
+
+        let mut asset_changes: Vec<AssetChange> = account_delta
+            .vault()
+            .fungible()
+            .iter()
+            .map(|(faucet_id, amount)| AssetChange::Fungible {
+                faucet_id: *faucet_id,
+                amount: *amount,
+            })
+            .collect();
+        asset_changes.extend(account_delta.vault().non_fungible().iter().map(|(asset, action)| {
+            AssetChange::NonFungible {
+                asset: *asset,
+                added: matches!(action, NonFungibleDeltaAction::Add),
+            }
+        }));
+
+        let mut storage_changes: Vec<StorageSlotChange> = account_delta
+            .storage()
+            .values()
+            .iter()
+            .map(|(index, value)| StorageSlotChange::Value { index: *index, value: *value })
+            .collect();
+        storage_changes.extend(account_delta.storage().maps().iter().map(|(index, map_delta)| {
+            StorageSlotChange::Map {
+                index: *index,
+                updated_keys: map_delta.leaves().len(),
+            }
+        }));
+        storage_changes.sort_by_key(StorageSlotChange::index);
+
+        let consumed_notes = transaction
+            .input_notes()
+            .iter()
+            .map(|input_note| {
+                let note = input_note.note();
+                ConsumedNoteSummary {
+                    id: note.id(),
+                    sender: note.metadata().sender(),
+                    assets: note.assets().iter().cloned().collect(),
+                }
+            })
+            .collect();
+
+        let created_notes =
+            transaction.output_notes().iter().map(CreatedNoteSummary::from).collect();
+
+        Self {
+            transaction_id: transaction.id(),
+            account_id: transaction.account_id(),
+            block_num: transaction.block_header().block_num(),
+            expiration_block_num: transaction.expiration_block_num(),
+            initial_nonce: transaction.initial_account().nonce(),
+            final_nonce: account_delta.nonce(),
+            asset_changes,
+            storage_changes,
+            consumed_notes,
+            created_notes,
+        }
+    }
+}
+
+impl fmt::Display for TransactionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Transaction {} for account {}", self.transaction_id, self.account_id)?;
+        writeln!(
+            f,
+            "Executed against block {}, expires after block {}",
+            self.block_num, self.expiration_block_num
+        )?;
+
+        if self.consumed_notes.is_empty() {
+            writeln!(f, "No notes will be consumed.")?;
+        } else {
+            writeln!(f, "Consumed notes:")?;
+            for note in self.consumed_notes.iter() {
+                writeln!(f, "  - {} from {}", note.id.to_hex(), note.sender)?;
+                for asset in note.assets.iter() {
+                    writeln!(f, "      {}", AssetDisplay(asset))?;
+                }
+            }
+        }
+
+        if self.created_notes.is_empty() {
+            writeln!(f, "No notes will be created.")?;
+        } else {
+            writeln!(f, "Created notes:")?;
+            for note in self.created_notes.iter() {
+                writeln!(f, "  - {} ({:?}, tag {})", note.id.to_hex(), note.note_type, note.tag)?;
+                if let Some(recipient) = note.recipient {
+                    writeln!(f, "      recipient: {}", recipient.to_hex())?;
+                }
+                match &note.assets {
+                    Some(assets) => {
+                        for asset in assets.iter() {
+                            writeln!(f, "      {}", AssetDisplay(asset))?;
+                        }
+                    },
+                    None => writeln!(f, "      assets: unknown")?,
+                }
+            }
+        }
+
+        if self.asset_changes.is_empty() {
+            writeln!(f, "Account vault will not be changed.")?;
+        } else {
+            writeln!(f, "Vault changes:")?;
+            for change in self.asset_changes.iter() {
+                writeln!(f, "  - {change}")?;
+            }
+        }
+
+        if self.storage_changes.is_empty() {
+            writeln!(f, "Account storage will not be changed.")?;
+        } else {
+            writeln!(f, "Storage changes:")?;
+            for change in self.storage_changes.iter() {
+                writeln!(f, "  - {change}")?;
+            }
+        }
+
+        match self.final_nonce {
+            Some(final_nonce) => write!(f, "Nonce: {} -> {}", self.initial_nonce, final_nonce),
+            None => write!(f, "Nonce: unchanged ({})", self.initial_nonce),
+        }
+    }
+}
+
+// ASSET CHANGE
+// ================================================================================================
+
+/// A change to the vault of the account executing a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssetChange {
+    /// The balance of the fungible asset issued by `faucet_id` changes by `amount`.
+    Fungible { faucet_id: AccountId, amount: i64 },
+    /// The non-fungible `asset` is added to (`added` is `true`) or removed from the vault.
+    NonFungible { asset: NonFungibleAsset, added: bool },
+}
+
+impl fmt::Display for AssetChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetChange::Fungible { faucet_id, amount } => {
+                write!(f, "{amount:+} of fungible asset from faucet {faucet_id}")
+            },
+            AssetChange::NonFungible { asset, added } => {
+                let sign = if *added { "+" } else { "-" };
+                write!(f, "{sign}1 non-fungible asset from faucet {}", asset.faucet_id())
+            },
+        }
+    }
+}
+
+// STORAGE SLOT CHANGE
+// ================================================================================================
+
+/// An update to a storage slot of the account executing a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageSlotChange {
+    /// The value slot at `index` is set to `value`.
+    Value { index: u8, value: Word },
+    /// `updated_keys` entries of the map slot at `index` are updated.
+    Map { index: u8, updated_keys: usize },
+}
+
+impl StorageSlotChange {
+    /// Returns the index of the updated slot.
+    pub fn index(&self) -> u8 {
+        match self {
+            StorageSlotChange::Value { index, .. } | StorageSlotChange::Map { index, .. } => *index,
+        }
+    }
+}
+
+impl fmt::Display for StorageSlotChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageSlotChange::Value { index, value } => {
+                write!(f, "slot {index} set to {}", Digest::from(*value).to_hex())
+            },
+            StorageSlotChange::Map { index, updated_keys } => {
+                write!(f, "slot {index}: {updated_keys} map entries updated")
+            },
+        }
+    }
+}
+
+// NOTE SUMMARIES
+// ================================================================================================
+
+/// Describes a note consumed by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsumedNoteSummary {
+    /// The ID of the note.
+    pub id: NoteId,
+    /// The account that created the note.
+    pub sender: AccountId,
+    /// The assets carried by the note.
+    pub assets: Vec<Asset>,
+}
+
+/// Describes a note created by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatedNoteSummary {
+    /// The ID of the note.
+    pub id: NoteId,
+    /// Whether the note is public or private.
+    pub note_type: NoteType,
+    /// The tag used to route the note to its recipient.
+    pub tag: NoteTag,
+    /// Digest of the note's recipient, if known.
+    pub recipient: Option<Digest>,
+    /// The assets carried by the note, if known.
+    pub assets: Option<Vec<Asset>>,
+}
+
+impl From<&OutputNote> for CreatedNoteSummary {
+    fn from(note: &OutputNote) -> Self {
+        Self {
+            id: note.id(),
+            note_type: note.metadata().note_type(),
+            tag: note.metadata().tag(),
+            recipient: note.recipient_digest(),
+            assets: note.assets().map(|assets| assets.iter().cloned().collect()),
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Formats an [Asset] as its amount (or as a single non-fungible asset) and issuing faucet.
+struct AssetDisplay<'a>(&'a Asset);
+
+impl fmt::Display for AssetDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Asset::Fungible(asset) => {
+                write!(f, "{} of fungible asset from faucet {}", asset.amount(), asset.faucet_id())
+            },
+            Asset::NonFungible(asset) => {
+                write!(f, "1 non-fungible asset from faucet {}", asset.faucet_id())
+            },
+        }
+    }
+}
@@ -35,6 +35,7 @@ const {
   TransactionRequest,
   TransactionScriptInputPair,
   TransactionScriptInputPairArray,
+  TransactionSummary,
   WebClient,
 } = await wasm({
   importHook: () => {
@@ -77,5 +78,6 @@ export {
   TransactionRequest,
   TransactionScriptInputPair,
   TransactionScriptInputPairArray,
+  TransactionSummary,
   WebClient,
 };
@@ -36,5 +36,6 @@ export {
   TransactionRequest,
   TransactionScriptInputPair,
   TransactionScriptInputPairArray,
+  TransactionSummary,
   WebClient,
 } from "./crates/miden_client_web";
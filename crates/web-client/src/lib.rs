@@ -1,16 +1,19 @@
 extern crate alloc;
-use alloc::sync::Arc;
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::time::Duration;
 
 use console_error_panic_hook::set_once;
 use miden_client::{
+    config::RpcConfig,
     rpc::WebTonicRpcClient,
     store::{web_store::WebStore, StoreAuthenticator},
-    transactions::{LocalTransactionProver, TransactionProver},
+    transactions::{FallbackTransactionProver, LocalTransactionProver, TransactionProver},
     Client,
 };
 use miden_objects::{crypto::rand::RpoRandomCoin, Felt};
 use miden_tx_prover::RemoteTransactionProver;
 use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde_wasm_bindgen::from_value;
 use wasm_bindgen::prelude::*;
 
 pub mod account;
@@ -48,10 +51,26 @@ impl WebClient {
         self.inner.as_mut()
     }
 
+    /// Creates the internal client.
+    ///
+    /// Transactions are proven with the prover at `proving_url`, or locally if it's not provided.
+    /// When `fallback_proving_urls` is provided, those provers are tried in order when
+    /// `proving_url` can't produce a proof (it's unreachable or reports an internal error),
+    /// followed by the local prover, and `proving_timeout_ms` bounds how long each of the remote
+    /// provers is waited for before moving on to the next one. Transactions rejected as invalid
+    /// are not retried.
+    ///
+    /// `rpc_headers` is an object whose entries are sent as metadata headers with every request to
+    /// the node, in addition to the `authorization` header built from `rpc_api_key`. Headers that
+    /// browsers don't let scripts set, such as `cookie` or `host`, are dropped by the browser.
     pub async fn create_client(
         &mut self,
         node_url: Option<String>,
         proving_url: Option<String>,
+        rpc_api_key: Option<String>,
+        fallback_proving_urls: Option<Vec<String>>,
+        proving_timeout_ms: Option<u32>,
+        rpc_headers: JsValue,
     ) -> Result<JsValue, JsValue> {
         let mut rng = StdRng::from_entropy();
         let coin_seed: [u64; 4] = rng.gen();
@@ -62,13 +81,41 @@ impl WebClient {
             .map_err(|_| JsValue::from_str("Failed to initialize WebStore"))?;
         let web_store = Arc::new(web_store);
         let authenticator = Arc::new(StoreAuthenticator::new_with_rng(web_store.clone(), rng));
-        let web_rpc_client = Box::new(WebTonicRpcClient::new(
-            &node_url.unwrap_or_else(|| "http://18.203.155.106:57291".to_string()),
-        ));
+        let headers: BTreeMap<String, String> =
+            if rpc_headers.is_undefined() || rpc_headers.is_null() {
+                BTreeMap::new()
+            } else {
+                from_value(rpc_headers)
+                    .map_err(|err| JsValue::from_str(&format!("Invalid RPC headers: {}", err)))?
+            };
+        let rpc_config = RpcConfig {
+            headers,
+            api_key: rpc_api_key,
+            ..Default::default()
+        };
+        let web_rpc_client = Box::new(
+            WebTonicRpcClient::new_with_headers(
+                &node_url.unwrap_or_else(|| "http://18.203.155.106:57291".to_string()),
+                &rpc_config.request_headers(),
+            )
+            .map_err(|err| JsValue::from_str(&format!("Failed to create RPC client: {}", err)))?,
+        );
+
+        let tx_prover: Arc<dyn TransactionProver> = match (proving_url, fallback_proving_urls) {
+            (proving_url, Some(fallback_proving_urls)) => {
+                let timeout =
+                    proving_timeout_ms.map(|timeout| Duration::from_millis(timeout.into()));
 
-        let tx_prover: Arc<dyn TransactionProver> = match proving_url {
-            Some(proving_url) => Arc::new(RemoteTransactionProver::new(&proving_url.to_string())),
-            None => Arc::new(LocalTransactionProver::new(Default::default())),
+                let mut tx_prover = FallbackTransactionProver::new();
+                for url in proving_url.into_iter().chain(fallback_proving_urls) {
+                    let remote_prover = Arc::new(RemoteTransactionProver::new(&url));
+                    tx_prover = tx_prover.with_prover(url, remote_prover, timeout);
+                }
+                let local_prover = Arc::new(LocalTransactionProver::new(Default::default()));
+                Arc::new(tx_prover.with_prover("local", local_prover, None))
+            },
+            (Some(proving_url), None) => Arc::new(RemoteTransactionProver::new(&proving_url)),
+            (None, None) => Arc::new(LocalTransactionProver::new(Default::default())),
         };
 
         self.inner = Some(Client::new(
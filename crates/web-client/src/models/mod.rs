@@ -80,5 +80,6 @@ pub mod transaction_result;
 pub mod transaction_script;
 pub mod transaction_script_inputs;
 pub mod transaction_status;
+pub mod transaction_summary;
 pub mod transactions;
 pub mod word;
@@ -4,6 +4,7 @@ use wasm_bindgen::prelude::*;
 use super::{
     account_delta::AccountDelta, executed_transaction::ExecutedTransaction,
     input_notes::InputNotes, output_notes::OutputNotes, transaction_args::TransactionArgs,
+    transaction_summary::TransactionSummary,
 };
 
 #[wasm_bindgen]
@@ -36,6 +37,10 @@ impl TransactionResult {
     pub fn consumed_notes(&self) -> InputNotes {
         self.0.consumed_notes().into()
     }
+
+    pub fn summary(&self) -> TransactionSummary {
+        self.0.summary().into()
+    }
 }
 
 // CONVERSIONS
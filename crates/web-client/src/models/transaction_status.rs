@@ -7,6 +7,18 @@ pub struct TransactionStatus(NativeTransactionStatus);
 
 #[wasm_bindgen]
 impl TransactionStatus {
+    pub fn executed() -> TransactionStatus {
+        TransactionStatus(NativeTransactionStatus::Executed)
+    }
+
+    pub fn proven() -> TransactionStatus {
+        TransactionStatus(NativeTransactionStatus::Proven)
+    }
+
+    pub fn queued() -> TransactionStatus {
+        TransactionStatus(NativeTransactionStatus::Queued)
+    }
+
     pub fn pending() -> TransactionStatus {
         TransactionStatus(NativeTransactionStatus::Pending)
     }
@@ -15,6 +27,18 @@ impl TransactionStatus {
         TransactionStatus(NativeTransactionStatus::Committed(block_num))
     }
 
+    pub fn is_executed(&self) -> bool {
+        matches!(self.0, NativeTransactionStatus::Executed)
+    }
+
+    pub fn is_proven(&self) -> bool {
+        matches!(self.0, NativeTransactionStatus::Proven)
+    }
+
+    pub fn is_queued(&self) -> bool {
+        matches!(self.0, NativeTransactionStatus::Queued)
+    }
+
     pub fn is_pending(&self) -> bool {
         matches!(self.0, NativeTransactionStatus::Pending)
     }
@@ -0,0 +1,215 @@
+use miden_client::{
+    assets::Asset as NativeAsset,
+    transactions::{
+        AssetChange as NativeAssetChange, ConsumedNoteSummary as NativeConsumedNoteSummary,
+        CreatedNoteSummary as NativeCreatedNoteSummary,
+        StorageSlotChange as NativeStorageSlotChange,
+        TransactionSummary as NativeTransactionSummary,
+    },
+};
+use wasm_bindgen::prelude::*;
+
+use super::{
+    account_id::AccountId, felt::Felt, fungible_asset::FungibleAsset, note_id::NoteId,
+    note_tag::NoteTag, note_type::NoteType, rpo_digest::RpoDigest, transaction_id::TransactionId,
+    word::Word,
+};
+
+// TRANSACTION SUMMARY
+// ================================================================================================
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct TransactionSummary(NativeTransactionSummary);
+
+#[wasm_bindgen]
+impl TransactionSummary {
+    pub fn transaction_id(&self) -> TransactionId {
+        self.0.transaction_id().into()
+    }
+
+    pub fn account_id(&self) -> AccountId {
+        self.0.account_id().into()
+    }
+
+    pub fn block_num(&self) -> u32 {
+        self.0.block_num()
+    }
+
+    pub fn expiration_block_num(&self) -> u32 {
+        self.0.expiration_block_num()
+    }
+
+    pub fn initial_nonce(&self) -> Felt {
+        self.0.initial_nonce().into()
+    }
+
+    pub fn final_nonce(&self) -> Option<Felt> {
+        self.0.final_nonce().map(|nonce| nonce.into())
+    }
+
+    pub fn asset_changes(&self) -> Vec<AssetChange> {
+        self.0.asset_changes().iter().cloned().map(AssetChange).collect()
+    }
+
+    pub fn storage_changes(&self) -> Vec<StorageSlotChange> {
+        self.0.storage_changes().iter().cloned().map(StorageSlotChange).collect()
+    }
+
+    pub fn consumed_notes(&self) -> Vec<ConsumedNoteSummary> {
+        self.0.consumed_notes().iter().cloned().map(ConsumedNoteSummary).collect()
+    }
+
+    pub fn created_notes(&self) -> Vec<CreatedNoteSummary> {
+        self.0.created_notes().iter().cloned().map(CreatedNoteSummary).collect()
+    }
+
+    /// Returns a human-readable description of the transaction's effects.
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn to_display_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+// ASSET CHANGE
+// ================================================================================================
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct AssetChange(NativeAssetChange);
+
+#[wasm_bindgen]
+impl AssetChange {
+    pub fn is_fungible(&self) -> bool {
+        matches!(self.0, NativeAssetChange::Fungible { .. })
+    }
+
+    pub fn faucet_id(&self) -> AccountId {
+        match &self.0 {
+            NativeAssetChange::Fungible { faucet_id, .. } => faucet_id.into(),
+            NativeAssetChange::NonFungible { asset, .. } => asset.faucet_id().into(),
+        }
+    }
+
+    /// Returns the change in the balance of the asset. Non-fungible assets count as one unit.
+    pub fn amount(&self) -> i64 {
+        match &self.0 {
+            NativeAssetChange::Fungible { amount, .. } => *amount,
+            NativeAssetChange::NonFungible { added: true, .. } => 1,
+            NativeAssetChange::NonFungible { added: false, .. } => -1,
+        }
+    }
+}
+
+// STORAGE SLOT CHANGE
+// ================================================================================================
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct StorageSlotChange(NativeStorageSlotChange);
+
+#[wasm_bindgen]
+impl StorageSlotChange {
+    pub fn index(&self) -> u8 {
+        self.0.index()
+    }
+
+    /// Returns the new value of the slot if it is a value slot.
+    pub fn value(&self) -> Option<Word> {
+        match &self.0 {
+            NativeStorageSlotChange::Value { value, .. } => Some(value.into()),
+            NativeStorageSlotChange::Map { .. } => None,
+        }
+    }
+
+    /// Returns the number of updated entries if the slot is a map slot.
+    pub fn updated_map_entries(&self) -> Option<usize> {
+        match &self.0 {
+            NativeStorageSlotChange::Value { .. } => None,
+            NativeStorageSlotChange::Map { updated_keys, .. } => Some(*updated_keys),
+        }
+    }
+}
+
+// NOTE SUMMARIES
+// ================================================================================================
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConsumedNoteSummary(NativeConsumedNoteSummary);
+
+#[wasm_bindgen]
+impl ConsumedNoteSummary {
+    pub fn id(&self) -> NoteId {
+        self.0.id.into()
+    }
+
+    pub fn sender(&self) -> AccountId {
+        self.0.sender.into()
+    }
+
+    pub fn fungible_assets(&self) -> Vec<FungibleAsset> {
+        fungible_assets(&self.0.assets)
+    }
+}
+
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct CreatedNoteSummary(NativeCreatedNoteSummary);
+
+#[wasm_bindgen]
+impl CreatedNoteSummary {
+    pub fn id(&self) -> NoteId {
+        self.0.id.into()
+    }
+
+    pub fn note_type(&self) -> NoteType {
+        self.0.note_type.into()
+    }
+
+    pub fn tag(&self) -> NoteTag {
+        self.0.tag.into()
+    }
+
+    pub fn recipient_digest(&self) -> Option<RpoDigest> {
+        self.0.recipient.map(|digest| digest.into())
+    }
+
+    /// Returns `false` if the transaction only knows the note's header, in which case its assets
+    /// are unknown.
+    pub fn has_known_assets(&self) -> bool {
+        self.0.assets.is_some()
+    }
+
+    pub fn fungible_assets(&self) -> Vec<FungibleAsset> {
+        self.0.assets.as_deref().map(fungible_assets).unwrap_or_default()
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+fn fungible_assets(assets: &[NativeAsset]) -> Vec<FungibleAsset> {
+    assets
+        .iter()
+        .filter_map(|asset| match asset {
+            NativeAsset::Fungible(fungible_asset) => Some(fungible_asset.into()),
+            NativeAsset::NonFungible(_) => None,
+        })
+        .collect()
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<NativeTransactionSummary> for TransactionSummary {
+    fn from(native_transaction_summary: NativeTransactionSummary) -> Self {
+        TransactionSummary(native_transaction_summary)
+    }
+}
+
+impl From<&NativeTransactionSummary> for TransactionSummary {
+    fn from(native_transaction_summary: &NativeTransactionSummary) -> Self {
+        TransactionSummary(native_transaction_summary.clone())
+    }
+}
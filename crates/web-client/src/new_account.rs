@@ -37,17 +37,18 @@ impl WebClient {
         decimals: u8,
         max_supply: u64,
     ) -> Result<Account, JsValue> {
-        if non_fungible {
-            return Err(JsValue::from_str("Non-fungible faucets are not supported yet"));
-        }
-
         if let Some(client) = self.get_mut_inner() {
-            let client_template = AccountTemplate::FungibleFaucet {
-                token_symbol: TokenSymbol::new(token_symbol)
-                    .map_err(|e| JsValue::from_str(&e.to_string()))?,
-                decimals,
-                max_supply,
-                storage_mode: storage_mode.into(),
+            // Token symbol, decimals and max supply only apply to fungible faucets
+            let client_template = if non_fungible {
+                AccountTemplate::NonFungibleFaucet { storage_mode: storage_mode.into() }
+            } else {
+                AccountTemplate::FungibleFaucet {
+                    token_symbol: TokenSymbol::new(token_symbol)
+                        .map_err(|e| JsValue::from_str(&e.to_string()))?,
+                    decimals,
+                    max_supply,
+                    storage_mode: storage_mode.into(),
+                }
             };
 
             match client.new_account(client_template).await {
@@ -1,7 +1,8 @@
 use miden_client::{
     accounts::AccountTemplate,
-    notes::Note,
+    notes::{Note, SwapOrderStatus},
     transactions::{SwapTransactionData, TransactionRequest},
+    ClientError,
 };
 use miden_objects::{
     accounts::{AccountId, AccountStorageMode},
@@ -142,6 +143,19 @@ async fn test_swap_fully_onchain() {
 
     execute_tx_and_sync(&mut client1, account_a.id(), tx_request).await;
 
+    let swap_orders = client1.get_swap_orders().await.unwrap();
+    assert_eq!(swap_orders.len(), 1);
+    assert_eq!(swap_orders[0].id(), expected_output_notes[0].id());
+    assert_eq!(swap_orders[0].payback_note_id(), expected_payback_note_details[0].id());
+    assert_eq!(swap_orders[0].status(), SwapOrderStatus::Open);
+
+    // The SWAP note script has no recall path, so the order can't be cancelled
+    let swap_note_id = swap_orders[0].id();
+    assert!(matches!(
+        client1.cancel_swap(swap_note_id).await,
+        Err(ClientError::SwapOrderNotCancellable(id)) if id == swap_note_id
+    ));
+
     let payback_note_tag =
         build_swap_tag(NoteType::Public, btc_faucet_account.id(), eth_faucet_account.id());
 
@@ -163,10 +177,12 @@ async fn test_swap_fully_onchain() {
     // sync on client 1, we should get the missing payback note details.
     // try consuming the received note with accountA, it should now have 25 ETH
     client1.sync_state().await.unwrap();
+    assert_eq!(client1.get_swap_orders().await.unwrap()[0].status(), SwapOrderStatus::Filled);
     println!("Consuming swap payback note on first client...");
 
     let tx_request = TransactionRequest::consume_notes(vec![expected_payback_note_details[0].id()]);
     execute_tx_and_sync(&mut client1, account_a.id(), tx_request).await;
+    assert_eq!(client1.get_swap_orders().await.unwrap()[0].status(), SwapOrderStatus::Consumed);
 
     // At the end we should end up with
     //